@@ -0,0 +1,31 @@
+//! Compares converting a [`Primant`] to a float against dividing by `u32::MAX`.
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use unifrac::Primant;
+
+/// Raw values spread over the whole range.
+fn values() -> Vec<u32> {
+    (0..1024u32).map(|i| i.wrapping_mul(2_654_435_761)).collect()
+}
+
+fn float_conversion(c: &mut Criterion) {
+    let values = values();
+    let mut group = c.benchmark_group("float_conversion");
+    group.bench_function("f64/from", |b| {
+        b.iter(|| values.iter().map(|&raw| f64::from(Primant::from_raw(black_box(raw)))).sum::<f64>())
+    });
+    group.bench_function("f64/division", |b| {
+        b.iter(|| values.iter().map(|&raw| black_box(raw) as f64 / u32::MAX as f64).sum::<f64>())
+    });
+    group.bench_function("f32/from", |b| {
+        b.iter(|| values.iter().map(|&raw| f32::from(Primant::from_raw(black_box(raw)))).sum::<f32>())
+    });
+    group.bench_function("f32/division", |b| {
+        b.iter(|| values.iter().map(|&raw| black_box(raw) as f32 / u32::MAX as f32).sum::<f32>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, float_conversion);
+criterion_main!(benches);
@@ -0,0 +1,160 @@
+use crate::{Phase, Primant};
+
+/// An arc of the cycle, going forwards from a start [`Phase`] by a [`Primant`] of
+/// a turn, such as the sweep of a gauge or the valid window of a rotation.
+///
+/// Both ends are inclusive, and the arc may cross the end of the cycle. An arc of
+/// length [`Primant::ZERO`] is a single point, and an arc of length
+/// [`Primant::MAX`] is the whole cycle.
+///
+/// # Representation
+///
+/// Below [`Primant::MAX`], the raw length is the number of raw phase steps covered
+/// by the arc, so arcs between any two phases are exact. Only the arc one raw step
+/// short of the whole cycle cannot be represented, and is widened to the whole
+/// cycle.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Arc, Phase, Primant};
+/// // A quarter turn centered on the end of the cycle.
+/// let arc = Arc::from_ends(Phase::from_raw(0xE000_0000), Phase::from_raw(0x2000_0000));
+/// assert!(arc.contains(Phase::from_raw(0x1000_0000)));
+/// assert!(!arc.contains(Phase::from_raw(0x8000_0000)));
+/// assert_eq!(arc.midpoint(), Phase::MIN);
+/// assert_eq!(arc.end(), Phase::from_raw(0x2000_0000));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Arc {
+    start: Phase,
+    length: Primant,
+}
+
+impl Arc {
+    /// The arc covering the whole cycle, starting at [`Phase::MIN`].
+    pub const FULL: Arc = Arc::new(Phase::MIN, Primant::MAX);
+
+    /// Creates a new [`Arc`] going forwards from `start` by `length` of a turn.
+    pub const fn new(start: Phase, length: Primant) -> Self {
+        Arc { start, length }
+    }
+
+    /// Creates a new [`Arc`] going forwards from `start` to `end`, across the end
+    /// of the cycle if needed.
+    ///
+    /// The arc ends exactly at `end`, unless it is one raw step short of the whole
+    /// cycle. If `start` and `end` are equal, the arc is a single point.
+    pub fn from_ends(start: Phase, end: Phase) -> Self {
+        Arc::new(start, Primant::from_raw(end.to_raw().wrapping_sub(start.to_raw())))
+    }
+
+    /// Returns the start of the arc.
+    pub const fn start(&self) -> Phase {
+        self.start
+    }
+
+    /// Returns the length of the arc, as a fraction of a turn.
+    pub const fn length(&self) -> Primant {
+        self.length
+    }
+
+    /// Returns the end of the arc.
+    pub fn end(&self) -> Phase {
+        self.sample(Primant::MAX)
+    }
+
+    /// Returns the phase halfway along the arc, rounded down.
+    pub fn midpoint(&self) -> Phase {
+        self.offset(self.span() / 2)
+    }
+
+    /// Returns the phase at `t` along the arc, rounded to the nearest value, so
+    /// [`Primant::ZERO`] yields the start and [`Primant::MAX`] the end.
+    pub fn sample(&self, t: Primant) -> Phase {
+        self.offset(t.percent_of(self.span()))
+    }
+
+    /// Returns whether `phase` lies on the arc.
+    pub fn contains(&self, phase: Phase) -> bool {
+        (phase.to_raw().wrapping_sub(self.start.to_raw()) as u64) <= self.span()
+    }
+
+    /// Returns whether `other` lies entirely on this arc.
+    ///
+    /// The whole cycle lies on any other arc covering the whole cycle, whatever
+    /// their starts.
+    pub fn contains_arc(&self, other: &Arc) -> bool {
+        if self.span() == 1 << 32 { return true; }
+        let offset = other.start.to_raw().wrapping_sub(self.start.to_raw()) as u64;
+        offset + other.span() <= self.span()
+    }
+
+    /// Returns whether both arcs share at least one phase.
+    pub fn intersects(&self, other: &Arc) -> bool {
+        // Two overlapping arcs always contain the start of one another.
+        self.contains(other.start) || other.contains(self.start)
+    }
+
+    /// Returns the length of the arc in raw phase units, from `0` to `2^32`.
+    fn span(&self) -> u64 {
+        if self.length == Primant::MAX { 1 << 32 } else { self.length.to_raw() as u64 }
+    }
+
+    /// Returns the phase `offset` raw units after the start.
+    fn offset(&self, offset: u64) -> Phase {
+        Phase::from_raw(self.start.to_raw().wrapping_add(offset as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ends() {
+        for (start, end) in [(0, 0), (5, 3), (u32::MAX, 0), (0x8000_0000, 0x7FFF_FFFE), (123, 0x1234_5678)] {
+            let arc = Arc::from_ends(Phase::from_raw(start), Phase::from_raw(end));
+            assert_eq!(arc.end(), Phase::from_raw(end));
+            assert_eq!(arc.span(), end.wrapping_sub(start) as u64);
+        }
+        assert_eq!(Arc::from_ends(Phase::from_raw(5), Phase::from_raw(4)).span(), 1 << 32);
+        assert_eq!(Arc::FULL.span(), 1 << 32);
+        assert_eq!(Arc::FULL.end(), Phase::MIN);
+    }
+
+    #[test]
+    fn test_contains() {
+        let point = Arc::new(Phase::from_raw(7), Primant::ZERO);
+        assert!(point.contains(Phase::from_raw(7)));
+        assert!(!point.contains(Phase::from_raw(8)));
+        assert!(Arc::FULL.contains(Phase::from_raw(u32::MAX)));
+
+        let arc = Arc::from_ends(Phase::from_raw(0xF000_0000), Phase::from_raw(0x1000_0000));
+        assert!(arc.contains_arc(&Arc::from_ends(Phase::from_raw(0xF800_0000), Phase::from_raw(0x1000_0000))));
+        assert!(!arc.contains_arc(&Arc::from_ends(Phase::from_raw(0xF800_0000), Phase::from_raw(0x1000_0001))));
+        assert!(!arc.contains_arc(&Arc::FULL));
+        assert!(Arc::new(Phase::from_raw(9), Primant::MAX).contains_arc(&Arc::FULL));
+    }
+
+    #[test]
+    fn test_intersects() {
+        let arc = Arc::from_ends(Phase::from_raw(0xF000_0000), Phase::from_raw(0x1000_0000));
+        let touching = Arc::from_ends(Phase::from_raw(0x1000_0000), Phase::from_raw(0x2000_0000));
+        let inside = Arc::from_ends(Phase::from_raw(0xF800_0000), Phase::from_raw(0x0800_0000));
+        let apart = Arc::from_ends(Phase::from_raw(0x1000_0001), Phase::from_raw(0xEFFF_FFFF));
+        assert!(arc.intersects(&touching) && touching.intersects(&arc));
+        assert!(arc.intersects(&inside) && inside.intersects(&arc));
+        assert!(!arc.intersects(&apart) && !apart.intersects(&arc));
+        assert!(Arc::FULL.intersects(&apart));
+    }
+
+    #[test]
+    fn test_sample() {
+        let arc = Arc::new(Phase::from_raw(0xC000_0000), Primant::from_ratio(1u32, 2u32));
+        assert_eq!(arc.sample(Primant::ZERO), arc.start());
+        assert_eq!(arc.sample(Primant::from_ratio(1u32, 2u32)), Phase::from_raw(0xFFFF_FFFF));
+        assert_eq!(arc.midpoint(), Phase::from_raw(0xFFFF_FFFF));
+        assert_eq!(arc.end(), Phase::from_raw(0x3FFF_FFFF));
+    }
+}
@@ -0,0 +1,210 @@
+//! Implementations of the casting traits of the `az` crate.
+//!
+//! Casts convert by numeric value, like the casts between the primitive types:
+//!
+//! - The fractional types cast to and from `f32` and `f64`, like the [`From`] and
+//!   [`TryFrom`] implementations. Saturating casts panic if the value is NaN.
+//! - [`Primant`] and [`Bipolar`] cast to and from every primitive integer type.
+//!   Casting to an integer truncates towards zero, so only the endpoints are
+//!   nonzero, and only integers within the range of the type can be cast back.
+//! - [`Primant`] casts to and from [`Bipolar`] and [`Phase`], rounding to the
+//!   nearest value. Only non-negative values fit in a [`Primant`], and
+//!   [`Primant::MAX`] does not fit in a [`Phase`].
+//!
+//! Unchecked casts panic if the value does not fit.
+//!
+//! This is only available with the `az` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use az::{CheckedAs, SaturatingAs};
+//! # use unifrac::{Bipolar, Primant};
+//!
+//! assert_eq!(1u8.checked_as::<Primant>(), Some(Primant::MAX));
+//! assert_eq!(2u8.checked_as::<Primant>(), None);
+//! assert_eq!(Bipolar::MIN.saturating_as::<Primant>(), Primant::ZERO);
+//! assert_eq!(1.5f32.saturating_as::<Primant>(), Primant::MAX);
+//! ```
+
+use ::az::{Cast, CheckedCast, SaturatingCast};
+use crate::{Bipolar, Phase, Primant};
+
+/// Implements the unchecked and saturating casts from `$src` to `$dst` in terms of
+/// the checked cast and a saturating function.
+macro_rules! impl_unchecked_cast {
+    ($src:ty => $dst:ty, |$value:ident| $saturate:expr) => {
+        impl Cast<$dst> for $src {
+            fn cast(self) -> $dst {
+                self.checked_cast().expect("value does not fit in the destination type")
+            }
+        }
+
+        impl SaturatingCast<$dst> for $src {
+            fn saturating_cast(self) -> $dst {
+                let $value = self;
+                $saturate
+            }
+        }
+    };
+}
+
+/// Implements every cast from `$src` to `$dst` in terms of an infallible conversion.
+macro_rules! impl_lossless_cast {
+    ($src:ty => $dst:ty, |$value:ident| $convert:expr) => {
+        impl CheckedCast<$dst> for $src {
+            fn checked_cast(self) -> Option<$dst> {
+                Some(self.cast())
+            }
+        }
+
+        impl Cast<$dst> for $src {
+            fn cast(self) -> $dst {
+                let $value = self;
+                $convert
+            }
+        }
+
+        impl SaturatingCast<$dst> for $src {
+            fn saturating_cast(self) -> $dst {
+                self.cast()
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_casts {
+    ($($t:ty => $f:ty),*) => {$(
+        impl_lossless_cast!($t => $f, |value| <$f>::from(value));
+
+        impl CheckedCast<$t> for $f {
+            fn checked_cast(self) -> Option<$t> {
+                <$t>::try_from(self).ok()
+            }
+        }
+
+        impl_unchecked_cast!($f => $t, |value| {
+            assert!(!value.is_nan(), "value must not be NaN");
+            <$t>::from_float_saturating(value)
+        });
+    )*};
+}
+
+impl_float_casts!(Primant => f32, Primant => f64, Phase => f32, Phase => f64, Bipolar => f32, Bipolar => f64);
+
+macro_rules! impl_integer_casts {
+    ($($i:ty),*) => {$(
+        impl_lossless_cast!(Primant => $i, |value| (value == Primant::MAX) as $i);
+
+        impl CheckedCast<Primant> for $i {
+            fn checked_cast(self) -> Option<Primant> {
+                match self {
+                    0 => Some(Primant::ZERO),
+                    1 => Some(Primant::MAX),
+                    _ => None,
+                }
+            }
+        }
+
+        impl_unchecked_cast!($i => Primant, |value| if value >= 1 { Primant::MAX } else { Primant::ZERO });
+
+        impl CheckedCast<$i> for Bipolar {
+            fn checked_cast(self) -> Option<$i> {
+                <$i>::try_from(self.to_raw() / i32::MAX).ok()
+            }
+        }
+
+        impl_unchecked_cast!(Bipolar => $i, |value| <$i>::try_from(value.to_raw() / i32::MAX).unwrap_or(0));
+
+        impl CheckedCast<Bipolar> for $i {
+            fn checked_cast(self) -> Option<Bipolar> {
+                match i8::try_from(self) {
+                    Ok(-1) => Some(Bipolar::MIN),
+                    Ok(0) => Some(Bipolar::ZERO),
+                    Ok(1) => Some(Bipolar::MAX),
+                    _ => None,
+                }
+            }
+        }
+
+        impl_unchecked_cast!($i => Bipolar, |value| match i8::try_from(value) {
+            Ok(value) if value < 0 => Bipolar::MIN,
+            Ok(0) => Bipolar::ZERO,
+            Ok(_) => Bipolar::MAX,
+            Err(_) if value > 0 => Bipolar::MAX,
+            Err(_) => Bipolar::MIN,
+        });
+    )*};
+}
+
+impl_integer_casts!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Rescales a raw value from `from_max` to `to_max`, rounding to the nearest value.
+fn rescale(raw: u64, from_max: u64, to_max: u64) -> u64 {
+    ((raw as u128 * to_max as u128 * 2 + from_max as u128) / (from_max as u128 * 2)) as u64
+}
+
+impl_lossless_cast!(Primant => Bipolar, |value| {
+    Bipolar::from_raw(rescale(value.to_raw() as u64, u32::MAX as u64, i32::MAX as u64) as i32)
+});
+
+impl CheckedCast<Primant> for Bipolar {
+    fn checked_cast(self) -> Option<Primant> {
+        let raw = u32::try_from(self.to_raw()).ok()?;
+        Some(Primant::from_raw(rescale(raw as u64, i32::MAX as u64, u32::MAX as u64) as u32))
+    }
+}
+
+impl_unchecked_cast!(Bipolar => Primant, |value| value.checked_cast().unwrap_or(Primant::ZERO));
+
+impl_lossless_cast!(Phase => Primant, |value| {
+    Primant::from_raw(rescale(value.to_raw() as u64, 1 << 32, u32::MAX as u64) as u32)
+});
+
+impl CheckedCast<Phase> for Primant {
+    fn checked_cast(self) -> Option<Phase> {
+        let raw = rescale(self.to_raw() as u64, u32::MAX as u64, 1 << 32);
+        u32::try_from(raw).ok().map(Phase::from_raw)
+    }
+}
+
+impl_unchecked_cast!(Primant => Phase, |value| value.checked_cast().unwrap_or(Phase::MAX));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_casts() {
+        assert_eq!(Cast::<u8>::cast(Primant::MAX), 1);
+        assert_eq!(Cast::<i64>::cast(Primant::from_raw(u32::MAX - 1)), 0);
+        assert_eq!(Cast::<Primant>::cast(1u128), Primant::MAX);
+        assert_eq!(SaturatingCast::<Primant>::saturating_cast(-3i32), Primant::ZERO);
+        assert_eq!(CheckedCast::<u16>::checked_cast(Bipolar::MIN), None);
+        assert_eq!(CheckedCast::<i16>::checked_cast(Bipolar::MIN), Some(-1));
+        assert_eq!(SaturatingCast::<u16>::saturating_cast(Bipolar::MIN), 0);
+        assert_eq!(CheckedCast::<Bipolar>::checked_cast(-1i8), Some(Bipolar::MIN));
+        assert_eq!(CheckedCast::<Bipolar>::checked_cast(u128::MAX), None);
+        assert_eq!(SaturatingCast::<Bipolar>::saturating_cast(u128::MAX), Bipolar::MAX);
+        assert_eq!(SaturatingCast::<Bipolar>::saturating_cast(i64::MIN), Bipolar::MIN);
+    }
+
+    #[test]
+    fn test_float_casts() {
+        assert_eq!(Cast::<f64>::cast(Bipolar::MIN), -1.0);
+        assert_eq!(CheckedCast::<Phase>::checked_cast(1.0f64), None);
+        assert_eq!(CheckedCast::<Primant>::checked_cast(f32::NAN), None);
+        assert_eq!(SaturatingCast::<Bipolar>::saturating_cast(-2.0f64), Bipolar::MIN);
+    }
+
+    #[test]
+    fn test_fraction_casts() {
+        assert_eq!(Cast::<Bipolar>::cast(Primant::MAX), Bipolar::MAX);
+        assert_eq!(Cast::<Primant>::cast(Bipolar::MAX), Primant::MAX);
+        assert_eq!(CheckedCast::<Primant>::checked_cast(Bipolar::from_raw(-1)), None);
+        assert_eq!(Cast::<Primant>::cast(Phase::from_raw(1 << 31)), Primant::from_raw(1 << 31));
+        assert_eq!(Cast::<Phase>::cast(Primant::from_raw(u32::MAX / 2)), Phase::from_raw(u32::MAX / 2));
+        assert_eq!(CheckedCast::<Phase>::checked_cast(Primant::MAX), None);
+        assert_eq!(SaturatingCast::<Phase>::saturating_cast(Primant::MAX), Phase::MAX);
+    }
+}
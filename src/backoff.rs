@@ -0,0 +1,126 @@
+use core::time::Duration;
+use crate::random::Xorshift32;
+use crate::Primant;
+
+/// An exponential backoff schedule for retrying failed operations.
+///
+/// A [`Backoff`] is an endless iterator of delays. The delay starts at a base
+/// value and doubles with every attempt, up to a cap. A jitter, given as a
+/// [`Primant`], randomly shortens each delay by up to that fraction of it, so that
+/// clients failing at the same time do not retry in lockstep:
+/// - [`Primant::ZERO`] keeps the delays exact;
+/// - one half gives the _equal jitter_ policy, keeping at least half of each delay;
+/// - [`Primant::MAX`] gives the _full jitter_ policy, spreading each delay over its
+///   whole range.
+///
+/// # Examples
+///
+/// ```
+/// # use core::time::Duration;
+/// # use unifrac::{Backoff, Primant};
+/// let backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(1));
+/// let delays: Vec<_> = backoff.take(6).map(|delay| delay.as_millis()).collect();
+/// assert_eq!(delays, [100, 200, 400, 800, 1000, 1000]);
+///
+/// let jittered = Backoff::new(Duration::from_millis(100), Duration::from_secs(1))
+///     .with_jitter(Primant::from_ratio(1u32, 2u32));
+/// for (delay, exact) in jittered.zip(delays).take(6) {
+///     assert!(delay.as_millis() >= exact / 2 && delay.as_millis() <= exact);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Backoff {
+    base: Duration,
+    cap: Duration,
+    jitter: Primant,
+    attempt: u32,
+    random: Xorshift32,
+}
+
+impl Backoff {
+    /// Creates a new [`Backoff`] starting at `base` and doubling up to `cap`,
+    /// without jitter.
+    pub fn new(base: Duration, cap: Duration) -> Self {
+        Backoff {
+            base,
+            cap,
+            jitter: Primant::ZERO,
+            attempt: 0,
+            random: Xorshift32::default(),
+        }
+    }
+
+    /// Sets the largest fraction by which each delay is randomly shortened.
+    pub fn with_jitter(self, jitter: Primant) -> Self {
+        Backoff { jitter, ..self }
+    }
+
+    /// Sets the seed of the pseudo-random generator used for the jitter.
+    ///
+    /// A seed of zero is replaced with a non-zero default.
+    pub fn with_seed(self, seed: u32) -> Self {
+        Backoff { random: Xorshift32::new(seed), ..self }
+    }
+
+    /// Returns the number of delays produced since the last reset.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Restarts the schedule from the base delay, such as after a success.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    /// Returns the delay before jitter for the current attempt.
+    fn exact_delay(&self) -> Duration {
+        let factor = 1u32.checked_shl(self.attempt).unwrap_or(0);
+        match self.base.checked_mul(factor) {
+            Some(delay) if factor != 0 => delay.min(self.cap),
+            _ => self.cap,
+        }
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.exact_delay();
+        self.attempt = self.attempt.saturating_add(1);
+
+        let shortening = self.jitter.scale_u128(self.random.next_u32() as u128, false);
+        let kept = u32::MAX as u128 - shortening;
+        let nanos = Primant::from_raw(kept as u32).scale_u128(delay.as_nanos(), true);
+        Some(Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cap() {
+        let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(60));
+        assert_eq!(backoff.nth(40), Some(Duration::from_secs(60)));
+        assert_eq!(backoff.attempt(), 41);
+        backoff.reset();
+        assert_eq!(backoff.next(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_full_jitter() {
+        let backoff = Backoff::new(Duration::from_millis(10), Duration::from_millis(10))
+            .with_jitter(Primant::MAX)
+            .with_seed(3);
+        let (mut total, mut shortest) = (0, u128::MAX);
+        for delay in backoff.take(1000) {
+            assert!(delay <= Duration::from_millis(10));
+            total += delay.as_micros();
+            shortest = shortest.min(delay.as_micros());
+        }
+        assert!(shortest < 100);
+        assert!((4500..=5500).contains(&(total / 1000)));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{Hysteresis, Primant};
+
+/// The effect of a controlled actuator on the measured value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Turning the actuator on raises the measured value, as with a heater.
+    Raise,
+    /// Turning the actuator on lowers the measured value, as with a cooler.
+    Lower,
+}
+
+/// An on–off controller, such as a thermostat.
+///
+/// A [`BangBang`] controller keeps a measured value around a setpoint by switching
+/// an actuator on and off. To avoid switching too often, the actuator only changes
+/// state once the measurement leaves a band of the given hysteresis width, centered
+/// on the setpoint.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{BangBang, Direction, Primant};
+/// let setpoint = Primant::from_ratio(1u32, 2u32);
+/// let hysteresis = Primant::from_ratio(1u32, 10u32);
+/// let mut heater = BangBang::new(setpoint, hysteresis, Direction::Raise);
+/// assert!(heater.update(Primant::from_ratio(2u32, 5u32)));
+/// assert!(heater.update(Primant::from_ratio(1u32, 2u32)));
+/// assert!(!heater.update(Primant::from_ratio(3u32, 5u32)));
+/// assert!(!heater.update(Primant::from_ratio(1u32, 2u32)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BangBang {
+    comparator: Hysteresis,
+    setpoint: Primant,
+    hysteresis: Primant,
+    direction: Direction,
+}
+
+impl BangBang {
+    /// Creates a new [`BangBang`] controller, with the actuator initially off.
+    pub fn new(setpoint: Primant, hysteresis: Primant, direction: Direction) -> Self {
+        let mut comparator = Hysteresis::around(setpoint, hysteresis);
+        comparator.set_state(direction == Direction::Raise);
+        BangBang {
+            comparator,
+            setpoint,
+            hysteresis,
+            direction,
+        }
+    }
+
+    /// Returns the setpoint.
+    pub fn setpoint(&self) -> Primant {
+        self.setpoint
+    }
+
+    /// Changes the setpoint, keeping the current state of the actuator.
+    pub fn set_setpoint(&mut self, setpoint: Primant) {
+        self.setpoint = setpoint;
+        self.rebuild();
+    }
+
+    /// Returns the width of the hysteresis band.
+    pub fn hysteresis(&self) -> Primant {
+        self.hysteresis
+    }
+
+    /// Changes the width of the hysteresis band, keeping the current state of the
+    /// actuator.
+    pub fn set_hysteresis(&mut self, hysteresis: Primant) {
+        self.hysteresis = hysteresis;
+        self.rebuild();
+    }
+
+    /// Returns the direction in which the actuator affects the measurement.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns whether the actuator is currently on.
+    pub fn is_on(&self) -> bool {
+        self.actuator(self.comparator.state())
+    }
+
+    /// Updates the controller with a new measurement and returns whether the
+    /// actuator should be on.
+    pub fn update(&mut self, measurement: Primant) -> bool {
+        let above = self.comparator.update(measurement);
+        self.actuator(above)
+    }
+
+    /// Converts the state of the comparator, which is on above the band, into the
+    /// state of the actuator.
+    fn actuator(&self, above: bool) -> bool {
+        match self.direction {
+            Direction::Raise => !above,
+            Direction::Lower => above,
+        }
+    }
+
+    /// Rebuilds the comparator after a change of its thresholds.
+    fn rebuild(&mut self) {
+        let state = self.comparator.state();
+        self.comparator = Hysteresis::around(self.setpoint, self.hysteresis);
+        self.comparator.set_state(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooling() {
+        let mut cooler = BangBang::new(Primant::from_ratio(1u32, 2u32), Primant::from_ratio(1u32, 5u32), Direction::Lower);
+        assert!(!cooler.update(Primant::from_ratio(11u32, 20u32)));
+        assert!(cooler.update(Primant::from_ratio(3u32, 5u32)));
+        assert!(cooler.update(Primant::from_ratio(9u32, 20u32)));
+        assert!(!cooler.update(Primant::from_ratio(2u32, 5u32)));
+    }
+}
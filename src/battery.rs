@@ -0,0 +1,136 @@
+use crate::{Lerp, Primant};
+
+/// A discharge curve mapping the voltage of a battery cell to its state of charge.
+///
+/// The curve is defined by breakpoints of a cell voltage, in millivolts, and the
+/// corresponding state of charge, with the voltages strictly increasing. Between
+/// breakpoints, the state of charge is interpolated linearly; below the first and
+/// above the last breakpoint, it is clamped.
+///
+/// Presets are provided for common chemistries. They describe a single cell at rest
+/// or under light load, so the voltage of a battery pack should be divided by its
+/// number of cells in series first. The presets are typical values only; for
+/// accurate readings, measure the discharge curve of the actual cell.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{BatteryCurve, Primant};
+/// let charge = BatteryCurve::LIPO.state_of_charge(3840);
+/// assert_eq!(charge.percent_of(100u32), 50);
+/// assert_eq!(BatteryCurve::LIPO.state_of_charge(4300), Primant::MAX);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatteryCurve<'a> {
+    points: &'a [(u32, Primant)],
+}
+
+/// Returns the [`Primant`] closest to the given whole percentage.
+const fn percent(value: u32) -> Primant {
+    Primant::from_raw(((value as u64 * u32::MAX as u64 + 50) / 100) as u32)
+}
+
+impl BatteryCurve<'static> {
+    /// A lithium-polymer or lithium-ion cell, with a nominal voltage of 3.7 V and a
+    /// full charge at 4.2 V.
+    pub const LIPO: BatteryCurve<'static> = BatteryCurve::new(&[
+        (3270, percent(0)),
+        (3610, percent(5)),
+        (3690, percent(10)),
+        (3730, percent(20)),
+        (3770, percent(30)),
+        (3800, percent(40)),
+        (3840, percent(50)),
+        (3870, percent(60)),
+        (3950, percent(70)),
+        (4020, percent(80)),
+        (4110, percent(90)),
+        (4150, percent(95)),
+        (4200, percent(100)),
+    ]);
+
+    /// A lithium iron phosphate cell, with a nominal voltage of 3.2 V and a full
+    /// charge at 3.4 V at rest.
+    pub const LIFEPO4: BatteryCurve<'static> = BatteryCurve::new(&[
+        (2500, percent(0)),
+        (3000, percent(9)),
+        (3200, percent(14)),
+        (3220, percent(17)),
+        (3250, percent(20)),
+        (3260, percent(30)),
+        (3270, percent(40)),
+        (3300, percent(70)),
+        (3320, percent(90)),
+        (3350, percent(99)),
+        (3400, percent(100)),
+    ]);
+
+    /// An alkaline cell, with a nominal voltage of 1.5 V.
+    pub const ALKALINE: BatteryCurve<'static> = BatteryCurve::new(&[
+        (900, percent(0)),
+        (1050, percent(5)),
+        (1150, percent(20)),
+        (1220, percent(40)),
+        (1280, percent(60)),
+        (1350, percent(80)),
+        (1450, percent(95)),
+        (1550, percent(100)),
+    ]);
+}
+
+impl<'a> BatteryCurve<'a> {
+    /// Creates a new [`BatteryCurve`] from breakpoints of a cell voltage, in
+    /// millivolts, and the corresponding state of charge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no breakpoints, or if the voltages are not strictly
+    /// increasing.
+    pub const fn new(points: &'a [(u32, Primant)]) -> Self {
+        assert!(!points.is_empty(), "breakpoints must not be empty");
+        let mut i = 1;
+        while i < points.len() {
+            assert!(points[i].0 > points[i - 1].0, "voltages must be strictly increasing");
+            i += 1;
+        }
+        BatteryCurve { points }
+    }
+
+    /// Returns the breakpoints of the curve.
+    pub const fn points(&self) -> &'a [(u32, Primant)] {
+        self.points
+    }
+
+    /// Returns the state of charge of a cell with the given voltage, in millivolts.
+    pub fn state_of_charge(&self, millivolts: u32) -> Primant {
+        let index = self.points.partition_point(|&(voltage, _)| voltage <= millivolts);
+        if index == 0 { return self.points[0].1; }
+        if index == self.points.len() { return self.points[index - 1].1; }
+
+        let ((v0, charge0), (v1, charge1)) = (self.points[index - 1], self.points[index]);
+        let t = Primant::ratio_of((millivolts - v0) as u64, (v1 - v0) as u64);
+        charge0.lerp(charge1, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        for curve in [BatteryCurve::LIPO, BatteryCurve::LIFEPO4, BatteryCurve::ALKALINE] {
+            assert_eq!(curve.state_of_charge(0), Primant::ZERO);
+            assert_eq!(curve.state_of_charge(u32::MAX), Primant::MAX);
+            assert!(curve.points().windows(2).all(|pair| pair[1].1 >= pair[0].1));
+        }
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let points = [(1000, Primant::ZERO), (2000, Primant::MAX)];
+        let curve = BatteryCurve::new(&points);
+        assert_eq!(curve.state_of_charge(1500), Primant::ratio_of(1, 2));
+        assert_eq!(curve.state_of_charge(2000), Primant::MAX);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::Phase;
+
+/// A musical clock converting elapsed ticks into beat and bar phases.
+///
+/// The clock accumulates elapsed time as an exact integer count of beat fractions,
+/// so it does not drift no matter how long it runs, and tempo changes take effect
+/// from the current position without a jump.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{BeatClock, Phase};
+/// // 120 BPM in 4/4 time, with a 1 kHz tick.
+/// let mut clock = BeatClock::new(120, 1000, 4);
+/// clock.advance(750);
+/// assert_eq!(clock.beat(), Phase::from_raw(1 << 31));
+/// assert_eq!(clock.bar(), Phase::from_raw(3 << 29));
+/// assert_eq!(clock.beat_in_bar(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BeatClock {
+    bpm: u32,
+    ticks_per_second: u32,
+    beats_per_bar: u32,
+    /// The elapsed number of beats, multiplied by the number of ticks per minute.
+    position: u128,
+}
+
+impl BeatClock {
+    /// Creates a new [`BeatClock`] at the start of the first bar.
+    ///
+    /// `bpm` is the tempo in beats per minute, `ticks_per_second` is the rate at
+    /// which ticks are counted (such as a sample rate or a timer frequency), and
+    /// `beats_per_bar` is the numerator of the time signature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ticks_per_second` or `beats_per_bar` is zero.
+    pub fn new(bpm: u32, ticks_per_second: u32, beats_per_bar: u32) -> Self {
+        assert_ne!(ticks_per_second, 0, "ticks per second must not be zero");
+        assert_ne!(beats_per_bar, 0, "beats per bar must not be zero");
+        BeatClock {
+            bpm,
+            ticks_per_second,
+            beats_per_bar,
+            position: 0,
+        }
+    }
+
+    /// Returns the tempo in beats per minute.
+    pub fn bpm(&self) -> u32 {
+        self.bpm
+    }
+
+    /// Changes the tempo, starting from the current position.
+    pub fn set_bpm(&mut self, bpm: u32) {
+        self.bpm = bpm;
+    }
+
+    /// Advances the clock by the given number of ticks.
+    pub fn advance(&mut self, ticks: u64) {
+        self.position += ticks as u128 * self.bpm as u128;
+    }
+
+    /// Moves the clock back to the start of the first bar.
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    /// Returns the position within the current beat.
+    pub fn beat(&self) -> Phase {
+        Phase::from_time_u128(self.position, self.ticks_per_beat())
+    }
+
+    /// Returns the position within the current bar.
+    pub fn bar(&self) -> Phase {
+        Phase::from_time_u128(self.position, self.ticks_per_beat() * self.beats_per_bar as u128)
+    }
+
+    /// Returns the number of whole beats elapsed since the start.
+    pub fn beats(&self) -> u64 {
+        (self.position / self.ticks_per_beat()) as u64
+    }
+
+    /// Returns the number of whole bars elapsed since the start.
+    pub fn bars(&self) -> u64 {
+        self.beats() / self.beats_per_bar as u64
+    }
+
+    /// Returns the index of the current beat within the current bar, starting at zero.
+    pub fn beat_in_bar(&self) -> u32 {
+        (self.beats() % self.beats_per_bar as u64) as u32
+    }
+
+    /// Returns the length of a beat, in the units of `position`.
+    fn ticks_per_beat(&self) -> u128 {
+        self.ticks_per_second as u128 * 60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_drift() {
+        // 44.1 kHz at 97 BPM does not divide evenly into beats.
+        let mut clock = BeatClock::new(97, 44_100, 3);
+        for _ in 0..97 * 60 {
+            clock.advance(44_100);
+        }
+        assert_eq!(clock.beat(), Phase::MIN);
+        assert_eq!(clock.beats(), 97 * 97);
+        assert_eq!(clock.beat_in_bar(), (97 * 97 % 3) as u32);
+    }
+
+    #[test]
+    fn test_tempo_change() {
+        let mut clock = BeatClock::new(60, 100, 4);
+        clock.advance(50);
+        assert_eq!(clock.beat(), Phase::from_raw(1 << 31));
+        clock.set_bpm(120);
+        clock.advance(25);
+        assert_eq!(clock.beat(), Phase::MIN);
+        assert_eq!(clock.beats(), 1);
+        assert_eq!(clock.bar(), Phase::from_raw(1 << 30));
+    }
+}
@@ -0,0 +1,296 @@
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::Primant;
+
+/// A fraction between 0 and 1 (inclusive), with an arbitrary number of fractional
+/// bits.
+///
+/// A [`BigPrimant`] is a reference-precision type, meant for validating the
+/// fixed-width math of the other types, or for long computations that must not
+/// accumulate any error. Products are exact, as their fractional bits add up, and
+/// every other operation rounds to the nearest value, with ties rounded up.
+///
+/// This type is only available with the `alloc` feature.
+///
+/// # Representation
+///
+/// A [`BigPrimant`] with `bits` fractional bits is represented as a numerator
+/// over `2^bits`. Since [`Primant`] is not a binary fraction, converting one to a
+/// [`BigPrimant`] rounds it, to well below the precision of a [`Primant`] with
+/// enough bits.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{BigPrimant, Primant};
+/// let values = [BigPrimant::from_primant(Primant::ZERO, 64), BigPrimant::from_primant(Primant::MAX, 64)];
+/// let mean = BigPrimant::mean(&values, 64).unwrap();
+/// assert_eq!(mean.to_primant(), Primant::from_raw(1 << 31));
+/// ```
+#[derive(Clone)]
+pub struct BigPrimant {
+    /// The 32-bit limbs of the numerator, least significant first, without
+    /// trailing zeros.
+    limbs: Vec<u32>,
+    bits: u32,
+}
+
+/// Construction and conversion functions.
+impl BigPrimant {
+    /// Returns zero with `bits` fractional bits.
+    pub const fn zero(bits: u32) -> Self {
+        BigPrimant { limbs: Vec::new(), bits }
+    }
+
+    /// Returns one with `bits` fractional bits.
+    pub fn one(bits: u32) -> Self {
+        BigPrimant { limbs: shl(&[1], bits), bits }
+    }
+
+    /// Converts a [`Primant`] to a [`BigPrimant`] with `bits` fractional bits,
+    /// rounding to the nearest value.
+    pub fn from_primant(value: Primant, bits: u32) -> Self {
+        let (quotient, _) = div_small(&shl(&[value.to_raw()], bits + 1), u32::MAX as u64);
+        BigPrimant { limbs: shr_round(&quotient, 1), bits }
+    }
+
+    /// Returns the nearest [`Primant`].
+    pub fn to_primant(&self) -> Primant {
+        let raw = shr_round(&mul_small(&self.limbs, u32::MAX), self.bits);
+        Primant::from_raw(raw.first().copied().unwrap_or(0))
+    }
+
+    /// Returns the number of fractional bits.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Returns the value with `bits` fractional bits, rounding to the nearest value
+    /// if there are fewer bits than before.
+    pub fn with_bits(&self, bits: u32) -> Self {
+        let limbs = if bits >= self.bits {
+            shl(&self.limbs, bits - self.bits)
+        } else {
+            shr_round(&self.limbs, self.bits - bits)
+        };
+        BigPrimant { limbs, bits }
+    }
+}
+
+/// Arithmetic functions.
+impl BigPrimant {
+    /// Multiplies two [`BigPrimant`]s exactly.
+    ///
+    /// The product has as many fractional bits as both factors together, so use
+    /// [`BigPrimant::with_bits`] to keep long products from growing.
+    pub fn mul(&self, other: &BigPrimant) -> Self {
+        BigPrimant {
+            limbs: mul(&self.limbs, &other.limbs),
+            bits: self.bits + other.bits,
+        }
+    }
+
+    /// Returns the mean of `values` with `bits` fractional bits, rounded to the
+    /// nearest value, or [`None`] if there are no values.
+    ///
+    /// The sum is exact, so the only rounding is in the final division.
+    pub fn mean(values: &[BigPrimant], bits: u32) -> Option<Self> {
+        let common = values.iter().map(|value| value.bits).max()?;
+        let mut sum = Vec::new();
+        for value in values {
+            sum = add(&sum, &shl(&value.limbs, common - value.bits));
+        }
+        // Dividing with one more bit, then rounding it off, rounds the division to
+        // the nearest value.
+        let (quotient, _) = div_small(&shl(&sum, bits + 1), values.len() as u64);
+        Some(BigPrimant { limbs: shr_round(&quotient, common + 1), bits })
+    }
+}
+
+impl PartialEq for BigPrimant {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigPrimant {}
+
+impl PartialOrd for BigPrimant {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigPrimant {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let common = self.bits.max(other.bits);
+        cmp(&shl(&self.limbs, common - self.bits), &shl(&other.limbs, common - other.bits))
+    }
+}
+
+impl From<Primant> for BigPrimant {
+    /// Converts a [`Primant`] with 64 fractional bits.
+    fn from(value: Primant) -> Self {
+        BigPrimant::from_primant(value, 64)
+    }
+}
+
+impl Debug for BigPrimant {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("BigPrimant(")?;
+        #[cfg(feature = "float")]
+        {
+            let top = self.with_bits(64).limbs;
+            let numerator = top.iter().rev().fold(0u128, |acc, &limb| (acc << 32) | limb as u128);
+            write!(f, "{}", numerator as f64 / 18_446_744_073_709_551_616.0)?;
+        }
+        #[cfg(not(feature = "float"))]
+        {
+            let top = self.with_bits(32).limbs;
+            let numerator = top.iter().rev().fold(0u128, |acc, &limb| (acc << 32) | limb as u128);
+            crate::format::write_ratio(f, numerator, 1 << 32, 1, 10, "", 0)?;
+        }
+        if f.alternate() {
+            write!(f, " with {} fractional bits", self.bits)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Removes the trailing zero limbs.
+fn trim(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+/// Returns `value * 2^shift`.
+fn shl(value: &[u32], shift: u32) -> Vec<u32> {
+    if value.is_empty() { return Vec::new(); }
+    let (words, offset) = ((shift / 32) as usize, shift % 32);
+    let mut result = vec![0; words + value.len() + 1];
+    for (index, &limb) in value.iter().enumerate() {
+        let wide = (limb as u64) << offset;
+        result[words + index] |= wide as u32;
+        result[words + index + 1] |= (wide >> 32) as u32;
+    }
+    trim(result)
+}
+
+/// Returns `value / 2^shift`, rounded to the nearest integer, with ties rounded up.
+fn shr_round(value: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 { return value.to_vec(); }
+    let (words, offset) = ((shift / 32) as usize, shift % 32);
+    let half = (shift - 1) as usize;
+    let round_up = value.get(half / 32).is_some_and(|limb| limb >> (half % 32) & 1 == 1);
+
+    let mut result = Vec::with_capacity(value.len().saturating_sub(words));
+    for index in words..value.len() {
+        let wide = value[index] as u64 | (value.get(index + 1).copied().unwrap_or(0) as u64) << 32;
+        result.push((wide >> offset) as u32);
+    }
+    let result = trim(result);
+    if round_up { add(&result, &[1]) } else { result }
+}
+
+/// Returns `a + b`.
+fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for index in 0..a.len().max(b.len()) {
+        let sum = a.get(index).copied().unwrap_or(0) as u64 + b.get(index).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+    trim(result)
+}
+
+/// Returns `a * b`.
+fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    trim(result)
+}
+
+/// Returns `value * factor`.
+fn mul_small(value: &[u32], factor: u32) -> Vec<u32> {
+    mul(value, &[factor])
+}
+
+/// Returns `value / divisor` rounded down, and the remainder.
+fn div_small(value: &[u32], divisor: u64) -> (Vec<u32>, u64) {
+    let mut result = vec![0u32; value.len()];
+    let mut remainder = 0u128;
+    for index in (0..value.len()).rev() {
+        let current = remainder << 32 | value[index] as u128;
+        result[index] = (current / divisor as u128) as u32;
+        remainder = current % divisor as u128;
+    }
+    (trim(result), remainder as u64)
+}
+
+/// Compares two trimmed values.
+fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lerp;
+
+    #[test]
+    fn test_primant_round_trip() {
+        for raw in [0, 1, 2, 12345, u32::MAX / 2, u32::MAX - 1, u32::MAX] {
+            let value = Primant::from_raw(raw);
+            assert_eq!(BigPrimant::from_primant(value, 40).to_primant(), value);
+        }
+        assert_eq!(BigPrimant::from_primant(Primant::MAX, 100), BigPrimant::one(100));
+        assert_eq!(BigPrimant::from_primant(Primant::ZERO, 7), BigPrimant::zero(7));
+    }
+
+    #[test]
+    fn test_with_bits() {
+        let three_quarters = BigPrimant::from_primant(Primant::from_ratio(3u32, 4u32), 2);
+        assert_eq!(three_quarters.limbs, [3]);
+        assert_eq!(three_quarters.with_bits(1), BigPrimant::one(0));
+        assert_eq!(three_quarters.with_bits(70), three_quarters);
+        assert!(BigPrimant::one(3) > three_quarters);
+    }
+
+    #[test]
+    fn test_mul_matches_lerp() {
+        // Scaling a value by a fraction is a linear interpolation from zero.
+        let raws = [0, 1, 3, 12_345_678, u32::MAX / 3, u32::MAX / 2, u32::MAX - 1, u32::MAX];
+        for &a in &raws {
+            for &b in &raws {
+                let (a, b) = (Primant::from_raw(a), Primant::from_raw(b));
+                let exact = BigPrimant::from_primant(a, 96).mul(&BigPrimant::from_primant(b, 96));
+                assert_eq!(exact.bits(), 192);
+                assert_eq!(exact.to_primant(), Primant::ZERO.lerp(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(BigPrimant::mean(&[], 32), None);
+        let values = [BigPrimant::one(3), BigPrimant::zero(90), BigPrimant::zero(1)];
+        let mean = BigPrimant::mean(&values, 2).unwrap();
+        // One third rounds to one quarter with two bits.
+        assert_eq!(mean.limbs, [1]);
+        assert_eq!(BigPrimant::mean(&values, 100).unwrap().to_primant(), Primant::from_raw(u32::MAX / 3));
+    }
+}
@@ -0,0 +1,381 @@
+use core::fmt::{Debug, Display};
+use core::str::FromStr;
+#[cfg(feature = "float")]
+use num_traits::float::FloatCore;
+use crate::{format, parse, shaping, ParsePrimantError, Primant};
+
+/// A fraction between -1 and 1 (inclusive).
+///
+/// This type is useful for representing a signed, normalized value, such as an
+/// audio sample, the output of an oscillator, or the position of a joystick axis.
+///
+/// # Naming
+///
+/// _Bipolar_ is the term used in signal processing and synthesizers for a signal
+/// swinging both below and above zero, as opposed to a _unipolar_ one, like a
+/// [`Primant`].
+///
+/// # Representation
+///
+/// A [`Bipolar`] is represented as a 32-bit signed integer.
+/// The value `0` represents `0.0`, and the maximum value represents `1.0`.
+/// The representation is symmetric, so `-i32::MAX` represents `-1.0`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Bipolar(i32);
+
+/// Raw conversion functions.
+///
+/// These functions should never panic.
+impl Bipolar {
+    pub const MIN: Bipolar = Bipolar(-i32::MAX);
+    pub const ZERO: Bipolar = Bipolar(0);
+    pub const MAX: Bipolar = Bipolar(i32::MAX);
+
+    /// Creates a new [`Bipolar`] from a raw representation.
+    ///
+    /// As the representation is symmetric, `i32::MIN` saturates to [`Bipolar::MIN`].
+    pub const fn from_raw(value: i32) -> Self {
+        Bipolar(if value == i32::MIN { -i32::MAX } else { value })
+    }
+
+    /// Returns the raw representation of the [`Bipolar`].
+    pub const fn to_raw(self) -> i32 {
+        self.0
+    }
+}
+
+#[cfg(feature = "float")]
+impl TryFrom<f32> for Bipolar {
+    type Error = ();
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::try_from_float(value).ok_or(())
+    }
+}
+
+#[cfg(feature = "float")]
+impl TryFrom<f64> for Bipolar {
+    type Error = ();
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_from_float(value).ok_or(())
+    }
+}
+
+#[cfg(feature = "float")]
+impl From<Bipolar> for f32 {
+    fn from(value: Bipolar) -> Self {
+        value.0 as f32 / i32::MAX as f32
+    }
+}
+
+#[cfg(feature = "float")]
+impl From<Bipolar> for f64 {
+    fn from(value: Bipolar) -> Self {
+        value.0 as f64 / i32::MAX as f64
+    }
+}
+
+/// Generic conversion functions to and from floating-point numbers.
+///
+/// These functions are only available with the `float` feature.
+#[cfg(feature = "float")]
+impl Bipolar {
+    /// Creates a new [`Bipolar`] from a floating-point value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not in the range `-1.0..=1.0`.
+    pub fn from_float<T: FloatCore>(value: T) -> Self {
+        assert!(value >= -T::one() && value <= T::one(), "value must be in the range -1.0..=1.0");
+        Self::from_float_in_range(value)
+    }
+
+    /// Creates a new [`Bipolar`] from a floating-point value.
+    ///
+    /// Returns `None` if the value is not in the range `-1.0..=1.0`.
+    pub fn try_from_float<T: FloatCore>(value: T) -> Option<Self> {
+        if value < -T::one() || value > T::one() { return None; }
+        Some(Self::from_float_in_range(value))
+    }
+
+    /// Creates a new [`Bipolar`] from a floating-point value.
+    ///
+    /// If the value is not in the range `-1.0..=1.0`, it saturates to the closest
+    /// representable value.
+    pub fn from_float_saturating<T: FloatCore>(value: T) -> Self {
+        Self::from_float_in_range(value.clamp(-T::one(), T::one()))
+    }
+
+    /// Returns the value as a floating-point number.
+    pub fn into_float<T: FloatCore>(self) -> T {
+        T::from(self.0).unwrap() / T::from(i32::MAX).unwrap()
+    }
+
+    /// Converts a floating-point value in the range `-1.0..=1.0` to a [`Bipolar`].
+    ///
+    /// The scaled value is clamped, as `i32::MAX` is not exactly representable in
+    /// single precision.
+    fn from_float_in_range<T: FloatCore>(value: T) -> Self {
+        let value = (value * T::from(i32::MAX).unwrap()).to_i64().unwrap();
+        Bipolar(value.clamp(-i32::MAX as i64, i32::MAX as i64) as i32)
+    }
+}
+
+/// Generic conversion functions to and from [`Float`](num_traits::Float) types.
+///
+/// These accept types implementing [`Float`](num_traits::Float) but not
+/// [`FloatCore`], such as wrapper or scalar types of other crates. They are only
+/// available with the `float` feature, along with either `std` or `libm`.
+#[cfg(all(feature = "float", any(feature = "std", feature = "libm")))]
+impl Bipolar {
+    /// Creates a new [`Bipolar`] from a floating-point value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not in the range `-1.0..=1.0`.
+    pub fn from_real<T: num_traits::Float>(value: T) -> Self {
+        Self::from_float(value.to_f64().unwrap())
+    }
+
+    /// Creates a new [`Bipolar`] from a floating-point value.
+    ///
+    /// Returns `None` if the value is not in the range `-1.0..=1.0`.
+    pub fn try_from_real<T: num_traits::Float>(value: T) -> Option<Self> {
+        Self::try_from_float(value.to_f64()?)
+    }
+
+    /// Creates a new [`Bipolar`] from a floating-point value.
+    ///
+    /// If the value is not in the range `-1.0..=1.0`, it saturates to the closest
+    /// representable value.
+    pub fn from_real_saturating<T: num_traits::Float>(value: T) -> Self {
+        Self::from_float_saturating(value.to_f64().unwrap())
+    }
+
+    /// Returns the value as a floating-point number.
+    pub fn into_real<T: num_traits::Float>(self) -> T {
+        T::from(self.into_float::<f64>()).unwrap()
+    }
+}
+
+/// Conversion functions to and from unipolar values.
+impl Bipolar {
+    /// Creates a new [`Bipolar`] by mapping the range of a [`Primant`] onto the
+    /// range of a [`Bipolar`], so that `0.0` becomes `-1.0`, `0.5` becomes `0.0`,
+    /// and `1.0` stays `1.0`.
+    ///
+    /// The result is rounded to the nearest representable value, and the endpoints
+    /// are mapped exactly.
+    pub fn from_unipolar(value: Primant) -> Self {
+        let (unipolar_max, bipolar_max) = (u32::MAX as i128, i32::MAX as i128);
+        let offset = 2 * value.to_raw() as i128 - unipolar_max;
+        let scaled = (2 * offset * bipolar_max + unipolar_max).div_euclid(2 * unipolar_max);
+        Bipolar(scaled as i32)
+    }
+
+    /// Maps this value onto the range of a [`Primant`], so that `-1.0` becomes
+    /// `0.0`, `0.0` becomes `0.5`, and `1.0` stays `1.0`.
+    ///
+    /// This is the inverse of [`Bipolar::from_unipolar`]: converting a [`Bipolar`]
+    /// to a [`Primant`] and back always yields the original value.
+    pub fn to_unipolar(self) -> Primant {
+        let (unipolar_max, bipolar_max) = (u32::MAX as u128, i32::MAX as u128);
+        let offset = (self.0 as i64 + bipolar_max as i64) as u128;
+        Primant::from_raw(((offset * unipolar_max + bipolar_max) / (2 * bipolar_max)) as u32)
+    }
+}
+
+/// Conversion functions to servo pulse widths.
+impl Bipolar {
+    /// Returns the width of an RC servo pulse, in microseconds, for this position.
+    ///
+    /// [`Bipolar::ZERO`] maps to exactly `center_us`, and [`Bipolar::MIN`] and
+    /// [`Bipolar::MAX`] to exactly `center_us - span_us` and `center_us + span_us`,
+    /// with the positions in between rounded to the nearest microsecond. The result
+    /// saturates at the bounds of [`u32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Bipolar;
+    /// assert_eq!(Bipolar::MIN.to_servo_us(1500, 500), 1000);
+    /// assert_eq!(Bipolar::ZERO.to_servo_us(1500, 500), 1500);
+    /// assert_eq!(Bipolar::from_raw(i32::MAX / 2).to_servo_us(1500, 500), 1750);
+    /// ```
+    pub fn to_servo_us(self, center_us: u32, span_us: u32) -> u32 {
+        let max = i32::MAX as u64;
+        let offset = (self.0.unsigned_abs() as u64 * span_us as u64 + max / 2) / max;
+        if self.0 < 0 {
+            center_us.saturating_sub(offset as u32)
+        } else {
+            center_us.saturating_add(offset as u32)
+        }
+    }
+}
+
+/// Response shaping, such as for conditioning the input of a control.
+impl Bipolar {
+    /// Applies a dead zone around zero, mapping values whose magnitude is up to
+    /// `threshold` to zero and stretching the rest of the range so that both
+    /// endpoints stay the same.
+    pub fn deadzone(self, threshold: Primant) -> Self {
+        let magnitude = shaping::deadzone(self.0.unsigned_abs() as u64, i32::MAX as u64, threshold);
+        Bipolar(magnitude as i32 * self.0.signum())
+    }
+
+    /// Applies an exponential response curve symmetrically around zero, making the
+    /// value less sensitive near the center while keeping both endpoints.
+    ///
+    /// The result is `(1 - amount)·x + amount·x³`, so [`Primant::ZERO`] keeps the
+    /// response linear and [`Primant::MAX`] makes it fully cubic.
+    pub fn expo(self, amount: Primant) -> Self {
+        let magnitude = shaping::expo(self.0.unsigned_abs() as u64, i32::MAX as u64, amount);
+        Bipolar(magnitude as i32 * self.0.signum())
+    }
+}
+
+/// Parsing functions.
+impl Bipolar {
+    /// Parses a decimal number with an optional sign, optionally followed by the
+    /// suffix of a [`PartsUnit`](crate::PartsUnit), such as `"-0.5"` or `"+25%"`.
+    ///
+    /// This accepts the same units as [`Primant::parse`], and the result is the
+    /// nearest representable value, with ties rounded away from zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParsePrimantError`] if the string is not a valid decimal with a
+    /// known unit, or if the magnitude of the value is greater than one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{Bipolar, ParsePrimantError};
+    /// assert_eq!(Bipolar::parse("-100%"), Ok(Bipolar::MIN));
+    /// assert_eq!("+1".parse(), Ok(Bipolar::MAX));
+    /// assert_eq!(Bipolar::parse("-2"), Err(ParsePrimantError::OutOfRange));
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, ParsePrimantError> {
+        parse::parse_bipolar(text)
+    }
+}
+
+/// Parses a [`Bipolar`] the same way as [`Bipolar::parse`].
+impl FromStr for Bipolar {
+    type Err = ParsePrimantError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse::parse_bipolar(text)
+    }
+}
+
+/// Parses a [`Bipolar`] the same way as [`Bipolar::parse`], for generic conversion
+/// bounds such as `T: TryFrom<&str>`.
+impl TryFrom<&str> for Bipolar {
+    type Error = ParsePrimantError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        parse::parse_bipolar(text)
+    }
+}
+
+/// Prints the approximate value, or with the alternate flag (`{:#?}`), also the exact
+/// raw value.
+impl Debug for Bipolar {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        f.write_str("Bipolar(")?;
+        #[cfg(feature = "float")]
+        write!(f, "{}", f64::from(*self))?;
+        #[cfg(not(feature = "float"))]
+        {
+            f.write_str(sign)?;
+            format::write_ratio(f, self.0.unsigned_abs() as u128, i32::MAX as u128, 1, 10, "", 0)?;
+        }
+        if f.alternate() {
+            write!(f, " ≈ raw {}{:#010x}", sign, self.0.unsigned_abs())?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl Display for Bipolar {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(if self.0 < 0 { "-" } else { "+" })?;
+        format::write_ratio(f, self.0.unsigned_abs() as u128, i32::MAX as u128, 1, 4, "", 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw() {
+        assert_eq!(Bipolar::from_raw(i32::MIN), Bipolar::MIN);
+        assert_eq!(Bipolar::from_raw(-5).to_raw(), -5);
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_f64_conversion() {
+        let value = Bipolar::try_from(-0.5f64).unwrap();
+        assert!((f64::from(value) + 0.5).abs() < 1e-6);
+        assert_eq!(Bipolar::try_from(1.5f64), Err(()));
+        assert_eq!(Bipolar::from_float_saturating(-2.0f32), Bipolar::MIN);
+        assert_eq!(Bipolar::from_float(1.0f32), Bipolar::MAX);
+    }
+
+    #[test]
+    fn test_shaping() {
+        let quarter = Primant::from_ratio(1u32, 4u32);
+        let half = Bipolar::from_raw(-(i32::MAX / 2 + 1));
+        assert_eq!(Bipolar::from_raw(i32::MAX / 5).deadzone(quarter), Bipolar::ZERO);
+        assert_eq!(Bipolar::MIN.deadzone(quarter), Bipolar::MIN);
+        assert!(half.deadzone(quarter).to_raw().abs_diff(-(i32::MAX / 3)) <= 2);
+        assert_eq!(Bipolar::MIN.expo(Primant::MAX), Bipolar::MIN);
+        assert!(half.expo(Primant::MAX).to_raw().abs_diff(-(i32::MAX / 8)) <= 2);
+    }
+
+    #[test]
+    fn test_unipolar() {
+        assert_eq!(Bipolar::from_unipolar(Primant::ZERO), Bipolar::MIN);
+        assert_eq!(Bipolar::from_unipolar(Primant::MAX), Bipolar::MAX);
+        assert_eq!(Bipolar::MIN.to_unipolar(), Primant::ZERO);
+        assert_eq!(Bipolar::MAX.to_unipolar(), Primant::MAX);
+        assert_eq!(Bipolar::ZERO.to_unipolar(), Primant::from_raw(1 << 31));
+        for raw in (-i32::MAX..i32::MAX).step_by(99_999_989) {
+            let value = Bipolar::from_raw(raw);
+            assert_eq!(Bipolar::from_unipolar(value.to_unipolar()), value);
+        }
+    }
+
+    #[test]
+    fn test_servo() {
+        assert_eq!(Bipolar::MAX.to_servo_us(1500, 500), 2000);
+        assert_eq!(Bipolar::MIN.to_servo_us(1500, 500), 1000);
+        assert_eq!(Bipolar::MIN.to_servo_us(100, 500), 0);
+        assert_eq!(Bipolar::MAX.to_servo_us(u32::MAX, u32::MAX), u32::MAX);
+        assert_eq!(Primant::MAX.to_servo_us(2000, 1000), 1000);
+        assert_eq!(Primant::MAX.to_servo_us(0, u32::MAX), u32::MAX);
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_debug_raw() {
+        extern crate alloc;
+        use alloc::format;
+        assert_eq!(format!("{:#?}", Bipolar::MIN), "Bipolar(-1 ≈ raw -0x7fffffff)");
+        assert_eq!(format!("{:?}", Bipolar::MIN), "Bipolar(-1)");
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate alloc;
+        use alloc::format;
+        assert_eq!(format!("{}", Bipolar::ZERO), "+0.0000");
+        assert_eq!(format!("{}", Bipolar::MIN), "-1.0000");
+        assert_eq!(format!("{}", Bipolar::from_raw(i32::MAX / 2 + 1)), "+0.5000");
+    }
+}
@@ -0,0 +1,162 @@
+use crate::gamma::{exp2, gamma_raw};
+use crate::{Lerp, Nco, Phase, Primant};
+
+/// `log2(e)` in Q32.
+const LOG2_E: i128 = 6196328019;
+
+/// `e^-2` in Q62.
+const E_MINUS_2: u128 = 624123833502197200;
+
+/// The shape of the waveform produced by a [`Breath`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BreathShape {
+    /// A raised cosine, rising and falling symmetrically.
+    RaisedCosine,
+    /// An exponentiated sine, `e^sin`, which lingers near the dark end and peaks
+    /// briefly, like the breathing light of a sleeping device.
+    Exponential,
+}
+
+impl BreathShape {
+    /// Returns the brightness at the given phase, before gamma correction.
+    ///
+    /// The brightness is zero at [`Phase::MIN`] and full in the middle of the cycle.
+    pub fn sample(self, phase: Phase) -> Primant {
+        let (inverted, _) = phase.overflowing_add(Phase::from_raw(1 << 31));
+        let level = inverted.cos();
+        match self {
+            BreathShape::RaisedCosine => level.to_unipolar(),
+            BreathShape::Exponential => {
+                // e^(s - 1), mapped from [e^-2, 1] onto the unit interval.
+                let offset = level.to_raw() as i128 - i32::MAX as i128;
+                let exponent = ((offset * LOG2_E) << 8).div_euclid(i32::MAX as i128);
+                let value = exp2(exponent).saturating_sub(E_MINUS_2);
+                let raw = value * u32::MAX as u128 / ((1 << 62) - E_MINUS_2);
+                Primant::from_raw(raw.min(u32::MAX as u128) as u32)
+            }
+        }
+    }
+}
+
+/// A breathing light effect, slowly fading a light in and out.
+///
+/// A [`Breath`] drives a [`BreathShape`] with an [`Nco`], corrects it for the
+/// nonlinear brightness perception of the eye, and raises it to a floor, so the
+/// light never goes completely dark. The output of each tick is a duty cycle.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Breath, BreathShape, Primant};
+/// let floor = Primant::from_ratio(1u32, 100u32);
+/// let mut breath = Breath::new(4).with_shape(BreathShape::RaisedCosine).with_floor(floor);
+/// assert!(breath.tick() < Primant::from_ratio(1u32, 4u32));
+/// assert_eq!(breath.tick(), Primant::MAX);
+/// breath.tick();
+/// assert_eq!(breath.tick(), floor);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Breath {
+    nco: Nco,
+    shape: BreathShape,
+    floor: Primant,
+    gamma: (u32, u32),
+}
+
+impl Breath {
+    /// Creates a new [`Breath`] with a period of `period_ticks`, an exponential
+    /// shape, a gamma of 2.2, and no floor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period_ticks` is zero.
+    pub fn new(period_ticks: u64) -> Self {
+        Self::from_nco(Nco::new(Phase::from_time(1, period_ticks)))
+    }
+
+    /// Creates a new [`Breath`] driven by the given [`Nco`], with an exponential
+    /// shape, a gamma of 2.2, and no floor.
+    pub fn from_nco(nco: Nco) -> Self {
+        Breath {
+            nco,
+            shape: BreathShape::Exponential,
+            floor: Primant::ZERO,
+            gamma: (22, 10),
+        }
+    }
+
+    /// Sets the shape of the waveform.
+    pub fn with_shape(self, shape: BreathShape) -> Self {
+        Breath { shape, ..self }
+    }
+
+    /// Sets the lowest duty cycle of the output.
+    pub fn with_floor(self, floor: Primant) -> Self {
+        Breath { floor, ..self }
+    }
+
+    /// Sets the gamma used for correcting the brightness, as `numerator /
+    /// denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn with_gamma(self, numerator: u32, denominator: u32) -> Self {
+        assert!(denominator != 0, "denominator must be non-zero");
+        Breath { gamma: (numerator, denominator), ..self }
+    }
+
+    /// Returns a reference to the underlying oscillator.
+    pub fn nco(&self) -> &Nco {
+        &self.nco
+    }
+
+    /// Returns a mutable reference to the underlying oscillator, such as for
+    /// changing the period.
+    pub fn nco_mut(&mut self) -> &mut Nco {
+        &mut self.nco
+    }
+
+    /// Returns the duty cycle at the given phase.
+    pub fn sample(&self, phase: Phase) -> Primant {
+        let level = self.shape.sample(phase).to_raw();
+        let corrected = gamma_raw(level, self.gamma.0, self.gamma.1);
+        self.floor.lerp(Primant::MAX, Primant::from_raw(corrected))
+    }
+
+    /// Advances the oscillator by one tick and returns the duty cycle.
+    pub fn tick(&mut self) -> Primant {
+        let phase = self.nco.tick();
+        self.sample(phase)
+    }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn test_shapes() {
+        for shape in [BreathShape::RaisedCosine, BreathShape::Exponential] {
+            assert_eq!(shape.sample(Phase::MIN), Primant::ZERO);
+            assert_eq!(shape.sample(Phase::from_raw(1 << 31)), Primant::MAX);
+        }
+        let quarter = Phase::from_raw(1 << 30);
+        let expected = ((-1.0f64).exp() - (-2.0f64).exp()) / (1.0 - (-2.0f64).exp());
+        let actual = BreathShape::Exponential.sample(quarter).into_float::<f64>();
+        assert!((actual - expected).abs() < 1e-8);
+        let half = BreathShape::RaisedCosine.sample(quarter).into_float::<f64>();
+        assert!((half - 0.5).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_floor() {
+        let floor = Primant::from_ratio(1u32, 10u32);
+        let breath = Breath::new(100).with_floor(floor).with_gamma(1, 1);
+        assert_eq!(breath.sample(Phase::MIN), floor);
+        let quarter = breath.with_shape(BreathShape::RaisedCosine).sample(Phase::from_raw(1 << 30));
+        assert!((quarter.into_float::<f64>() - 0.55).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::Primant;
+
+/// A calibration mapping raw sensor readings onto a [`Primant`].
+///
+/// A [`Calibration`] stores the range of raw values a sensor produces, such as the
+/// readings of a potentiometer or a joystick axis at its endpoints. The range can be
+/// given upfront, or learned by observing readings with [`Calibration::observe`].
+///
+/// Readings are converted with [`Calibration::normalize`], which clamps them to the
+/// calibrated range. An optional dead zone at both ends of the range makes sure the
+/// endpoints can be reached reliably despite noise.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Calibration, Primant};
+/// let mut calibration = Calibration::auto_ranging();
+/// for reading in [512, 130, 3900, 2000] {
+///     calibration.observe(reading);
+/// }
+/// assert_eq!(calibration.normalize(130), Primant::ZERO);
+/// assert_eq!(calibration.normalize(4095), Primant::MAX);
+///
+/// let calibration = calibration.with_dead_zone(Primant::from_ratio(5u32, 100u32));
+/// assert_eq!(calibration.normalize(250), Primant::ZERO);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Calibration {
+    min: i32,
+    max: i32,
+    dead_zone: Primant,
+}
+
+impl Calibration {
+    /// Creates a new [`Calibration`] with a known range of raw values.
+    ///
+    /// If `min` is greater than `max`, they are swapped.
+    pub fn new(min: i32, max: i32) -> Self {
+        Calibration {
+            min: min.min(max),
+            max: min.max(max),
+            dead_zone: Primant::ZERO,
+        }
+    }
+
+    /// Creates a new [`Calibration`] with no range observed yet.
+    ///
+    /// Until at least two distinct readings have been observed, every reading
+    /// normalizes to [`Primant::ZERO`].
+    pub fn auto_ranging() -> Self {
+        Calibration {
+            min: i32::MAX,
+            max: i32::MIN,
+            dead_zone: Primant::ZERO,
+        }
+    }
+
+    /// Sets the dead zone, as a fraction of the range ignored at each end.
+    ///
+    /// Readings within the dead zone of either end are mapped to that end exactly,
+    /// and the rest of the range is stretched to cover the whole unit interval.
+    pub fn with_dead_zone(self, dead_zone: Primant) -> Self {
+        Calibration { dead_zone, ..self }
+    }
+
+    /// Extends the calibrated range to include `raw`.
+    pub fn observe(&mut self, raw: i32) {
+        self.min = self.min.min(raw);
+        self.max = self.max.max(raw);
+    }
+
+    /// Returns the calibrated range, or `None` if no reading has been observed yet.
+    pub fn range(&self) -> Option<(i32, i32)> {
+        (self.min <= self.max).then_some((self.min, self.max))
+    }
+
+    /// Converts a raw reading to a [`Primant`], clamping it to the calibrated range.
+    pub fn normalize(&self, raw: i32) -> Primant {
+        if self.min >= self.max { return Primant::ZERO; }
+
+        let span = (self.max as i64 - self.min as i64) as u64;
+        let margin = self.dead_zone.scale_u128(span as u128, true) as u64;
+        let (low, high) = (margin, span.saturating_sub(margin));
+        let offset = (raw as i64 - self.min as i64).clamp(0, span as i64) as u64;
+        if offset <= low { return Primant::ZERO; }
+        if offset >= high { return Primant::MAX; }
+        Primant::ratio_of(offset - low, high - low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let calibration = Calibration::new(1000, -1000);
+        assert_eq!(calibration.range(), Some((-1000, 1000)));
+        assert_eq!(calibration.normalize(i32::MIN), Primant::ZERO);
+        assert_eq!(calibration.normalize(i32::MAX), Primant::MAX);
+        assert_eq!(calibration.normalize(0), Primant::ratio_of(1, 2));
+        let calibration = calibration.with_dead_zone(Primant::from_ratio(1u32, 4u32));
+        assert_eq!(calibration.normalize(-500), Primant::ZERO);
+        assert_eq!(calibration.normalize(0), Primant::ratio_of(1, 2));
+        assert_eq!(calibration.normalize(500), Primant::MAX);
+    }
+
+    #[test]
+    fn test_auto_ranging() {
+        let mut calibration = Calibration::auto_ranging();
+        assert_eq!(calibration.range(), None);
+        assert_eq!(calibration.normalize(5), Primant::ZERO);
+        calibration.observe(5);
+        assert_eq!(calibration.normalize(5), Primant::ZERO);
+        calibration.observe(15);
+        assert_eq!(calibration.normalize(10), Primant::ratio_of(1, 2));
+    }
+}
@@ -0,0 +1,183 @@
+use core::iter::FusedIterator;
+use crate::gamma::{exp2, log2};
+use crate::{Nco, Phase};
+
+/// The way a [`Chirp`] sweeps its frequency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChirpShape {
+    /// The frequency changes by the same amount every tick.
+    Linear,
+    /// The frequency changes by the same ratio every tick, so every octave takes
+    /// the same time.
+    Exponential,
+}
+
+/// A frequency sweep, such as a test signal or a sonar ping.
+///
+/// A [`Chirp`] drives an [`Nco`] for a fixed number of ticks, moving its increment
+/// from a start to an end frequency word, and yields the phase after every tick.
+/// The first tick advances by exactly the start increment and the last one by
+/// exactly the end increment.
+///
+/// The increment of every tick is computed from the tick number rather than from
+/// the previous increment, so long sweeps do not drift, and the phase itself is
+/// accumulated exactly.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Chirp, ChirpShape, Phase};
+/// // From 1 Hz to 4 Hz over 4 ticks at 16 Hz.
+/// let chirp = Chirp::from_frequencies(1, 4, 16, 4, ChirpShape::Linear);
+/// let phases: Vec<_> = chirp.map(Phase::to_raw).collect();
+/// assert_eq!(phases, [0x1000_0000, 0x3000_0000, 0x6000_0000, 0xA000_0000]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Chirp {
+    nco: Nco,
+    start: Phase,
+    end: Phase,
+    ticks: u32,
+    tick: u32,
+    shape: ChirpShape,
+}
+
+impl Chirp {
+    /// Creates a new [`Chirp`] starting at [`Phase::MIN`], sweeping the increment
+    /// from `start` to `end` over `ticks` ticks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape is exponential and either increment is zero.
+    pub fn new(start: Phase, end: Phase, ticks: u32, shape: ChirpShape) -> Self {
+        if shape == ChirpShape::Exponential {
+            assert!(start != Phase::MIN && end != Phase::MIN, "exponential chirps must not reach zero frequency");
+        }
+        Chirp {
+            nco: Nco::new(start),
+            start,
+            end,
+            ticks,
+            tick: 0,
+            shape,
+        }
+    }
+
+    /// Creates a new [`Chirp`] sweeping from `start_frequency` to `end_frequency`,
+    /// when ticked at `tick_rate`, over `ticks` ticks.
+    ///
+    /// The rates must be given in the same unit, as for [`Nco::from_frequency`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tick_rate` is zero, or if the shape is exponential and either
+    /// frequency is rounded to zero.
+    pub fn from_frequencies(start_frequency: u64, end_frequency: u64, tick_rate: u64, ticks: u32, shape: ChirpShape) -> Self {
+        let start = Phase::from_time(start_frequency, tick_rate);
+        let end = Phase::from_time(end_frequency, tick_rate);
+        Self::new(start, end, ticks, shape)
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> Phase {
+        self.nco.phase()
+    }
+
+    /// Returns the increment applied by the last tick, or the start increment
+    /// before the first one.
+    pub fn increment(&self) -> Phase {
+        self.nco.increment()
+    }
+
+    /// Returns whether every tick of the sweep has been yielded.
+    pub fn is_finished(&self) -> bool {
+        self.tick == self.ticks
+    }
+
+    /// Restarts the sweep from [`Phase::MIN`].
+    pub fn reset(&mut self) {
+        *self = Self::new(self.start, self.end, self.ticks, self.shape);
+    }
+
+    /// Computes the increment of the tick at `index`.
+    fn increment_at(&self, index: u32) -> Phase {
+        let last = self.ticks.saturating_sub(1);
+        if index == 0 || last == 0 { return self.start; }
+        let (start, end) = (self.start.to_raw() as i64, self.end.to_raw() as i64);
+        match self.shape {
+            ChirpShape::Linear => {
+                // The product reaches up to 64 bits, so it is computed in 128 bits.
+                let offset = ((end - start) as i128 * index as i128 / last as i128) as i64;
+                Phase::from_raw((start + offset) as u32)
+            }
+            ChirpShape::Exponential => {
+                // Interpolates linearly between the logarithms of both increments.
+                let (start, end) = (log2(start as u32) as i128, log2(end as u32) as i128);
+                let exponent = start + ((end - start) * index as i128).div_euclid(last as i128);
+                let increment = (exp2(exponent.min(0)) * u32::MAX as u128 + (1 << 61)) >> 62;
+                Phase::from_raw(increment.clamp(1, u32::MAX as u128) as u32)
+            }
+        }
+    }
+}
+
+impl Iterator for Chirp {
+    type Item = Phase;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished() { return None; }
+        self.nco.set_increment(self.increment_at(self.tick));
+        self.tick += 1;
+        Some(self.nco.tick())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.ticks - self.tick) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Chirp {}
+
+impl FusedIterator for Chirp {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear() {
+        let mut chirp = Chirp::new(Phase::from_raw(1000), Phase::from_raw(0), 1_000_001, ChirpShape::Linear);
+        assert_eq!(chirp.len(), 1_000_001);
+        let mut total = 0u64;
+        for (index, phase) in chirp.by_ref().enumerate() {
+            total += 1000 - index as u64 / 1000;
+            assert_eq!(phase.to_raw() as u64, total % (1 << 32));
+        }
+        assert_eq!(chirp.increment(), Phase::MIN);
+        assert!(chirp.is_finished());
+        chirp.reset();
+        assert_eq!(chirp.next(), Some(Phase::from_raw(1000)));
+        assert_eq!(Chirp::new(Phase::MIN, Phase::MAX, 0, ChirpShape::Linear).next(), None);
+
+        // A full sweep over as many ticks as possible does not overflow.
+        let chirp = Chirp::new(Phase::MIN, Phase::MAX, u32::MAX, ChirpShape::Linear);
+        assert_eq!(chirp.increment_at(u32::MAX / 2), Phase::from_raw(u32::MAX / 2));
+        assert_eq!(chirp.increment_at(u32::MAX - 1), Phase::MAX);
+    }
+
+    #[test]
+    fn test_exponential() {
+        let mut chirp = Chirp::new(Phase::from_raw(1 << 10), Phase::from_raw(1 << 20), 11, ChirpShape::Exponential);
+        for octave in 10..=20 {
+            chirp.next();
+            assert_eq!(chirp.increment(), Phase::from_raw(1 << octave));
+        }
+        let chirp = Chirp::new(Phase::from_raw(3_000_000), Phase::from_raw(24_000_000), 4, ChirpShape::Exponential);
+        let increments: [u32; 4] = core::array::from_fn(|index| chirp.increment_at(index as u32).to_raw());
+        assert_eq!(increments, [3_000_000, 6_000_000, 12_000_000, 24_000_000]);
+        let chirp = Chirp::new(Phase::MAX, Phase::from_raw(1), 3, ChirpShape::Exponential);
+        assert_eq!(chirp.increment_at(1), Phase::from_raw(1 << 16));
+        assert_eq!(chirp.increment_at(2), Phase::from_raw(1));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{Phase, Primant};
+
+/// Converts a color from HSV to 8-bit RGB.
+///
+/// The hue is a [`Phase`] around the color wheel, starting at red, through yellow,
+/// green, cyan, blue, and magenta, back to red. The saturation and the value are
+/// [`Primant`]s. The channels are computed exactly and rounded to the nearest
+/// integer, so primary and secondary colors come out as exact `0` and `255`
+/// channels.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{hsv_to_rgb, Phase, Primant};
+/// let cyan = Phase::from_raw(1 << 31);
+/// assert_eq!(hsv_to_rgb(cyan, Primant::MAX, Primant::MAX), [0, 255, 255]);
+/// let half = Primant::from_ratio(1u32, 2u32);
+/// assert_eq!(hsv_to_rgb(cyan, half, Primant::MAX), [128, 255, 255]);
+/// let quarter = Primant::from_ratio(1u32, 4u32);
+/// assert_eq!(hsv_to_rgb(cyan, Primant::MAX, quarter), [0, 64, 64]);
+/// ```
+pub fn hsv_to_rgb(hue: Phase, saturation: Primant, value: Primant) -> [u8; 3] {
+    let position = hue.to_raw() as u64 * 6;
+    let (sector, fraction) = (position >> 32, position & 0xFFFF_FFFF);
+
+    // Each channel is `value · (1 - saturation · amount)`, for `amount` in Q32.
+    let (max, value, saturation) = (u32::MAX as u128, value.to_raw() as u128, saturation.to_raw() as u128);
+    let channel = |amount: u64| {
+        let remaining = (max << 32) - saturation * amount as u128;
+        let denominator = (max * max) << 32;
+        ((255 * value * remaining + denominator / 2) / denominator) as u8
+    };
+    let (full, falling, rising) = (channel(0), channel(fraction), channel((1 << 32) - fraction));
+    let empty = channel(1 << 32);
+
+    match sector {
+        0 => [full, rising, empty],
+        1 => [falling, full, empty],
+        2 => [empty, full, rising],
+        3 => [empty, falling, full],
+        4 => [rising, empty, full],
+        _ => [full, empty, falling],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hue_wheel() {
+        let hue = |sixths: u64| Phase::from_raw(((sixths << 32) / 6) as u32);
+        let colors = [[255, 0, 0], [255, 255, 0], [0, 255, 0], [0, 255, 255], [0, 0, 255], [255, 0, 255]];
+        for (sixths, color) in colors.into_iter().enumerate() {
+            assert_eq!(hue(sixths as u64).to_rgb_hue(), color);
+        }
+        assert_eq!(Phase::from_raw(u32::MAX).to_rgb_hue(), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(Phase::MIN, Primant::ZERO, Primant::MAX), [255, 255, 255]);
+        assert_eq!(hsv_to_rgb(Phase::MIN, Primant::MAX, Primant::ZERO), [0, 0, 0]);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Lut, Primant};
+
+/// A perceptual colormap, mapping a [`Primant`] to an 8-bit RGB color.
+///
+/// Colormaps are used for visualizing scalar data, such as the frames of a thermal
+/// camera or a spectrogram. Each colormap is stored as a compact table of 33 colors,
+/// interpolated linearly in between. The tables are sampled from polynomial fits of
+/// the reference colormaps with the endpoints matched exactly, so they may differ
+/// from the reference by a few levels in between.
+///
+/// This type is only available with the `colormaps` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Colormap, Primant};
+/// assert_eq!(Colormap::Viridis.sample(Primant::ZERO), [68, 1, 84]);
+/// assert_eq!(Colormap::Viridis.sample(Primant::MAX), [253, 231, 37]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Colormap {
+    /// Viridis, from dark blue through green to yellow.
+    Viridis,
+    /// Magma, from black through purple and orange to pale yellow.
+    Magma,
+    /// Turbo, a rainbow from dark blue through green and yellow to dark red.
+    Turbo,
+}
+
+impl Colormap {
+    /// Returns the lookup table of the colormap.
+    pub const fn lut(self) -> Lut<'static, [u8; 3], 33> {
+        match self {
+            Colormap::Viridis => Lut::new(&VIRIDIS),
+            Colormap::Magma => Lut::new(&MAGMA),
+            Colormap::Turbo => Lut::new(&TURBO),
+        }
+    }
+
+    /// Returns the color at `t`.
+    pub fn sample(self, t: Primant) -> [u8; 3] {
+        self.lut().sample(t)
+    }
+}
+
+const VIRIDIS: [[u8; 3]; 33] = [
+    [68, 1, 84], [72, 13, 96], [72, 24, 106], [72, 34, 115], [71, 45, 123],
+    [69, 55, 129], [67, 64, 134], [64, 74, 137], [61, 82, 140], [57, 91, 141],
+    [52, 99, 142], [48, 107, 142], [43, 114, 142], [39, 122, 142], [35, 129, 141],
+    [33, 137, 140], [31, 144, 139], [31, 152, 138], [33, 159, 135], [36, 166, 132],
+    [42, 174, 128], [50, 181, 123], [61, 188, 116], [74, 194, 107], [90, 200, 97],
+    [108, 206, 86], [128, 211, 73], [149, 216, 60], [172, 220, 48], [194, 223, 37],
+    [216, 226, 29], [236, 229, 27], [253, 231, 37],
+];
+
+const MAGMA: [[u8; 3]; 33] = [
+    [0, 0, 4], [3, 4, 18], [10, 8, 38], [19, 10, 56], [30, 13, 73], [41, 15, 89],
+    [54, 17, 102], [66, 19, 113], [79, 22, 122], [92, 24, 128], [105, 27, 132],
+    [118, 30, 134], [131, 34, 134], [145, 38, 132], [158, 42, 129], [171, 48, 125],
+    [183, 53, 119], [196, 59, 114], [207, 67, 108], [218, 75, 103], [228, 84, 99],
+    [237, 94, 96], [244, 106, 95], [250, 118, 96], [254, 132, 99], [255, 147, 104],
+    [255, 163, 111], [255, 180, 121], [254, 197, 132], [252, 213, 145], [250, 228, 159],
+    [251, 240, 173], [252, 253, 191],
+];
+
+const TURBO: [[u8; 3]; 33] = [
+    [48, 18, 59], [62, 42, 114], [73, 62, 175], [74, 84, 215], [68, 106, 238],
+    [60, 128, 248], [50, 149, 247], [42, 170, 239], [38, 189, 225], [37, 206, 207],
+    [41, 221, 187], [50, 233, 166], [64, 243, 146], [81, 250, 127], [102, 253, 109],
+    [126, 253, 93], [150, 250, 80], [175, 244, 68], [198, 235, 59], [220, 223, 51],
+    [238, 208, 45], [251, 190, 40], [255, 171, 36], [255, 150, 33], [255, 128, 29],
+    [252, 106, 25], [238, 84, 21], [221, 63, 17], [201, 45, 12], [180, 29, 7],
+    [161, 18, 2], [148, 12, 0], [122, 4, 3],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints() {
+        assert_eq!(Colormap::Magma.sample(Primant::ZERO), [0, 0, 4]);
+        assert_eq!(Colormap::Magma.sample(Primant::MAX), [252, 253, 191]);
+        assert_eq!(Colormap::Turbo.sample(Primant::ZERO), [48, 18, 59]);
+        assert_eq!(Colormap::Turbo.sample(Primant::MAX), [122, 4, 3]);
+        assert_eq!(Colormap::Viridis.sample(Primant::from_ratio(1u32, 2u32)), [31, 144, 139]);
+    }
+}
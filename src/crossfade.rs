@@ -0,0 +1,150 @@
+use crate::{Bipolar, Phase, Primant};
+
+/// The gain curves of a crossfade.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// The gains change at a constant rate and always add up to one. Correlated
+    /// signals, such as two takes of the same sound, keep a constant level.
+    Linear,
+    /// The gains follow a quarter of a cosine and a sine, so their squares always
+    /// add up to one. Uncorrelated signals keep a constant power, without the dip
+    /// of a linear crossfade in the middle.
+    EqualPower,
+}
+
+impl Easing {
+    /// Returns the gains of the outgoing and the incoming signal at the position
+    /// `t` of the crossfade.
+    ///
+    /// [`Primant::ZERO`] yields only the outgoing signal, and [`Primant::MAX`]
+    /// yields only the incoming one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{Easing, Primant};
+    /// let (out, into) = Easing::EqualPower.gains(Primant::from_ratio(1u32, 2u32));
+    /// assert!(out.within(Primant::from_ratio(7071u32, 10000u32), Primant::from_ratio(1u32, 10000u32)));
+    /// assert_eq!(out, into);
+    /// ```
+    pub fn gains(self, t: Primant) -> (Primant, Primant) {
+        match self {
+            Easing::Linear => (Primant::from_raw(u32::MAX - t.to_raw()), t),
+            Easing::EqualPower => {
+                let (sin, cos) = Phase::from_raw(t.percent_of(1u32 << 30)).sin_cos();
+                let gain = |value: Bipolar| Primant::ratio_of(value.to_raw().max(0) as u64, i32::MAX as u64);
+                (gain(cos), gain(sin))
+            }
+        }
+    }
+}
+
+/// A sample type that [`crossfade_slices`] can mix.
+pub trait CrossfadeSample: Copy {
+    /// Returns `self · gain + other · other_gain`.
+    fn mix(self, gain: Primant, other: Self, other_gain: Primant) -> Self;
+}
+
+impl CrossfadeSample for i16 {
+    /// Mixes both samples exactly, then rounds to the nearest integer and saturates
+    /// to the range of an `i16`.
+    fn mix(self, gain: Primant, other: Self, other_gain: Primant) -> Self {
+        let sum = self as i64 * gain.to_raw() as i64 + other as i64 * other_gain.to_raw() as i64;
+        let max = u32::MAX as i64;
+        let rounded = (sum + sum.signum() * (max / 2)) / max;
+        rounded.clamp(i16::MIN as i64, i16::MAX as i64) as i16
+    }
+}
+
+/// This implementation is only available with the `float` feature.
+#[cfg(feature = "float")]
+impl CrossfadeSample for f32 {
+    fn mix(self, gain: Primant, other: Self, other_gain: Primant) -> Self {
+        self * f32::from(gain) + other * f32::from(other_gain)
+    }
+}
+
+/// Crossfades from `a` to `b` into `out`, such as for splicing two audio buffers
+/// without a click.
+///
+/// The position of the crossfade sweeps across the buffer, from exactly
+/// [`Primant::ZERO`] at the first sample, which is taken from `a`, to exactly
+/// [`Primant::MAX`] at the last one, which is taken from `b`. The gains at each
+/// sample follow `curve`.
+///
+/// # Panics
+///
+/// Panics if the slices do not all have the same length, or if it does not fit in
+/// a `u32`.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{crossfade_slices, Easing};
+/// let (a, b) = ([1000i16; 5], [-1000i16; 5]);
+/// let mut out = [0; 5];
+/// crossfade_slices(&a, &b, &mut out, Easing::Linear);
+/// assert_eq!(out, [1000, 500, 0, -500, -1000]);
+/// ```
+pub fn crossfade_slices<T: CrossfadeSample>(a: &[T], b: &[T], out: &mut [T], curve: Easing) {
+    assert!(a.len() == b.len() && a.len() == out.len(), "slices must have the same length");
+    let count = u32::try_from(out.len()).expect("length must fit in a u32");
+    let positions = Primant::sweep_inclusive(count);
+    for (((out, &a), &b), t) in out.iter_mut().zip(a).zip(b).zip(positions) {
+        let (gain, other_gain) = curve.gains(t);
+        *out = a.mix(gain, b, other_gain);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lerp;
+
+    #[test]
+    fn test_gains() {
+        for curve in [Easing::Linear, Easing::EqualPower] {
+            assert_eq!(curve.gains(Primant::ZERO), (Primant::MAX, Primant::ZERO));
+            assert_eq!(curve.gains(Primant::MAX), (Primant::ZERO, Primant::MAX));
+        }
+        // The squares of the equal-power gains add up to one.
+        for raw in [1, u32::MAX / 7, u32::MAX / 3, u32::MAX / 2, u32::MAX - 5] {
+            let (out, into) = Easing::EqualPower.gains(Primant::from_raw(raw));
+            let power = Primant::ZERO.lerp(out, out).to_raw() as i64 + Primant::ZERO.lerp(into, into).to_raw() as i64;
+            assert!((power - u32::MAX as i64).abs() <= 4);
+        }
+    }
+
+    #[test]
+    fn test_crossfade_i16() {
+        let (a, b) = ([i16::MAX; 3], [i16::MAX; 3]);
+        let mut out = [0; 3];
+        crossfade_slices(&a, &b, &mut out, Easing::EqualPower);
+        // The middle sample is boosted by about 3 dB, and saturates.
+        assert_eq!(out, [i16::MAX; 3]);
+        crossfade_slices(&a, &b, &mut out, Easing::Linear);
+        assert_eq!(out, [i16::MAX; 3]);
+        let (a, b) = ([-3i16, 7], [9i16, i16::MIN]);
+        let mut out = [0; 2];
+        crossfade_slices(&a, &b, &mut out, Easing::EqualPower);
+        assert_eq!(out, [-3, i16::MIN]);
+        crossfade_slices::<i16>(&[], &[], &mut [], Easing::Linear);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn test_crossfade_f32() {
+        let (a, b) = ([1.0f32; 3], [0.0f32; 3]);
+        let mut out = [0.0; 3];
+        crossfade_slices(&a, &b, &mut out, Easing::EqualPower);
+        assert_eq!(out[0], 1.0);
+        assert!((out[1] - core::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+        assert_eq!(out[2], 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch() {
+        crossfade_slices(&[0i16; 2], &[0i16; 3], &mut [0i16; 2], Easing::Linear);
+    }
+}
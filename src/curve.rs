@@ -0,0 +1,132 @@
+use crate::Primant;
+
+/// A piecewise-linear, monotonically non-decreasing curve mapping a [`Primant`] to a
+/// [`Primant`].
+///
+/// The curve is defined by breakpoints `(t, y)` with strictly increasing `t` and
+/// non-decreasing `y`. Between breakpoints, the output is interpolated linearly;
+/// before the first and after the last breakpoint, it is held constant.
+///
+/// Unlike a generic lookup table, a monotonic curve can also be queried backwards
+/// with [`MonotonicCurve::inverse`], which is useful for calibration mappings that
+/// need to convert in both directions.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{MonotonicCurve, Primant};
+/// const POINTS: [(Primant, Primant); 3] = [
+///     (Primant::ZERO, Primant::ZERO),
+///     (Primant::from_raw(u32::MAX / 2), Primant::from_raw(u32::MAX / 4)),
+///     (Primant::MAX, Primant::MAX),
+/// ];
+/// const CURVE: MonotonicCurve = MonotonicCurve::new(&POINTS);
+/// let y = CURVE.forward(Primant::from_raw(u32::MAX / 4));
+/// assert_eq!(y, Primant::from_raw(u32::MAX / 8));
+/// assert_eq!(CURVE.inverse(y), Primant::from_raw(u32::MAX / 4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonotonicCurve<'a> {
+    points: &'a [(Primant, Primant)],
+}
+
+impl<'a> MonotonicCurve<'a> {
+    /// Creates a new [`MonotonicCurve`] from its breakpoints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no breakpoints, if their `t` values are not strictly
+    /// increasing, or if their `y` values are decreasing.
+    pub const fn new(points: &'a [(Primant, Primant)]) -> Self {
+        match Self::try_new(points) {
+            Some(curve) => curve,
+            None => panic!("breakpoints must be non-empty, with increasing t and non-decreasing y"),
+        }
+    }
+
+    /// Creates a new [`MonotonicCurve`] from its breakpoints.
+    ///
+    /// Returns `None` if there are no breakpoints, if their `t` values are not
+    /// strictly increasing, or if their `y` values are decreasing.
+    pub const fn try_new(points: &'a [(Primant, Primant)]) -> Option<Self> {
+        if points.is_empty() { return None; }
+        let mut i = 1;
+        while i < points.len() {
+            let (previous, current) = (points[i - 1], points[i]);
+            if current.0.to_raw() <= previous.0.to_raw() || current.1.to_raw() < previous.1.to_raw() {
+                return None;
+            }
+            i += 1;
+        }
+        Some(MonotonicCurve { points })
+    }
+
+    /// Returns the breakpoints of the curve.
+    pub const fn points(&self) -> &'a [(Primant, Primant)] {
+        self.points
+    }
+
+    /// Evaluates the curve at `t`, rounding down.
+    pub fn forward(&self, t: Primant) -> Primant {
+        let index = self.points.partition_point(|&(x, _)| x <= t);
+        if index == 0 { return self.points[0].1; }
+        if index == self.points.len() { return self.points[index - 1].1; }
+
+        let ((x0, y0), (x1, y1)) = (self.points[index - 1], self.points[index]);
+        let (dx, dy) = (x1.to_raw() - x0.to_raw(), y1.to_raw() - y0.to_raw());
+        let offset = (t.to_raw() - x0.to_raw()) as u64 * dy as u64 / dx as u64;
+        Primant::from_raw(y0.to_raw() + offset as u32)
+    }
+
+    /// Returns the smallest `t` for which [`MonotonicCurve::forward`] returns at
+    /// least `y`.
+    ///
+    /// This inverse is exact: for every `y` that the curve reaches,
+    /// `curve.forward(curve.inverse(y)) == y`. If `y` is greater than the largest
+    /// output of the curve, the `t` of the last breakpoint is returned.
+    pub fn inverse(&self, y: Primant) -> Primant {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if y <= first.1 { return Primant::ZERO; }
+        if y > last.1 { return last.0; }
+
+        let index = self.points.partition_point(|&(_, y1)| y1 < y);
+        let ((x0, y0), (x1, y1)) = (self.points[index - 1], self.points[index]);
+        let (dx, dy) = (x1.to_raw() - x0.to_raw(), y1.to_raw() - y0.to_raw());
+        let offset = ((y.to_raw() - y0.to_raw()) as u64 * dx as u64).div_ceil(dy as u64);
+        Primant::from_raw(x0.to_raw() + offset as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_inverse() {
+        let points = [
+            (Primant::from_raw(1000), Primant::from_raw(5000)),
+            (Primant::from_raw(2000), Primant::from_raw(5000)),
+            (Primant::from_raw(3000), Primant::from_raw(8000)),
+            (Primant::from_raw(9000), Primant::from_raw(9000)),
+        ];
+        let curve = MonotonicCurve::new(&points);
+        assert_eq!(curve.forward(Primant::ZERO), Primant::from_raw(5000));
+        assert_eq!(curve.forward(Primant::MAX), Primant::from_raw(9000));
+        assert_eq!(curve.inverse(Primant::from_raw(5000)), Primant::ZERO);
+        assert_eq!(curve.inverse(Primant::MAX), Primant::from_raw(9000));
+        for y in 5000..=9000 {
+            let t = curve.inverse(Primant::from_raw(y));
+            assert!(curve.forward(t).to_raw() >= y);
+            assert!(t == Primant::ZERO || curve.forward(Primant::from_raw(t.to_raw() - 1)).to_raw() < y);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let points = [(Primant::MAX, Primant::ZERO), (Primant::ZERO, Primant::MAX)];
+        assert!(MonotonicCurve::try_new(&points).is_none());
+        let points = [(Primant::ZERO, Primant::MAX), (Primant::MAX, Primant::ZERO)];
+        assert!(MonotonicCurve::try_new(&points).is_none());
+        assert!(MonotonicCurve::try_new(&[]).is_none());
+    }
+}
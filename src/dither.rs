@@ -0,0 +1,142 @@
+use crate::random::Xorshift32;
+use crate::Primant;
+
+/// Returns the threshold of an ordered (Bayer) dither matrix at the given pixel.
+///
+/// The matrix has a size of `2^level` by `2^level`, and is tiled across the image,
+/// so `x` and `y` may be any coordinates. Its thresholds are evenly spread across
+/// the unit interval, at the centers of `4^level` equal steps, and each value should
+/// be compared with the threshold of its pixel: a pixel is lit if its value is
+/// greater than the threshold. [`Primant::ZERO`] thus leaves every pixel dark, and
+/// [`Primant::MAX`] lights every pixel.
+///
+/// # Panics
+///
+/// Panics if `level` is greater than `16`.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{bayer_threshold, Primant};
+/// let half = Primant::from_ratio(1u32, 2u32);
+/// let lit = (0..4).filter(|&i| half > bayer_threshold(i % 2, i / 2, 1)).count();
+/// assert_eq!(lit, 2);
+/// assert_eq!(bayer_threshold(0, 0, 1), Primant::from_ratio(1u32, 8u32));
+/// ```
+pub fn bayer_threshold(x: u32, y: u32, level: u32) -> Primant {
+    assert!(level <= 16, "level must be at most 16");
+    let mut index = 0u64;
+    for bit in 0..level {
+        let shift = 2 * (level - 1 - bit);
+        index |= ((((x ^ y) >> bit) & 1) as u64) << (shift + 1);
+        index |= (((y >> bit) & 1) as u64) << shift;
+    }
+    Primant::ratio_of(2 * index + 1, 2 << (2 * level))
+}
+
+/// A triangular-PDF dither generator, for reducing the bit depth of values without
+/// distortion.
+///
+/// Truncating or rounding a value to a lower bit depth produces an error that
+/// depends on the value, which is heard as distortion in audio and seen as banding
+/// in gradients. Adding noise with a triangular probability density, spanning one
+/// quantization step in either direction, before rounding turns this error into
+/// constant, signal-independent noise.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, TpdfDither};
+/// let mut dither = TpdfDither::new(8);
+/// let value = Primant::from_ratio(1u32, 100u32);
+/// // A value between two 8-bit levels is preserved on average.
+/// let sum: u32 = (0..10000).map(|_| dither.quantize(value)).sum();
+/// assert!((sum as f64 / 10000.0 - 2.55).abs() < 0.05);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TpdfDither {
+    bits: u32,
+    random: Xorshift32,
+}
+
+impl TpdfDither {
+    /// Creates a new [`TpdfDither`] for quantizing to `bits` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is zero or greater than `32`.
+    pub fn new(bits: u32) -> Self {
+        assert!((1..=32).contains(&bits), "bits must be between 1 and 32");
+        TpdfDither {
+            bits,
+            random: Xorshift32::default(),
+        }
+    }
+
+    /// Sets the seed of the pseudo-random generator.
+    ///
+    /// A seed of zero is replaced with a non-zero default.
+    pub fn with_seed(self, seed: u32) -> Self {
+        TpdfDither { random: Xorshift32::new(seed), ..self }
+    }
+
+    /// Returns the target bit depth.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Returns the highest quantized level, `2^bits - 1`.
+    fn max_level(&self) -> u64 {
+        (1 << self.bits) - 1
+    }
+
+    /// Adds triangular noise, spanning one quantization step in either direction, to
+    /// `value`, saturating at the bounds.
+    pub fn dither(&mut self, value: Primant) -> Primant {
+        let difference = self.random.next_u32() as i128 - self.random.next_u32() as i128;
+        let noise = difference * u32::MAX as i128 / ((self.max_level() as i128) << 32);
+        let raw = (value.to_raw() as i128 + noise).clamp(0, u32::MAX as i128);
+        Primant::from_raw(raw as u32)
+    }
+
+    /// Dithers `value` and rounds it to the nearest of the `2^bits` levels.
+    pub fn quantize(&mut self, value: Primant) -> u32 {
+        let value = self.dither(value);
+        value.scale_u128(self.max_level() as u128, true) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bayer() {
+        let matrix = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+        for (y, row) in matrix.iter().enumerate() {
+            for (x, &index) in row.iter().enumerate() {
+                let expected = Primant::ratio_of(2 * index + 1, 32);
+                assert_eq!(bayer_threshold(x as u32, y as u32, 2), expected);
+                assert_eq!(bayer_threshold(x as u32 + 4, y as u32 + 8, 2), expected);
+            }
+        }
+        assert_eq!(bayer_threshold(5, 7, 0), Primant::from_ratio(1u32, 2u32));
+        assert!(bayer_threshold(u32::MAX, u32::MAX, 16) < Primant::MAX);
+    }
+
+    #[test]
+    fn test_tpdf() {
+        let mut dither = TpdfDither::new(4).with_seed(1);
+        let step = Primant::from_ratio(1u32, 15u32).to_raw() as i64;
+        let value = Primant::from_ratio(1u32, 2u32);
+        let mut sum = 0;
+        for _ in 0..10000 {
+            let offset = dither.dither(value).to_raw() as i64 - value.to_raw() as i64;
+            assert!(offset.abs() <= step);
+            sum += offset;
+        }
+        assert!((sum / 10000).abs() < step / 50);
+        assert_eq!(TpdfDither::new(32).quantize(Primant::MAX), u32::MAX);
+        assert!(TpdfDither::new(1).quantize(Primant::ZERO) <= 1);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::Primant;
+
+/// A duty cycle meter, measuring a PWM signal from the timestamps of its edges,
+/// such as those captured by a hardware timer.
+///
+/// A [`DutyMeter`] completes a measurement at every rising edge, from the time
+/// since the previous rising edge, which is the period, and the time the signal
+/// stayed high within it. The timestamps come from a free-running counter of a
+/// given width, and differences are taken modulo its range, so the counter may wrap
+/// between edges, as long as a period is shorter than the full range.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{DutyMeter, Primant};
+/// // A 16-bit capture timer, wrapping during the second period.
+/// let mut meter = DutyMeter::new(16);
+/// for (rising, falling) in [(65_000, 65_250), (65_500, 214), (428, 678)] {
+///     meter.rising(rising);
+///     meter.falling(falling);
+/// }
+/// assert_eq!(meter.period(), Some(464));
+/// assert_eq!(meter.duty(), Some(Primant::ratio_of(250, 464)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DutyMeter {
+    mask: u64,
+    rising: Option<u64>,
+    falling: Option<u64>,
+    period: Option<u64>,
+    duty: Option<Primant>,
+}
+
+impl DutyMeter {
+    /// Creates a new [`DutyMeter`] reading timestamps from a counter of
+    /// `counter_bits` bits, such as `16` for a 16-bit timer or `64` for a
+    /// monotonic tick count that never wraps in practice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `counter_bits` is zero or greater than `64`.
+    pub fn new(counter_bits: u32) -> Self {
+        assert!((1..=64).contains(&counter_bits), "counter width must be between 1 and 64 bits");
+        DutyMeter {
+            mask: u64::MAX >> (64 - counter_bits),
+            rising: None,
+            falling: None,
+            period: None,
+            duty: None,
+        }
+    }
+
+    /// Records a rising edge at `ticks`, completing a measurement if a whole period
+    /// has been seen since the previous rising edge.
+    ///
+    /// A period without a falling edge, or of zero ticks, is skipped, keeping the
+    /// previous measurement.
+    pub fn rising(&mut self, ticks: u64) {
+        let ticks = ticks & self.mask;
+        if let (Some(start), Some(end)) = (self.rising, self.falling) {
+            let period = ticks.wrapping_sub(start) & self.mask;
+            let high = end.wrapping_sub(start) & self.mask;
+            if period != 0 {
+                self.period = Some(period);
+                self.duty = Some(Primant::ratio_of_saturating(high, period));
+            }
+        }
+        self.rising = Some(ticks);
+        self.falling = None;
+    }
+
+    /// Records a falling edge at `ticks`.
+    ///
+    /// Falling edges before the first rising edge are ignored.
+    pub fn falling(&mut self, ticks: u64) {
+        if self.rising.is_some() {
+            self.falling = Some(ticks & self.mask);
+        }
+    }
+
+    /// Returns the duty cycle of the last complete period, or `None` before the
+    /// first one.
+    pub fn duty(&self) -> Option<Primant> {
+        self.duty
+    }
+
+    /// Returns the length of the last complete period in ticks, or `None` before
+    /// the first one.
+    pub fn period(&self) -> Option<u64> {
+        self.period
+    }
+
+    /// Forgets every edge and measurement.
+    pub fn reset(&mut self) {
+        *self = DutyMeter { mask: self.mask, ..DutyMeter::new(64) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measurement() {
+        let mut meter = DutyMeter::new(32);
+        meter.falling(5);
+        meter.rising(10);
+        assert_eq!(meter.duty(), None);
+        meter.falling(13);
+        meter.rising(20);
+        assert_eq!(meter.period(), Some(10));
+        assert_eq!(meter.duty(), Some(Primant::ratio_of(3, 10)));
+
+        // A missing falling edge keeps the previous measurement.
+        meter.rising(30);
+        assert_eq!(meter.period(), Some(10));
+
+        // Across the wrap of the counter.
+        meter.rising(u32::MAX as u64 - 99);
+        meter.falling(u32::MAX as u64);
+        meter.rising(100);
+        assert_eq!(meter.period(), Some(200));
+        assert_eq!(meter.duty(), Some(Primant::ratio_of(99, 200)));
+
+        meter.reset();
+        assert_eq!(meter.duty(), None);
+        meter.rising(u64::MAX);
+        meter.falling(1);
+        meter.rising(3);
+        assert_eq!(meter.period(), Some(4));
+    }
+
+    #[test]
+    fn test_full_width() {
+        let mut meter = DutyMeter::new(64);
+        meter.rising(u64::MAX - 1);
+        meter.falling(u64::MAX);
+        meter.rising(2);
+        assert_eq!(meter.period(), Some(4));
+        assert_eq!(meter.duty(), Some(Primant::ratio_of(1, 4)));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{MonotonicCurve, Primant};
+
+/// A fan curve mapping a normalized temperature to a duty cycle.
+///
+/// The mapping is given by a [`MonotonicCurve`] of breakpoints from temperature to
+/// duty. To keep the fan from oscillating when the temperature hovers around a
+/// breakpoint, a [`FanCurve`] applies hysteresis between rising and falling
+/// temperatures: the duty follows the curve immediately as the temperature rises,
+/// but only decreases once the temperature has fallen by more than the hysteresis
+/// width.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{FanCurve, MonotonicCurve, Primant};
+/// let percent = |value: u32| Primant::from_ratio(value, 100u32);
+/// let points = [(percent(30), Primant::ZERO), (percent(80), Primant::MAX)];
+/// let mut fan = FanCurve::new(MonotonicCurve::new(&points), percent(10));
+///
+/// assert_eq!(fan.update(percent(80)), Primant::MAX);
+/// // Within the hysteresis band, the duty is held.
+/// assert_eq!(fan.update(percent(75)), Primant::MAX);
+/// // Below it, the duty follows the curve shifted by the band.
+/// assert_eq!(fan.update(percent(60)), fan.curve().forward(percent(70)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FanCurve<'a> {
+    curve: MonotonicCurve<'a>,
+    hysteresis: Primant,
+    temperature: Option<Primant>,
+}
+
+impl<'a> FanCurve<'a> {
+    /// Creates a new [`FanCurve`] from a curve and a hysteresis width, in the same
+    /// units as the temperature.
+    pub const fn new(curve: MonotonicCurve<'a>, hysteresis: Primant) -> Self {
+        FanCurve {
+            curve,
+            hysteresis,
+            temperature: None,
+        }
+    }
+
+    /// Returns the curve mapping temperature to duty.
+    pub const fn curve(&self) -> MonotonicCurve<'a> {
+        self.curve
+    }
+
+    /// Returns the hysteresis width.
+    pub const fn hysteresis(&self) -> Primant {
+        self.hysteresis
+    }
+
+    /// Returns the temperature the current duty is taken from, or `None` if no
+    /// temperature has been given yet.
+    ///
+    /// It lags behind the measured temperature by at most the hysteresis width
+    /// when the temperature is falling.
+    pub fn effective_temperature(&self) -> Option<Primant> {
+        self.temperature
+    }
+
+    /// Returns the current duty, or the duty at the lowest temperature if no
+    /// temperature has been given yet.
+    pub fn duty(&self) -> Primant {
+        self.curve.forward(self.temperature.unwrap_or(Primant::ZERO))
+    }
+
+    /// Forgets the previous temperatures, so the next update follows the curve
+    /// exactly.
+    pub fn reset(&mut self) {
+        self.temperature = None;
+    }
+
+    /// Updates the curve with a new temperature and returns the duty.
+    pub fn update(&mut self, temperature: Primant) -> Primant {
+        let upper = Primant::from_raw(temperature.to_raw().saturating_add(self.hysteresis.to_raw()));
+        let effective = match self.temperature {
+            Some(previous) => previous.clamp(temperature, upper),
+            None => temperature,
+        };
+        self.temperature = Some(effective);
+        self.curve.forward(effective)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hysteresis() {
+        let points = [(Primant::from_raw(1000), Primant::ZERO), (Primant::from_raw(2000), Primant::MAX)];
+        let mut fan = FanCurve::new(MonotonicCurve::new(&points), Primant::from_raw(100));
+        assert_eq!(fan.duty(), Primant::ZERO);
+        fan.update(Primant::from_raw(1500));
+        let duty = fan.duty();
+        assert_eq!(fan.update(Primant::from_raw(1450)), duty);
+        assert_eq!(fan.update(Primant::from_raw(1400)), duty);
+        assert!(fan.update(Primant::from_raw(1399)) < duty);
+        assert_eq!(fan.effective_temperature(), Some(Primant::from_raw(1499)));
+        assert!(fan.update(Primant::from_raw(1400)) < duty);
+        assert_eq!(fan.update(Primant::from_raw(1501)), fan.curve().forward(Primant::from_raw(1501)));
+        assert_eq!(fan.update(Primant::MAX), Primant::MAX);
+    }
+}
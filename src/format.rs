@@ -0,0 +1,395 @@
+use core::fmt::{Display, Formatter, Result, Write};
+use crate::Primant;
+
+/// A configurable percentage formatter for [`Primant`].
+///
+/// The value is expanded into decimal digits using integer arithmetic only, and is
+/// rounded to the nearest representable decimal (with ties rounded up).
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{PercentFormat, Primant};
+/// let value = Primant::from_ratio(1u32, 3u32);
+/// assert_eq!(PercentFormat::new(value).to_string(), "33.33%");
+/// assert_eq!(PercentFormat::new(value).precision(0).to_string(), "33%");
+/// assert_eq!(PercentFormat::new(value).precision(1).suffix(" %").pad_to(8).to_string(), "  33.3 %");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PercentFormat<'a> {
+    value: Primant,
+    precision: u8,
+    suffix: &'a str,
+    width: usize,
+}
+
+impl PercentFormat<'static> {
+    /// The maximum number of decimal places supported by [`PercentFormat::precision`].
+    pub const MAX_PRECISION: u8 = 20;
+
+    /// Creates a new formatter for the given value.
+    ///
+    /// By default, the value is printed with two decimal places, a `%` suffix,
+    /// and no padding, matching the [`Display`] implementation of [`Primant`].
+    pub fn new(value: Primant) -> Self {
+        PercentFormat {
+            value,
+            precision: 2,
+            suffix: "%",
+            width: 0,
+        }
+    }
+}
+
+impl<'a> PercentFormat<'a> {
+    /// Sets the number of decimal places.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is greater than [`PercentFormat::MAX_PRECISION`].
+    pub fn precision(self, precision: u8) -> Self {
+        assert!(precision <= PercentFormat::MAX_PRECISION, "precision must not exceed 20");
+        PercentFormat { precision, ..self }
+    }
+
+    /// Sets the suffix printed after the number.
+    pub fn suffix<'b>(self, suffix: &'b str) -> PercentFormat<'b> {
+        PercentFormat {
+            value: self.value,
+            precision: self.precision,
+            suffix,
+            width: self.width,
+        }
+    }
+
+    /// Sets the minimum width of the output, in characters.
+    ///
+    /// Shorter outputs are right-aligned and padded with spaces.
+    pub fn pad_to(self, width: usize) -> Self {
+        PercentFormat { width, ..self }
+    }
+}
+
+impl Display for PercentFormat<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_scaled(f, self.value, 100, self.precision, self.suffix, self.width)
+    }
+}
+
+/// A formatter for [`Primant`] that picks a unit suitable for the magnitude of the value.
+///
+/// Values of at least one percent are printed as percentages (`%`). Smaller values
+/// are printed in per mille (`‰`), parts per million (`ppm`), or parts per billion
+/// (`ppb`), choosing the largest unit in which the printed value is at least one. This keeps
+/// small quantities, such as error rates, from printing as `0.00%`.
+///
+/// Like [`PercentFormat`], it uses integer arithmetic only.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{EngineeringFormat, Primant};
+/// let value = Primant::ratio_of(3, 1_000_000);
+/// assert_eq!(EngineeringFormat::new(value).to_string(), "3.00ppm");
+/// assert_eq!(EngineeringFormat::new(Primant::MAX).precision(0).to_string(), "100%");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct EngineeringFormat {
+    value: Primant,
+    precision: u8,
+    width: usize,
+}
+
+impl EngineeringFormat {
+    /// The maximum number of decimal places supported by [`EngineeringFormat::precision`].
+    pub const MAX_PRECISION: u8 = 20;
+
+    /// Creates a new formatter for the given value.
+    ///
+    /// By default, the value is printed with two decimal places and no padding.
+    pub fn new(value: Primant) -> Self {
+        EngineeringFormat {
+            value,
+            precision: 2,
+            width: 0,
+        }
+    }
+
+    /// Sets the number of decimal places.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is greater than [`EngineeringFormat::MAX_PRECISION`].
+    pub fn precision(self, precision: u8) -> Self {
+        assert!(precision <= Self::MAX_PRECISION, "precision must not exceed 20");
+        EngineeringFormat { precision, ..self }
+    }
+
+    /// Sets the minimum width of the output, in characters.
+    ///
+    /// Shorter outputs are right-aligned and padded with spaces.
+    pub fn pad_to(self, width: usize) -> Self {
+        EngineeringFormat { width, ..self }
+    }
+}
+
+impl Display for EngineeringFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        const UNITS: [(u128, &str); 4] = [
+            (100, "%"),
+            (1_000, "‰"),
+            (1_000_000, "ppm"),
+            (1_000_000_000, "ppb"),
+        ];
+
+        // A unit is chosen if the value, once rounded to the requested precision,
+        // is at least one in that unit. Zero is always printed as a percentage.
+        let (multiplier, suffix) = if self.value == Primant::ZERO {
+            UNITS[0]
+        } else {
+            UNITS.iter()
+                .copied()
+                .find(|&(multiplier, _)| round_scaled(self.value, multiplier, self.precision).0 >= 1)
+                .unwrap_or(UNITS[3])
+        };
+        write_scaled(f, self.value, multiplier, self.precision, suffix, self.width)
+    }
+}
+
+/// A unit of parts of a whole, used for formatting and parsing [`Primant`]s.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::PartsUnit;
+/// assert_eq!(PartsUnit::BasisPoints.suffix(), "bps");
+/// assert_eq!(PartsUnit::from_suffix("‰"), Some(PartsUnit::PerMille));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PartsUnit {
+    /// Parts per hundred, printed as `%`.
+    Percent,
+    /// Parts per thousand, printed as `‰`.
+    PerMille,
+    /// Parts per ten thousand, printed as `bps`.
+    BasisPoints,
+    /// Parts per million, printed as `ppm`.
+    PartsPerMillion,
+    /// Parts per billion, printed as `ppb`.
+    PartsPerBillion,
+}
+
+impl PartsUnit {
+    /// Returns the number of parts in a whole.
+    pub const fn parts(self) -> u64 {
+        match self {
+            PartsUnit::Percent => 100,
+            PartsUnit::PerMille => 1_000,
+            PartsUnit::BasisPoints => 10_000,
+            PartsUnit::PartsPerMillion => 1_000_000,
+            PartsUnit::PartsPerBillion => 1_000_000_000,
+        }
+    }
+
+    /// Returns the suffix printed after a number in this unit.
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            PartsUnit::Percent => "%",
+            PartsUnit::PerMille => "‰",
+            PartsUnit::BasisPoints => "bps",
+            PartsUnit::PartsPerMillion => "ppm",
+            PartsUnit::PartsPerBillion => "ppb",
+        }
+    }
+
+    /// Returns the unit with the given suffix, or `None` if there is none.
+    ///
+    /// Besides the suffixes returned by [`PartsUnit::suffix`], basis points are
+    /// also recognized as `bp` and `‱`.
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        match suffix {
+            "%" => Some(PartsUnit::Percent),
+            "‰" => Some(PartsUnit::PerMille),
+            "bps" | "bp" | "‱" => Some(PartsUnit::BasisPoints),
+            "ppm" => Some(PartsUnit::PartsPerMillion),
+            "ppb" => Some(PartsUnit::PartsPerBillion),
+            _ => None,
+        }
+    }
+}
+
+/// A formatter for [`Primant`] in a fixed [`PartsUnit`], such as basis points.
+///
+/// Like [`PercentFormat`], it uses integer arithmetic only, and rounds to the
+/// nearest representable decimal.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{PartsFormat, PartsUnit, Primant};
+/// let value = Primant::ratio_of(25, 10_000);
+/// assert_eq!(PartsFormat::new(value, PartsUnit::BasisPoints).precision(0).to_string(), "25bps");
+/// assert_eq!(PartsFormat::new(value, PartsUnit::PerMille).precision(1).to_string(), "2.5‰");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PartsFormat {
+    value: Primant,
+    unit: PartsUnit,
+    precision: u8,
+    width: usize,
+}
+
+impl PartsFormat {
+    /// The maximum number of decimal places supported by [`PartsFormat::precision`].
+    pub const MAX_PRECISION: u8 = 20;
+
+    /// Creates a new formatter for the given value and unit.
+    ///
+    /// By default, the value is printed with two decimal places and no padding.
+    pub fn new(value: Primant, unit: PartsUnit) -> Self {
+        PartsFormat {
+            value,
+            unit,
+            precision: 2,
+            width: 0,
+        }
+    }
+
+    /// Sets the number of decimal places.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is greater than [`PartsFormat::MAX_PRECISION`].
+    pub fn precision(self, precision: u8) -> Self {
+        assert!(precision <= Self::MAX_PRECISION, "precision must not exceed 20");
+        PartsFormat { precision, ..self }
+    }
+
+    /// Sets the minimum width of the output, in characters.
+    ///
+    /// Shorter outputs are right-aligned and padded with spaces.
+    pub fn pad_to(self, width: usize) -> Self {
+        PartsFormat { width, ..self }
+    }
+}
+
+impl Display for PartsFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let multiplier = self.unit.parts() as u128;
+        write_scaled(f, self.value, multiplier, self.precision, self.unit.suffix(), self.width)
+    }
+}
+
+/// Writes `value * multiplier` rounded to `precision` decimal places, followed by
+/// `suffix`, right-aligned to `width` characters.
+fn write_scaled(
+    f: &mut Formatter<'_>,
+    value: Primant,
+    multiplier: u128,
+    precision: u8,
+    suffix: &str,
+    width: usize,
+) -> Result {
+    write_ratio(f, value.to_raw() as u128, u32::MAX as u128, multiplier, precision, suffix, width)
+}
+
+/// Writes `numerator / denominator * multiplier` rounded to `precision` decimal
+/// places, followed by `suffix`, right-aligned to `width` characters.
+///
+/// The denominator must not be greater than `2^32`, so that nothing overflows.
+pub(crate) fn write_ratio(
+    f: &mut Formatter<'_>,
+    numerator: u128,
+    denominator: u128,
+    multiplier: u128,
+    precision: u8,
+    suffix: &str,
+    width: usize,
+) -> Result {
+    let (integer, fraction) = round_ratio(numerator, denominator, multiplier, precision);
+    let integer_digits = integer.checked_ilog10().unwrap_or(0) as usize + 1;
+    let fraction_chars = if precision > 0 { precision as usize + 1 } else { 0 };
+    let length = integer_digits + fraction_chars + suffix.chars().count();
+    for _ in length..width {
+        f.write_char(' ')?;
+    }
+
+    write!(f, "{}", integer)?;
+    if precision > 0 {
+        write!(f, ".{:0width$}", fraction, width = precision as usize)?;
+    }
+    f.write_str(suffix)
+}
+
+/// Computes `value * multiplier` rounded to `precision` decimal places, returning the
+/// integer part and the decimal digits of the fractional part.
+fn round_scaled(value: Primant, multiplier: u128, precision: u8) -> (u128, u128) {
+    round_ratio(value.to_raw() as u128, u32::MAX as u128, multiplier, precision)
+}
+
+/// Computes `numerator / denominator * multiplier` rounded to `precision` decimal
+/// places, returning the integer part and the decimal digits of the fractional part.
+fn round_ratio(numerator: u128, denominator: u128, multiplier: u128, precision: u8) -> (u128, u128) {
+    let divisor = 10u128.pow(precision as u32);
+    let scaled = numerator * multiplier;
+    let (integer, remainder) = (scaled / denominator, scaled % denominator);
+    let fraction = (remainder * divisor + denominator / 2) / denominator;
+    if fraction == divisor {
+        (integer + 1, 0)
+    } else {
+        (integer, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate alloc;
+    use alloc::format;
+
+    #[test]
+    fn test_endpoints() {
+        assert_eq!(format!("{}", PercentFormat::new(Primant::ZERO).precision(4)), "0.0000%");
+        assert_eq!(format!("{}", PercentFormat::new(Primant::MAX).precision(0)), "100%");
+        assert_eq!(format!("{}", PercentFormat::new(Primant::MAX).precision(20)), "100.00000000000000000000%");
+    }
+
+    #[test]
+    fn test_rounding() {
+        let value = Primant::from_ratio(2u32, 3u32);
+        assert_eq!(format!("{}", PercentFormat::new(value)), "66.67%");
+        assert_eq!(format!("{}", PercentFormat::new(value).precision(4)), "66.6667%");
+        let value = Primant::from_ratio(999u32, 1000u32);
+        assert_eq!(format!("{}", PercentFormat::new(value).precision(0)), "100%");
+    }
+
+    #[test]
+    fn test_engineering_units() {
+        assert_eq!(format!("{}", EngineeringFormat::new(Primant::ZERO)), "0.00%");
+        assert_eq!(format!("{}", EngineeringFormat::new(Primant::ratio_of(1, 100))), "1.00%");
+        assert_eq!(format!("{}", EngineeringFormat::new(Primant::ratio_of(25, 10_000))), "2.50‰");
+        assert_eq!(format!("{}", EngineeringFormat::new(Primant::ratio_of(150, 1_000_000))), "150.00ppm");
+        assert_eq!(format!("{}", EngineeringFormat::new(Primant::from_raw(1)).precision(1)), "0.2ppb");
+        assert_eq!(format!("{}", EngineeringFormat::new(Primant::from_raw(1)).precision(20)), "0.23283064370807973754ppb");
+        assert_eq!(format!("{}", EngineeringFormat::new(Primant::ratio_of(25, 10_000)).pad_to(7)), "  2.50‰");
+    }
+
+    #[test]
+    fn test_padding() {
+        let value = Primant::from_ratio(1u32, 2u32);
+        assert_eq!(format!("{}", PercentFormat::new(value).pad_to(8)), "  50.00%");
+        assert_eq!(format!("{}", PercentFormat::new(value).pad_to(2)), "50.00%");
+        assert_eq!(format!("{}", PercentFormat::new(value).precision(0).suffix("").pad_to(3)), " 50");
+    }
+
+    #[test]
+    fn test_parts_units() {
+        let value = Primant::ratio_of(150, 1_000_000);
+        assert_eq!(format!("{}", PartsFormat::new(value, PartsUnit::PartsPerMillion)), "150.00ppm");
+        assert_eq!(format!("{}", PartsFormat::new(value, PartsUnit::BasisPoints).pad_to(8)), " 1.50bps");
+        assert_eq!(format!("{}", PartsFormat::new(Primant::MAX, PartsUnit::Percent).precision(0)), "100%");
+        for unit in [PartsUnit::Percent, PartsUnit::PerMille, PartsUnit::BasisPoints, PartsUnit::PartsPerMillion, PartsUnit::PartsPerBillion] {
+            assert_eq!(PartsUnit::from_suffix(unit.suffix()), Some(unit));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{Lerp, Primant};
+
+/// A position between the elements of a buffer, as an integer index and a
+/// [`Primant`] fraction of the way to the next element.
+///
+/// A [`FracIndex`] is the read position of a delay line or a resampler. It is
+/// usually produced by [`Primant::split_index`], and consumed by
+/// [`FracIndex::interpolate`]. The arithmetic wraps around the length of a circular
+/// buffer, and the fractions are added exactly.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{FracIndex, Primant};
+/// let buffer = [0u8, 100, 200, 50];
+/// let write = FracIndex::new(1, Primant::ZERO);
+/// // Read 2.5 elements behind the write position, wrapping around.
+/// let delay = FracIndex::new(2, Primant::from_ratio(1u32, 2u32));
+/// let read = write.wrapping_sub(delay, buffer.len());
+/// assert_eq!(read.index(), 2);
+/// assert_eq!(read.interpolate(&buffer), 125);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FracIndex {
+    index: usize,
+    fraction: Primant,
+}
+
+impl FracIndex {
+    /// Creates a new [`FracIndex`] at `fraction` of the way from `index` to the next
+    /// element.
+    pub const fn new(index: usize, fraction: Primant) -> Self {
+        FracIndex { index, fraction }
+    }
+
+    /// Returns the integer part of the position.
+    pub const fn index(self) -> usize {
+        self.index
+    }
+
+    /// Returns the fractional part of the position.
+    pub const fn fraction(self) -> Primant {
+        self.fraction
+    }
+
+    /// Adds two positions, wrapping around a circular buffer of `len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is zero.
+    pub fn wrapping_add(self, other: FracIndex, len: usize) -> Self {
+        assert_ne!(len, 0, "length must not be zero");
+        let (mut fraction, mut carry) = self.fraction.to_raw().overflowing_add(other.fraction.to_raw());
+        // One whole is `u32::MAX` rather than `2^32`.
+        if carry {
+            fraction += 1;
+        } else if fraction == u32::MAX {
+            (fraction, carry) = (0, true);
+        }
+        let index = (self.index % len + other.index % len + carry as usize) % len;
+        FracIndex::new(index, Primant::from_raw(fraction))
+    }
+
+    /// Subtracts `other` from this position, wrapping around a circular buffer of
+    /// `len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is zero.
+    pub fn wrapping_sub(self, other: FracIndex, len: usize) -> Self {
+        assert_ne!(len, 0, "length must not be zero");
+        let (fraction, borrow) = self.fraction.to_raw().overflowing_sub(other.fraction.to_raw());
+        let fraction = if borrow { fraction - 1 } else { fraction };
+        let index = (self.index % len + len - other.index % len + len - borrow as usize) % len;
+        FracIndex::new(index, Primant::from_raw(fraction))
+    }
+
+    /// Reads `buffer` at this position, interpolating linearly between the element
+    /// at the index and the next one, wrapping around the end of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is empty.
+    pub fn interpolate<T: Lerp + Copy>(self, buffer: &[T]) -> T {
+        assert!(!buffer.is_empty(), "buffer must not be empty");
+        let index = self.index % buffer.len();
+        let next = (index + 1) % buffer.len();
+        buffer[index].lerp(buffer[next], self.fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrapping_arithmetic() {
+        let half = Primant::from_raw(u32::MAX / 2);
+        let rest = Primant::from_raw(u32::MAX - u32::MAX / 2);
+        let sum = FracIndex::new(3, half).wrapping_add(FracIndex::new(0, rest), 4);
+        assert_eq!(sum, FracIndex::new(0, Primant::ZERO));
+        let sum = FracIndex::new(1, Primant::MAX).wrapping_add(FracIndex::new(1, Primant::from_raw(5)), 4);
+        assert_eq!(sum, FracIndex::new(3, Primant::from_raw(5)));
+
+        let position = FracIndex::new(2, Primant::from_raw(7));
+        let offset = FracIndex::new(3, Primant::from_raw(9));
+        let difference = position.wrapping_sub(offset, 5);
+        assert_eq!(difference, FracIndex::new(3, Primant::from_raw(u32::MAX - 2)));
+        assert_eq!(difference.wrapping_add(offset, 5), position);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let buffer = [10i16, -10];
+        assert_eq!(FracIndex::new(0, Primant::from_raw(u32::MAX / 4)).interpolate(&buffer), 5);
+        assert_eq!(FracIndex::new(3, Primant::MAX).interpolate(&buffer), 10);
+    }
+}
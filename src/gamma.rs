@@ -0,0 +1,158 @@
+use crate::{Lut, Primant};
+
+/// `2^(2^-i)` in Q62, for `i` from `1` to `40`.
+const EXP2_STEPS: [u64; 40] = [
+    6521908912666391106, 5484249825272419512, 5029079263719320435, 4815862801830788490,
+    4712668792719003884, 4661903986662671290, 4636727017470743990, 4624189567668517720,
+    4617933561212708776, 4614808732577250068, 4613247111281068008, 4612466498810092975,
+    4612076242109103707, 4611881126141011236, 4611783571252412754, 4611734794581956353,
+    4611710406440186476, 4611698212417665819, 4611692115418496524, 4611689066921934630,
+    4611687542674409371, 4611686780550835664, 4611686399489096040, 4611686208958238036,
+    4611686113692811986, 4611686066060099699, 4611686042243743740, 4611686030335565807,
+    4611686024381476851, 4611686021404432377, 4611686019915910140, 4611686019171649022,
+    4611686018799518463, 4611686018613453183, 4611686018520420544, 4611686018473904224,
+    4611686018450646064, 4611686018439016984, 4611686018433202444, 4611686018430295174,
+];
+
+/// `-log2(u32::MAX / 2^32)` in Q40, converting a logarithm of `raw / 2^32` to one of
+/// `raw / u32::MAX`.
+const LOG2_CORRECTION: i64 = 369;
+
+/// Raises a raw [`Primant`] to the power of `numerator / denominator`.
+///
+/// The endpoints are exact, and the error elsewhere stays within one raw step.
+/// The denominator must be non-zero.
+pub(crate) const fn gamma_raw(value: u32, numerator: u32, denominator: u32) -> u32 {
+    if numerator == 0 || value == u32::MAX { return u32::MAX; }
+    if value == 0 { return 0; }
+
+    let exponent = (log2(value) as i128 * numerator as i128).div_euclid(denominator as i128);
+    let result = exp2(exponent);
+    ((result * u32::MAX as u128 + (1 << 61)) >> 62) as u32
+}
+
+/// Returns `log2(value / u32::MAX)` in Q40, for a non-zero `value`.
+pub(crate) const fn log2(value: u32) -> i64 {
+    let zeros = value.leading_zeros();
+    let mut mantissa = (value as u128) << (zeros + 31);
+    let mut fraction = 0i64;
+    let mut i = 0;
+    while i < 40 {
+        mantissa = (mantissa * mantissa) >> 62;
+        if mantissa >= 2 << 62 {
+            mantissa >>= 1;
+            fraction |= 1 << (39 - i);
+        }
+        i += 1;
+    }
+    -((zeros as i64 + 1) << 40) + fraction + LOG2_CORRECTION
+}
+
+/// Returns `2^exponent` in Q62, for a non-positive `exponent` in Q40.
+pub(crate) const fn exp2(exponent: i128) -> u128 {
+    let shift = -(exponent >> 40);
+    if shift > 62 { return 0; }
+    let mut result = 1u128 << 62;
+    let mut i = 0;
+    while i < 40 {
+        if exponent & (1 << (39 - i)) != 0 {
+            result = (result * EXP2_STEPS[i] as u128) >> 62;
+        }
+        i += 1;
+    }
+    result >> shift
+}
+
+/// A gamma-corrected table of duty cycles, such as for dimming LEDs smoothly.
+///
+/// The brightness perceived by the eye is far from linear in the duty cycle of an
+/// LED, so dimming it linearly looks abrupt near the dark end. A [`GammaTable`]
+/// holds `N` duty cycles, as 16-bit values, following `x^gamma` for `N` evenly
+/// spaced brightness levels `x`.
+///
+/// The table is generated with integer arithmetic only, so it can be computed at
+/// compile time and live in read-only memory.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{GammaTable, Primant};
+/// const GAMMA: GammaTable<256> = GammaTable::new(22, 10);
+/// assert_eq!(GAMMA.table()[0], 0);
+/// assert_eq!(GAMMA.table()[128], 14386);
+/// assert_eq!(GAMMA.table()[255], u16::MAX);
+/// assert_eq!(GAMMA.duty(Primant::from_ratio(128u32, 255u32)), 14386);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GammaTable<const N: usize> {
+    table: [u16; N],
+}
+
+impl<const N: usize> GammaTable<N> {
+    /// Creates a new [`GammaTable`] with a gamma of `numerator / denominator`.
+    ///
+    /// The first entry is always `0` and the last entry always [`u16::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has fewer than two entries, or if `denominator` is zero.
+    pub const fn new(numerator: u32, denominator: u32) -> Self {
+        assert!(N >= 2, "table must have at least two entries");
+        assert!(denominator != 0, "denominator must be non-zero");
+        let mut table = [0; N];
+        let mut i = 0;
+        while i < N {
+            let last = N as u64 - 1;
+            let level = ((i as u64 * u32::MAX as u64 + last / 2) / last) as u32;
+            let duty = gamma_raw(level, numerator, denominator) as u64;
+            table[i] = ((duty * u16::MAX as u64 + u32::MAX as u64 / 2) / u32::MAX as u64) as u16;
+            i += 1;
+        }
+        GammaTable { table }
+    }
+
+    /// Returns the underlying table.
+    pub const fn table(&self) -> &[u16; N] {
+        &self.table
+    }
+
+    /// Returns a [`Lut`] interpolating between the entries of the table.
+    pub const fn lut(&self) -> Lut<'_, u16, N> {
+        Lut::new(&self.table)
+    }
+
+    /// Returns the duty cycle for the brightness `level`, interpolating between
+    /// the entries of the table.
+    pub fn duty(&self, level: Primant) -> u16 {
+        self.lut().sample(level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn test_gamma_raw() {
+        for (numerator, denominator) in [(22, 10), (1, 1), (1, 3), (28, 10)] {
+            let gamma = numerator as f64 / denominator as f64;
+            for raw in (0..u32::MAX).step_by(9_999_991) {
+                let expected = (raw as f64 / u32::MAX as f64).powf(gamma) * u32::MAX as f64;
+                let actual = gamma_raw(raw, numerator, denominator) as f64;
+                assert!((actual - expected).abs() <= 1.0, "{raw}^{gamma}: {actual} != {expected}");
+            }
+        }
+        assert_eq!(gamma_raw(12345, 1, 1), 12345);
+        assert_eq!(gamma_raw(12345, 0, 1), u32::MAX);
+    }
+
+    #[test]
+    fn test_table() {
+        const LINEAR: GammaTable<3> = GammaTable::new(1, 1);
+        assert_eq!(LINEAR.table(), &[0, 32768, u16::MAX]);
+        let table = GammaTable::<64>::new(22, 10);
+        assert!(table.table().windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
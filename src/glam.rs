@@ -0,0 +1,130 @@
+//! Interoperability with `glam`, making [`Phase`] the angle type and [`Primant`]
+//! the interpolation parameter of graphics code.
+//!
+//! This is only available with the `glam` feature, which implements [`Lerp`] for
+//! the `glam` vectors. As the vectors have an inherent `lerp` method taking a float,
+//! the trait method has to be called as `Lerp::lerp`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use glam::Vec3;
+//! # use unifrac::{Lerp, Primant};
+//! let start = Vec3::new(0.0, 2.0, -1.0);
+//! let end = Vec3::new(4.0, 2.0, 1.0);
+//! assert_eq!(Lerp::lerp(start, end, Primant::from_ratio(1u32, 2u32)), Vec3::new(2.0, 2.0, 0.0));
+//! ```
+
+use ::glam::{DQuat, DVec2, DVec3, DVec4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use crate::{Lerp, Phase, Primant};
+
+/// Interpolates between two rotations along the shortest arc, by `t`.
+///
+/// Unlike a raw float, `t` cannot stray outside of `0.0..=1.0`, so the result is
+/// always between the rotations.
+///
+/// # Examples
+///
+/// ```
+/// # use glam::Quat;
+/// # use unifrac::Primant;
+/// let start = Quat::IDENTITY;
+/// let end = Quat::from_rotation_z(core::f32::consts::FRAC_PI_2);
+/// let middle = unifrac::glam::slerp(start, end, Primant::from_ratio(1u32, 2u32));
+/// assert!(middle.abs_diff_eq(Quat::from_rotation_z(core::f32::consts::FRAC_PI_4), 1e-6));
+/// ```
+pub fn slerp(start: Quat, end: Quat, t: Primant) -> Quat {
+    start.slerp(end, f32::from(t))
+}
+
+/// Interpolates between two double-precision rotations along the shortest arc, by
+/// `t`.
+///
+/// See [`slerp`] for the single-precision version.
+pub fn dslerp(start: DQuat, end: DQuat, t: Primant) -> DQuat {
+    start.slerp(end, f64::from(t))
+}
+
+macro_rules! impl_lerp_vector {
+    ($($t:ty => $f:ty),*) => {$(
+        impl Lerp for $t {
+            fn lerp(self, other: Self, t: Primant) -> Self {
+                <$t>::lerp(self, other, <$f>::from(t))
+            }
+        }
+    )*};
+}
+
+impl_lerp_vector!(Vec2 => f32, Vec3 => f32, Vec3A => f32, Vec4 => f32, DVec2 => f64, DVec3 => f64, DVec4 => f64);
+
+/// Constructors of `glam` quaternions from a [`Phase`].
+pub trait QuatExt {
+    /// The type of the rotation axis.
+    type Axis;
+
+    /// Creates a quaternion rotating by `phase` around `axis`, which must be
+    /// normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use glam::{Quat, Vec3};
+    /// # use unifrac::Phase;
+    /// use unifrac::glam::QuatExt;
+    ///
+    /// let rotation = Quat::from_phase_axis(Phase::from_raw(0x4000_0000), Vec3::Z);
+    /// assert!((rotation * Vec3::X).abs_diff_eq(Vec3::Y, 1e-6));
+    /// ```
+    fn from_phase_axis(phase: Phase, axis: Self::Axis) -> Self;
+}
+
+macro_rules! impl_quat_ext {
+    ($($t:ty => $axis:ty, $f:ty),*) => {$(
+        impl QuatExt for $t {
+            type Axis = $axis;
+
+            fn from_phase_axis(phase: Phase, axis: $axis) -> Self {
+                // Halving the raw value halves the angle exactly.
+                let (sin, cos) = Phase::from_raw(phase.to_raw() >> 1).sin_cos();
+                let (sin, cos) = (<$f>::from(sin), <$f>::from(cos));
+                <$t>::from_xyzw(axis.x * sin, axis.y * sin, axis.z * sin, cos)
+            }
+        }
+    )*};
+}
+
+impl_quat_ext!(Quat => Vec3, f32, DQuat => DVec3, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp() {
+        let (start, end) = (DVec2::new(1.0, -1.0), DVec2::new(3.0, 1.0));
+        assert_eq!(Lerp::lerp(start, end, Primant::ZERO), start);
+        assert_eq!(Lerp::lerp(start, end, Primant::MAX), end);
+        assert_eq!(Lerp::lerp(Vec4::ZERO, Vec4::ONE, Primant::MAX), Vec4::ONE);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let end = DQuat::from_rotation_x(2.0);
+        assert!(dslerp(DQuat::IDENTITY, end, Primant::ZERO).abs_diff_eq(DQuat::IDENTITY, 1e-12));
+        assert!(dslerp(DQuat::IDENTITY, end, Primant::MAX).abs_diff_eq(end, 1e-9));
+        let quarter = slerp(Quat::IDENTITY, Quat::from_rotation_x(2.0), Primant::from_ratio(1u32, 4u32));
+        assert!(quarter.abs_diff_eq(Quat::from_rotation_x(0.5), 1e-6));
+    }
+
+    #[test]
+    fn test_from_phase_axis() {
+        for raw in [0, 0x2000_0000, 0x8000_0000, 0xC000_0000, u32::MAX] {
+            let phase = Phase::from_raw(raw);
+            let angle = raw as f64 / 4_294_967_296.0 * core::f64::consts::TAU;
+            let expected = DQuat::from_axis_angle(DVec3::Y, angle);
+            assert!(DQuat::from_phase_axis(phase, DVec3::Y).abs_diff_eq(expected, 1e-9));
+        }
+        let rotation = Phase::from_raw(0x8000_0000).to_rotation2();
+        assert!((rotation * Vec2::X).abs_diff_eq(-Vec2::X, 1e-6));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Bipolar, Phase, Primant};
+
+/// A detector of a single frequency in a block of samples, using the Goertzel
+/// algorithm, such as for DTMF or pilot tone detection.
+///
+/// A [`Goertzel`] computes one bin of a discrete Fourier transform, at a target
+/// frequency given as a [`Phase`] increment per sample, the same way as for an
+/// [`Nco`](crate::Nco). It needs a single multiplication per sample and no table.
+/// The magnitude is expressed as a fraction of full scale, so a full-scale sine
+/// wave at the target frequency yields about [`Primant::MAX`], and a silent or
+/// unrelated signal about [`Primant::ZERO`].
+///
+/// The target should lie on a bin of the block, that is, complete a whole number of
+/// cycles over the block, to avoid leakage into neighbouring frequencies.
+///
+/// Computations use integer arithmetic only. The recurrence is written around
+/// `2 - 2 cos ω` rather than `2 cos ω`, which keeps its precision for low
+/// frequencies over long blocks.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Goertzel, Phase, Primant};
+/// // Looking for 4 cycles per 64 samples.
+/// let mut tone = Goertzel::new(Phase::from_time(4, 64));
+/// let mut other = Goertzel::new(Phase::from_time(9, 64));
+/// for sample in 0..64 {
+///     let signal = Phase::from_time(4 * sample, 64).sin();
+///     tone.push(signal);
+///     other.push(signal);
+/// }
+/// assert!(tone.magnitude() > Primant::from_ratio(99u32, 100u32));
+/// assert!(other.magnitude() < Primant::from_ratio(1u32, 100u32));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Goertzel {
+    coefficient: u128,
+    previous: i128,
+    before_previous: i128,
+    count: u32,
+}
+
+impl Goertzel {
+    /// Creates a new [`Goertzel`] detecting the frequency advancing by `increment`
+    /// every sample.
+    pub fn new(increment: Phase) -> Self {
+        // 2 - 2 cos ω = (2 sin(ω / 2))^2, in 62-bit fixed point.
+        let half_sin = Phase::from_raw(increment.to_raw() >> 1).sin().to_raw() as u128;
+        let double_half_sin = (half_sin << 63) / i32::MAX as u128;
+        Goertzel {
+            coefficient: (double_half_sin * double_half_sin) >> 62,
+            previous: 0,
+            before_previous: 0,
+            count: 0,
+        }
+    }
+
+    /// Creates a new [`Goertzel`] detecting `frequency`, when sampled at
+    /// `sample_rate`.
+    ///
+    /// Both rates must be given in the same unit, as for
+    /// [`Nco::from_frequency`](crate::Nco::from_frequency).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_rate` is zero.
+    pub fn from_frequency(frequency: u64, sample_rate: u64) -> Self {
+        Self::new(Phase::from_time(frequency, sample_rate))
+    }
+
+    /// Returns the number of samples pushed since the last reset.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Feeds the next sample of the block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u32::MAX` samples were already pushed since the last reset.
+    pub fn push(&mut self, sample: Bipolar) {
+        self.count = self.count.checked_add(1).expect("too many samples in the block");
+        let (previous, before_previous) = (self.previous, self.before_previous);
+        let next = sample.to_raw() as i128 + 2 * previous - before_previous - self.coefficient_times(previous);
+        (self.previous, self.before_previous) = (next, self.previous);
+    }
+
+    /// Returns the magnitude of the target frequency over the samples pushed since
+    /// the last reset, as a fraction of full scale, saturating at
+    /// [`Primant::MAX`].
+    ///
+    /// Without any sample, the magnitude is [`Primant::ZERO`].
+    pub fn magnitude(&self) -> Primant {
+        if self.count == 0 { return Primant::ZERO; }
+        // Drops low bits of large states, so the squares below cannot overflow.
+        let largest = self.previous.unsigned_abs().max(self.before_previous.unsigned_abs());
+        let shift = (128 - largest.leading_zeros()).saturating_sub(60);
+        let (previous, before_previous) = (self.previous >> shift, self.before_previous >> shift);
+        let difference = previous - before_previous;
+        let power = difference * difference + self.coefficient_times(previous) * before_previous;
+        let magnitude = (power.max(0) as u128).isqrt() << shift;
+
+        // A full-scale sine wave reaches half the number of samples.
+        let full_scale = self.count as u128 * i32::MAX as u128 / 2;
+        if magnitude >= full_scale { return Primant::MAX; }
+        Primant::from_raw((magnitude * u32::MAX as u128 / full_scale) as u32)
+    }
+
+    /// Starts a new block, forgetting every sample.
+    pub fn reset(&mut self) {
+        (self.previous, self.before_previous, self.count) = (0, 0, 0);
+    }
+
+    /// Returns `(2 - 2 cos ω) · value`, rounded to the nearest value.
+    fn coefficient_times(&self, value: i128) -> i128 {
+        // Splits the value, so both partial products fit in 128 bits.
+        let magnitude = value.unsigned_abs();
+        let (high, low) = (magnitude >> 62, magnitude & ((1 << 62) - 1));
+        let product = self.coefficient * high + ((self.coefficient * low + (1 << 61)) >> 62);
+        if value < 0 { -(product as i128) } else { product as i128 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_magnitude() {
+        let half = Bipolar::from_raw(i32::MAX / 2);
+        for (cycles, length) in [(1, 8), (5, 100), (100, 1000), (1, 100_000)] {
+            let mut detector = Goertzel::from_frequency(cycles, length);
+            assert_eq!(detector.magnitude(), Primant::ZERO);
+            for sample in 0..length {
+                let phase = Phase::from_time(cycles * sample, length) + Phase::from_raw(0x1234_5678);
+                // A sine wave at half of full scale.
+                detector.push(Bipolar::from_raw(phase.sin().to_raw() / 2));
+            }
+            assert_eq!(detector.count(), length as u32);
+            assert!(detector.magnitude().within(Primant::from_ratio(1u32, 2u32), Primant::from_ratio(1u32, 1000u32)));
+            detector.reset();
+            detector.push(half);
+            assert_eq!(detector.count(), 1);
+        }
+
+        // A constant signal is not at the target frequency.
+        let mut detector = Goertzel::from_frequency(3, 30);
+        for _ in 0..30 {
+            detector.push(half);
+        }
+        assert!(detector.magnitude() < Primant::from_ratio(1u32, 10_000u32));
+
+        // At zero frequency, a constant signal saturates.
+        let mut detector = Goertzel::new(Phase::MIN);
+        for _ in 0..1000 {
+            detector.push(Bipolar::MAX);
+        }
+        assert_eq!(detector.magnitude(), Primant::MAX);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{Lerp, Phase, Primant};
+
+/// A multi-stop gradient, such as a color ramp or a parameter map.
+///
+/// A [`Gradient`] holds `N` stops, each a position and a value, with the positions
+/// in non-decreasing order. Between stops, the value is interpolated linearly with
+/// [`Lerp`]. Two stops at the same position make a hard edge.
+///
+/// A gradient can be sampled in two ways:
+/// - by a [`Primant`] with [`Gradient::sample`], holding the value of the first
+///   stop before it and of the last stop after it;
+/// - by a [`Phase`] with [`Gradient::sample_phase`], taking the positions of the
+///   stops as phases and interpolating from the last stop around the cycle back to
+///   the first.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Gradient, Phase, Primant};
+/// let gradient = Gradient::new([
+///     (Primant::from_ratio(1u32, 4u32), [200u8, 0, 0]),
+///     (Primant::from_ratio(3u32, 4u32), [0, 0, 100]),
+/// ]);
+/// assert_eq!(gradient.sample(Primant::ZERO), [200, 0, 0]);
+/// assert_eq!(gradient.sample(Primant::from_ratio(1u32, 2u32)), [100, 0, 50]);
+/// // Wrapping around, the color at the start of the cycle is halfway back to red.
+/// assert_eq!(gradient.sample_phase(Phase::MIN), [100, 0, 50]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gradient<T, const N: usize> {
+    stops: [(Primant, T); N],
+}
+
+impl<T, const N: usize> Gradient<T, N> {
+    /// Creates a new [`Gradient`] from its stops.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no stops, or if their positions are decreasing.
+    pub const fn new(stops: [(Primant, T); N]) -> Self {
+        assert!(N > 0, "stops must not be empty");
+        let mut i = 1;
+        while i < N {
+            assert!(stops[i].0.to_raw() >= stops[i - 1].0.to_raw(), "positions must not be decreasing");
+            i += 1;
+        }
+        Gradient { stops }
+    }
+
+    /// Returns the stops of the gradient.
+    pub const fn stops(&self) -> &[(Primant, T); N] {
+        &self.stops
+    }
+}
+
+impl<T: Lerp + Copy, const N: usize> Gradient<T, N> {
+    /// Samples the gradient at `t`, clamping to the first and the last stop.
+    pub fn sample(&self, t: Primant) -> T {
+        let index = self.stops.partition_point(|&(position, _)| position <= t);
+        if index == 0 { return self.stops[0].1; }
+        if index == N { return self.stops[N - 1].1; }
+
+        let ((p0, v0), (p1, v1)) = (self.stops[index - 1], self.stops[index]);
+        let span = (p1.to_raw() - p0.to_raw()) as u64;
+        v0.lerp(v1, Primant::ratio_of((t.to_raw() - p0.to_raw()) as u64, span))
+    }
+
+    /// Samples the gradient at `phase`, taking the positions of the stops as phases
+    /// and wrapping from the last stop back to the first.
+    pub fn sample_phase(&self, phase: Phase) -> T {
+        let position = phase.to_raw() as u64;
+        let index = self.stops.partition_point(|&(stop, _)| stop.to_raw() as u64 <= position);
+        let (previous, next) = if index == 0 || index == N { (N - 1, 0) } else { (index - 1, index) };
+        let ((p0, v0), (p1, v1)) = (self.stops[previous], self.stops[next]);
+        let (p0, mut p1) = (p0.to_raw() as u64, p1.to_raw() as u64);
+        if next <= previous { p1 += 1 << 32; }
+        let position = if position < p0 { position + (1 << 32) } else { position };
+        if position == p0 { return v0; }
+        v0.lerp(v1, Primant::ratio_of(position - p0, p1 - p0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let gradient = Gradient::new([
+            (Primant::ZERO, 0u32),
+            (Primant::from_raw(100), 100),
+            (Primant::from_raw(100), 1000),
+            (Primant::from_raw(200), 2000),
+        ]);
+        assert_eq!(gradient.sample(Primant::from_raw(50)), 50);
+        assert_eq!(gradient.sample(Primant::from_raw(100)), 1000);
+        assert_eq!(gradient.sample(Primant::from_raw(150)), 1500);
+        assert_eq!(gradient.sample(Primant::MAX), 2000);
+    }
+
+    #[test]
+    fn test_sample_phase() {
+        let gradient = Gradient::new([(Primant::from_raw(1 << 30), 0i32), (Primant::from_raw(3 << 30), 1000)]);
+        assert_eq!(gradient.sample_phase(Phase::from_raw(1 << 31)), 500);
+        assert_eq!(gradient.sample_phase(Phase::from_raw(u32::MAX)), 500);
+        assert_eq!(gradient.sample_phase(Phase::MIN), 500);
+        assert_eq!(gradient.sample_phase(Phase::from_raw(1 << 30)), 0);
+        let single = Gradient::new([(Primant::ZERO, 7u8)]);
+        assert_eq!(single.sample_phase(Phase::from_raw(12345)), 7);
+    }
+}
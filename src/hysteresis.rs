@@ -0,0 +1,73 @@
+use crate::Primant;
+
+/// A comparator with hysteresis, also known as a Schmitt trigger.
+///
+/// The output turns on once the input reaches the upper threshold, and turns off
+/// once it falls to the lower threshold. In between, the previous output is kept,
+/// so a noisy input near a single threshold does not make the output chatter.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Hysteresis, Primant};
+/// let mut comparator = Hysteresis::new(Primant::from_ratio(2u32, 5u32), Primant::from_ratio(3u32, 5u32));
+/// assert!(!comparator.update(Primant::from_ratio(1u32, 2u32)));
+/// assert!(comparator.update(Primant::from_ratio(7u32, 10u32)));
+/// assert!(comparator.update(Primant::from_ratio(1u32, 2u32)));
+/// assert!(!comparator.update(Primant::from_ratio(3u32, 10u32)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hysteresis {
+    low: Primant,
+    high: Primant,
+    state: bool,
+}
+
+impl Hysteresis {
+    /// Creates a new [`Hysteresis`] comparator, initially off.
+    ///
+    /// If `low` is greater than `high`, they are swapped.
+    pub fn new(low: Primant, high: Primant) -> Self {
+        Hysteresis {
+            low: low.min(high),
+            high: low.max(high),
+            state: false,
+        }
+    }
+
+    /// Creates a new [`Hysteresis`] comparator, initially off, with thresholds
+    /// spaced by `width` around `center`.
+    ///
+    /// The thresholds saturate at the ends of the unit interval.
+    pub fn around(center: Primant, width: Primant) -> Self {
+        let half = width.to_raw() / 2;
+        let low = center.to_raw().saturating_sub(half);
+        let high = center.to_raw().saturating_add(width.to_raw() - half);
+        Self::new(Primant::from_raw(low), Primant::from_raw(high))
+    }
+
+    /// Returns the lower and upper thresholds.
+    pub fn thresholds(&self) -> (Primant, Primant) {
+        (self.low, self.high)
+    }
+
+    /// Returns the current output.
+    pub fn state(&self) -> bool {
+        self.state
+    }
+
+    /// Sets the current output, such as to restore a known state on startup.
+    pub fn set_state(&mut self, state: bool) {
+        self.state = state;
+    }
+
+    /// Compares the input against the thresholds and returns the new output.
+    pub fn update(&mut self, value: Primant) -> bool {
+        if value >= self.high {
+            self.state = true;
+        } else if value <= self.low {
+            self.state = false;
+        }
+        self.state
+    }
+}
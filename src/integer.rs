@@ -0,0 +1,83 @@
+/// A primitive integer type, such as the numerator and denominator of
+/// [`Primant::from_ratio`](crate::Primant::from_ratio).
+///
+/// This trait is sealed, and is implemented for every primitive integer type. With
+/// the `float` feature, it is implemented for every
+/// [`PrimInt`](num_traits::PrimInt) instead, so generic code bounded by it keeps
+/// working. Values must then fit in 128 bits.
+pub trait Integer: Copy + PartialOrd + sealed::Sealed {
+    /// Splits the value into whether it is negative and its magnitude.
+    #[doc(hidden)]
+    fn to_sign_magnitude(self) -> (bool, u128);
+
+    /// Joins a sign and a magnitude into a value, which the magnitude must fit.
+    #[doc(hidden)]
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+#[cfg(not(feature = "float"))]
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Integer for $t {
+            fn to_sign_magnitude(self) -> (bool, u128) {
+                (false, self as u128)
+            }
+
+            fn from_sign_magnitude(_: bool, magnitude: u128) -> Self {
+                magnitude as $t
+            }
+        }
+    )*};
+}
+
+#[cfg(not(feature = "float"))]
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Integer for $t {
+            fn to_sign_magnitude(self) -> (bool, u128) {
+                (self < 0, self.unsigned_abs() as u128)
+            }
+
+            fn from_sign_magnitude(negative: bool, magnitude: u128) -> Self {
+                let value = magnitude as $t;
+                if negative { value.wrapping_neg() } else { value }
+            }
+        }
+    )*};
+}
+
+#[cfg(not(feature = "float"))]
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+#[cfg(not(feature = "float"))]
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "float")]
+impl<T: num_traits::PrimInt> sealed::Sealed for T {}
+
+#[cfg(feature = "float")]
+impl<T: num_traits::PrimInt> Integer for T {
+    fn to_sign_magnitude(self) -> (bool, u128) {
+        if self < T::zero() {
+            (true, self.to_i128().expect("integer must fit in 128 bits").unsigned_abs())
+        } else {
+            (false, self.to_u128().expect("integer must fit in 128 bits"))
+        }
+    }
+
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Self {
+        let value = if negative {
+            <T as num_traits::NumCast>::from((magnitude as i128).wrapping_neg())
+        } else {
+            <T as num_traits::NumCast>::from(magnitude)
+        };
+        value.expect("magnitude must fit in the integer type")
+    }
+}
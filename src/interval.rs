@@ -0,0 +1,140 @@
+use core::ops::{Add, Mul, Sub};
+use crate::Primant;
+
+/// An interval of [`Primant`]s bounding an uncertain value, such as a measurement
+/// with a known error band.
+///
+/// Unlike a [`PrimantRange`](crate::PrimantRange), which describes a set of allowed
+/// values, a [`PrimantInterval`] stands for a single value known only to lie
+/// between its bounds, and carries that uncertainty through arithmetic. Every
+/// operation returns the smallest interval containing every possible result,
+/// saturating at [`Primant::ZERO`] and [`Primant::MAX`] like the operators of
+/// [`Primant`], and rounding its bounds outwards, so the true value never escapes.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, PrimantInterval};
+/// let efficiency = PrimantInterval::new(Primant::ratio_of(90, 100), Primant::ratio_of(95, 100));
+/// let load = PrimantInterval::new(Primant::ratio_of(40, 100), Primant::ratio_of(50, 100));
+/// let output = efficiency * load;
+/// assert!(output.lo() <= Primant::ratio_of(36, 100) && output.hi() >= Primant::ratio_of(475, 1000));
+/// assert!(output.contains(Primant::ratio_of(40, 100)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrimantInterval {
+    lo: Primant,
+    hi: Primant,
+}
+
+impl PrimantInterval {
+    /// The interval of a value known only to be a fraction.
+    pub const FULL: PrimantInterval = PrimantInterval::new(Primant::ZERO, Primant::MAX);
+
+    /// Creates a new [`PrimantInterval`] from `lo` to `hi`, both inclusive.
+    ///
+    /// If `lo` is greater than `hi`, they are swapped.
+    pub const fn new(lo: Primant, hi: Primant) -> Self {
+        if lo.to_raw() <= hi.to_raw() {
+            PrimantInterval { lo, hi }
+        } else {
+            PrimantInterval { lo: hi, hi: lo }
+        }
+    }
+
+    /// Creates a new [`PrimantInterval`] of an exactly known `value`.
+    pub const fn point(value: Primant) -> Self {
+        PrimantInterval { lo: value, hi: value }
+    }
+
+    /// Creates a new [`PrimantInterval`] of `value`, give or take `error`,
+    /// saturating at both ends of the unit interval.
+    pub const fn around(value: Primant, error: Primant) -> Self {
+        PrimantInterval { lo: value.saturating_sub(error), hi: value.saturating_add(error) }
+    }
+
+    /// Returns the lower bound of the interval.
+    pub const fn lo(&self) -> Primant {
+        self.lo
+    }
+
+    /// Returns the upper bound of the interval.
+    pub const fn hi(&self) -> Primant {
+        self.hi
+    }
+
+    /// Returns the width of the interval, which is zero for an exact value.
+    pub const fn width(&self) -> Primant {
+        self.hi.saturating_sub(self.lo)
+    }
+
+    /// Returns whether `value` lies within the interval.
+    pub const fn contains(&self, value: Primant) -> bool {
+        self.lo.to_raw() <= value.to_raw() && value.to_raw() <= self.hi.to_raw()
+    }
+}
+
+impl From<Primant> for PrimantInterval {
+    fn from(value: Primant) -> Self {
+        PrimantInterval::point(value)
+    }
+}
+
+/// Adds two intervals, saturating at [`Primant::MAX`].
+impl Add for PrimantInterval {
+    type Output = PrimantInterval;
+
+    fn add(self, other: PrimantInterval) -> Self::Output {
+        PrimantInterval { lo: self.lo + other.lo, hi: self.hi + other.hi }
+    }
+}
+
+/// Subtracts two intervals, saturating at [`Primant::ZERO`].
+impl Sub for PrimantInterval {
+    type Output = PrimantInterval;
+
+    fn sub(self, other: PrimantInterval) -> Self::Output {
+        PrimantInterval { lo: self.lo - other.hi, hi: self.hi - other.lo }
+    }
+}
+
+/// Multiplies two intervals, rounding the lower bound down and the upper bound up.
+impl Mul for PrimantInterval {
+    type Output = PrimantInterval;
+
+    fn mul(self, other: PrimantInterval) -> Self::Output {
+        PrimantInterval { lo: product(self.lo, other.lo, false), hi: product(self.hi, other.hi, true) }
+    }
+}
+
+/// Multiplies two [`Primant`]s, rounding either down or up.
+fn product(a: Primant, b: Primant, round_up: bool) -> Primant {
+    let (product, max) = (a.to_raw() as u64 * b.to_raw() as u64, u32::MAX as u64);
+    Primant::from_raw(if round_up { product.div_ceil(max) } else { product / max } as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Primant::from_raw(1000), Primant::from_raw(3000));
+        let interval = PrimantInterval::new(b, a);
+        assert_eq!((interval.lo(), interval.hi()), (a, b));
+        assert_eq!(interval.width(), Primant::from_raw(2000));
+
+        let sum = interval + PrimantInterval::around(Primant::MAX, Primant::from_raw(1500));
+        assert_eq!((sum.lo(), sum.hi()), (Primant::from_raw(u32::MAX - 500), Primant::MAX));
+        let difference = interval - PrimantInterval::point(Primant::from_raw(2000));
+        assert_eq!((difference.lo(), difference.hi()), (Primant::ZERO, a));
+        assert_eq!(PrimantInterval::point(a) - interval, PrimantInterval::new(Primant::ZERO, Primant::ZERO));
+
+        let third = Primant::from_ratio(1u32, 3u32);
+        let product = PrimantInterval::point(third) * PrimantInterval::point(third);
+        assert_eq!(product.width(), Primant::from_raw(1));
+        assert!(product.contains(third * third));
+        assert_eq!(PrimantInterval::FULL * interval, PrimantInterval::new(Primant::ZERO, b));
+        assert_eq!(PrimantInterval::FULL * PrimantInterval::FULL, PrimantInterval::FULL);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{Bipolar, Primant};
+
+/// Linear interpolation parameterized by a [`Primant`].
+///
+/// Implementations should return `self` when `t` is [`Primant::ZERO`] and `other`
+/// when `t` is [`Primant::MAX`].
+///
+/// This trait is implemented for the primitive integer types (except for the
+/// 128-bit ones), for `f32` and `f64` with the `float` feature, for [`Primant`]
+/// and [`Bipolar`], and for arrays of interpolable values, which makes it possible
+/// to interpolate colors such as `[u8; 3]`. Integer results are rounded to the
+/// nearest value.
+///
+/// Other crates can implement it for their own types, such as colors or vectors,
+/// to interpolate them the same way.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Lerp, Primant};
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Rgb(u8, u8, u8);
+///
+/// impl Lerp for Rgb {
+///     fn lerp(self, other: Self, t: Primant) -> Self {
+///         Rgb(self.0.lerp(other.0, t), self.1.lerp(other.1, t), self.2.lerp(other.2, t))
+///     }
+/// }
+///
+/// let half = Primant::from_ratio(1u32, 2u32);
+/// assert_eq!(Rgb(0, 100, 200).lerp(Rgb(200, 100, 0), half), Rgb(100, 100, 100));
+/// ```
+pub trait Lerp: Sized {
+    /// Interpolates between `self` and `other`.
+    fn lerp(self, other: Self, t: Primant) -> Self;
+}
+
+/// Interpolates from `start` to `end` by `t`, rounded to the nearest integer (with
+/// ties rounded up), for values of up to 65 bits.
+pub(crate) const fn lerp_i128(start: i128, end: i128, t: Primant) -> i128 {
+    let max = u32::MAX as i128;
+    let delta = (end - start) * t.to_raw() as i128;
+    start + (2 * delta + max).div_euclid(2 * max)
+}
+
+macro_rules! impl_lerp_integer {
+    ($($t:ty),*) => {$(
+        impl Lerp for $t {
+            fn lerp(self, other: Self, t: Primant) -> Self {
+                lerp_i128(self as i128, other as i128, t) as $t
+            }
+        }
+    )*};
+}
+
+impl_lerp_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl Lerp for Primant {
+    fn lerp(self, other: Self, t: Primant) -> Self {
+        Primant::from_raw(self.to_raw().lerp(other.to_raw(), t))
+    }
+}
+
+impl Lerp for Bipolar {
+    fn lerp(self, other: Self, t: Primant) -> Self {
+        Bipolar::from_raw(self.to_raw().lerp(other.to_raw(), t))
+    }
+}
+
+/// Interpolates between two `f32`s, as [`Primant::lerp_float`].
+#[cfg(feature = "float")]
+impl Lerp for f32 {
+    fn lerp(self, other: Self, t: Primant) -> Self {
+        t.lerp_float(self, other)
+    }
+}
+
+/// Interpolates between two `f64`s, as [`Primant::lerp_float`].
+#[cfg(feature = "float")]
+impl Lerp for f64 {
+    fn lerp(self, other: Self, t: Primant) -> Self {
+        t.lerp_float(self, other)
+    }
+}
+
+impl<T: Lerp + Copy, const N: usize> Lerp for [T; N] {
+    fn lerp(self, other: Self, t: Primant) -> Self {
+        core::array::from_fn(|i| self[i].lerp(other[i], t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints() {
+        assert_eq!(10u8.lerp(250, Primant::ZERO), 10);
+        assert_eq!(10u8.lerp(250, Primant::MAX), 250);
+        assert_eq!(i64::MIN.lerp(i64::MAX, Primant::MAX), i64::MAX);
+        assert_eq!(Bipolar::MAX.lerp(Bipolar::MIN, Primant::MAX), Bipolar::MIN);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let half = Primant::from_raw(1 << 31);
+        assert_eq!(0u8.lerp(3, half), 2);
+        assert_eq!(3u8.lerp(0, half), 1);
+        assert_eq!([0u8, 100, 200].lerp([200, 100, 0], half), [100, 100, 100]);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn test_floats() {
+        assert_eq!(0.25f32.lerp(-4.0, Primant::ZERO), 0.25);
+        assert_eq!(0.25f64.lerp(-4.0, Primant::MAX), -4.0);
+        assert!((2.0f64.lerp(4.0, Primant::from_ratio(1u32, 4u32)) - 2.5).abs() < 1e-9);
+        assert_eq!([0.0f32, 1.0].lerp([1.0, 0.0], Primant::MAX), [1.0, 0.0]);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{Bipolar, Nco, Phase, Primant, SampleHold};
+
+/// The shape of the waveform produced by an [`Lfo`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Waveform {
+    /// A sine wave, starting at the middle of its range and rising.
+    Sine,
+    /// A triangle wave, rising from the bottom to the top of its range during the
+    /// first half of the cycle, and falling back during the second half.
+    Triangle,
+    /// A sawtooth wave, rising from the bottom to the top of its range during the
+    /// whole cycle.
+    Saw,
+    /// A square wave, at the top of its range during the first half of the cycle, and
+    /// at the bottom during the second half.
+    Square,
+    /// A stepped random wave, holding a new pseudo-random value every cycle.
+    SampleHold,
+}
+
+impl Waveform {
+    /// Returns the value of the waveform at the given phase, in its full range.
+    ///
+    /// [`Waveform::SampleHold`] has no fixed shape, so this returns the middle of the
+    /// range for it.
+    pub fn sample(self, phase: Phase) -> Primant {
+        let raw = phase.to_raw();
+        match self {
+            Waveform::Sine => phase.sin().to_unipolar(),
+            Waveform::Triangle => {
+                let rising = if raw < 1 << 31 { raw as u64 } else { (1 << 32) - raw as u64 };
+                Primant::from_raw((rising << 1).min(u32::MAX as u64) as u32)
+            }
+            Waveform::Saw => Primant::from_raw(raw),
+            Waveform::Square => if raw < 1 << 31 { Primant::MAX } else { Primant::ZERO },
+            Waveform::SampleHold => Primant::from_raw(1 << 31),
+        }
+    }
+}
+
+/// A low-frequency oscillator.
+///
+/// An [`Lfo`] drives a [`Waveform`] with an [`Nco`], and scales its output by a
+/// depth and an offset, both expressed as [`Primant`]s. The output of each tick is
+/// `offset + depth × waveform`, saturating at [`Primant::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Lfo, Nco, Primant, Waveform};
+/// // A triangle sweeping between 25% and 75%, with a period of 4 ticks.
+/// let half = Primant::from_ratio(1u32, 2u32);
+/// let quarter = Primant::from_ratio(1u32, 4u32);
+/// let mut lfo = Lfo::new(Nco::from_frequency(1, 4), Waveform::Triangle)
+///     .with_depth(half)
+///     .with_offset(quarter);
+/// assert_eq!(lfo.tick().percent_of(100u32), 50);
+/// assert_eq!(lfo.tick().percent_of(100u32), 75);
+/// assert_eq!(lfo.tick().percent_of(100u32), 50);
+/// assert_eq!(lfo.tick().percent_of(100u32), 25);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lfo {
+    nco: Nco,
+    waveform: Waveform,
+    depth: Primant,
+    offset: Primant,
+    sample_hold: SampleHold,
+}
+
+impl Lfo {
+    /// Creates a new [`Lfo`] with full depth and no offset.
+    pub fn new(nco: Nco, waveform: Waveform) -> Self {
+        Lfo {
+            nco,
+            waveform,
+            depth: Primant::MAX,
+            offset: Primant::ZERO,
+            sample_hold: SampleHold::new(Primant::from_raw(1 << 31)),
+        }
+    }
+
+    /// Sets the depth of the modulation.
+    pub fn with_depth(self, depth: Primant) -> Self {
+        Lfo { depth, ..self }
+    }
+
+    /// Sets the offset added to the modulation.
+    pub fn with_offset(self, offset: Primant) -> Self {
+        Lfo { offset, ..self }
+    }
+
+    /// Sets the seed of the pseudo-random generator used by [`Waveform::SampleHold`].
+    ///
+    /// A seed of zero is replaced with a non-zero default.
+    pub fn with_seed(self, seed: u32) -> Self {
+        Lfo { sample_hold: self.sample_hold.with_seed(seed), ..self }
+    }
+
+    /// Returns the underlying oscillator.
+    pub fn nco(&self) -> &Nco {
+        &self.nco
+    }
+
+    /// Returns the underlying oscillator mutably, allowing the rate or phase to be
+    /// changed.
+    pub fn nco_mut(&mut self) -> &mut Nco {
+        &mut self.nco
+    }
+
+    /// Returns the waveform.
+    pub fn waveform(&self) -> Waveform {
+        self.waveform
+    }
+
+    /// Changes the waveform.
+    pub fn set_waveform(&mut self, waveform: Waveform) {
+        self.waveform = waveform;
+    }
+
+    /// Advances the oscillator by one tick and returns the new output value.
+    pub fn tick(&mut self) -> Primant {
+        let phase = self.nco.tick();
+        let shape = match self.waveform {
+            Waveform::SampleHold => self.sample_hold.update_random(phase),
+            waveform => waveform.sample(phase),
+        };
+        let scaled = self.depth.scale_u128(shape.to_raw() as u128, true) as u32;
+        Primant::from_raw(self.offset.to_raw().saturating_add(scaled))
+    }
+
+    /// Advances the oscillator by one tick and returns the new output value, mapped
+    /// onto the range of a [`Bipolar`].
+    ///
+    /// With full depth and no offset, the output swings across the whole bipolar
+    /// range. See [`Bipolar::from_unipolar`].
+    pub fn tick_bipolar(&mut self) -> Bipolar {
+        Bipolar::from_unipolar(self.tick())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waveforms() {
+        let quarter = Phase::from_raw(1 << 30);
+        let half = Phase::from_raw(1 << 31);
+        assert_eq!(Waveform::Sine.sample(quarter), Primant::MAX);
+        assert_eq!(Waveform::Triangle.sample(Phase::MIN), Primant::ZERO);
+        assert_eq!(Waveform::Triangle.sample(half), Primant::MAX);
+        assert_eq!(Waveform::Saw.sample(Phase::MAX), Primant::MAX);
+        assert_eq!(Waveform::Square.sample(quarter), Primant::MAX);
+        assert_eq!(Waveform::Square.sample(half), Primant::ZERO);
+    }
+
+    #[test]
+    fn test_sample_hold() {
+        let mut lfo = Lfo::new(Nco::from_frequency(1, 4), Waveform::SampleHold);
+        let first = [lfo.tick(), lfo.tick(), lfo.tick()];
+        assert!(first.iter().all(|&value| value == first[0]));
+        let second = lfo.tick();
+        assert_ne!(second, first[0]);
+        assert_eq!(lfo.tick(), second);
+    }
+
+    #[test]
+    fn test_depth_and_offset() {
+        let mut lfo = Lfo::new(Nco::from_frequency(1, 4), Waveform::Square)
+            .with_depth(Primant::from_ratio(1u32, 2u32))
+            .with_offset(Primant::from_ratio(3u32, 4u32));
+        assert_eq!(lfo.tick(), Primant::MAX);
+        lfo.tick();
+        assert_eq!(lfo.tick(), Primant::from_ratio(3u32, 4u32));
+        let mut lfo = Lfo::new(Nco::from_frequency(1, 4), Waveform::Square);
+        assert_eq!(lfo.tick_bipolar(), Bipolar::MAX);
+    }
+}
@@ -1,12 +1,181 @@
 //! This crate provides several fractional types for Rust:
 //! - [`Primant`] — a type representing a fraction between 0 and 1 (inclusive).
 //! - [`Phase`] — a type representing a fraction between 0 and 1 (exclusive).
+//! - [`Bipolar`] — a type representing a fraction between -1 and 1 (inclusive).
 //!
 //! It does not depend on the standard library, so it can be used in `no_std` contexts.
 //! Be aware that tests do require the standard library, at least for now.
+//!
+//! # Features
+//!
+//! - `float` (enabled by default) — enables conversions to and from floating-point
+//!   numbers, depending on the `num-traits` crate. Without it, the crate has no
+//!   dependencies.
+//! - `std` — enables functionality depending on the standard library, such as
+//!   `Phase::from_instant`. Implies `alloc`.
+//! - `alloc` — enables types and functions depending on heap allocation, such as
+//!   `BigPrimant` or `Primant::normalize`.
+//! - `colormaps` — enables the preset perceptual colormaps of `Colormap`.
+//! - `rand_core` — enables sampling functions driven by a `rand_core` generator, such
+//!   as `Primant::stratified`.
+//! - `libm` — enables statistical and transcendental functions depending on the `libm`
+//!   crate, such as `Primant::wilson_interval` or `Primant::to_decibels`, so they work
+//!   without the platform math library. Implies `float`.
+//! - `serde` — implements `Serialize` and `Deserialize` for the fractional types, as
+//!   numbers in their ranges, and enables the helpers in the `serde` module, such as
+//!   for serializing a [`Phase`] in degrees. Implies `float`.
+//! - `schemars` — implements `JsonSchema` for the fractional types, describing them as
+//!   numbers in their ranges, as serialized with the `serde` feature.
+//! - `heapless` — enables rolling statistics over fixed-capacity ring buffers, such as
+//!   `RollingMean`, depending on the `heapless` crate.
+//! - `nalgebra` — implements `Lerp` for `nalgebra` vectors and matrices, and
+//!   conversions from a [`Phase`] to 2D rotations, and enables the helpers in the
+//!   `nalgebra` module, such as for interpolating quaternions. Implies `float`.
+//! - `glam` — implements `Lerp` for `glam` vectors, and enables the helpers in the
+//!   `glam` module, such as for building a quaternion from a [`Phase`]. Implies
+//!   `float`.
+//! - `az` — implements the `Cast`, `CheckedCast` and `SaturatingCast` traits of the
+//!   `az` crate between the fractional types, the primitive integers, and floats.
+//!   Implies `float`.
+//! - `nightly-f16` — implements conversions to and from the native `f16` type. Requires
+//!   a nightly compiler. Implies `float`.
 #![no_std]
+#![cfg_attr(feature = "nightly-f16", feature(f16))]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(any(feature = "alloc", feature = "schemars"))]
+extern crate alloc;
+
+mod integer;
 mod primant;
+mod primant128;
+#[cfg(feature = "alloc")]
+mod big_primant;
 mod phase;
+mod format;
+mod parse;
+mod beat;
+mod bipolar;
+mod nco;
+mod chirp;
+mod lfo;
+mod sample_hold;
+mod random;
+mod shaping;
+mod random_walk;
+mod noise;
+mod lerp;
+mod lut;
+mod curve;
+mod calibration;
+mod battery;
+#[cfg(feature = "float")]
+mod pid;
+mod hysteresis;
+mod bang_bang;
+mod soft_start;
+mod fan_curve;
+mod gamma;
+mod breath;
+mod color;
+mod gradient;
+mod dither;
+mod reservoir;
+mod backoff;
+mod ratio_budget;
+mod round_robin;
+mod success_rate;
+mod rate_estimator;
+mod range;
+mod rounding;
+mod sweep;
+mod frac_index;
+mod resample;
+mod crossfade;
+pub mod overflow;
+mod arc;
+mod split;
+mod top_k;
+mod interval;
+mod phasor;
+mod goertzel;
+mod sync_detector;
+mod duty_meter;
+mod phase_meter;
+#[cfg(feature = "libm")]
+mod beta;
+#[cfg(feature = "colormaps")]
+mod colormap;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "nightly-f16")]
+mod nightly_f16;
+#[cfg(feature = "heapless")]
+mod rolling;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+#[cfg(feature = "glam")]
+pub mod glam;
+#[cfg(feature = "az")]
+mod az;
 
+pub use integer::Integer;
 pub use primant::Primant;
-pub use phase::Phase;
\ No newline at end of file
+pub use primant128::Primant128;
+#[cfg(feature = "alloc")]
+pub use big_primant::BigPrimant;
+pub use phase::Phase;
+pub use format::{EngineeringFormat, PartsFormat, PartsUnit, PercentFormat};
+pub use parse::ParsePrimantError;
+pub use beat::BeatClock;
+pub use bipolar::Bipolar;
+pub use nco::Nco;
+pub use chirp::{Chirp, ChirpShape};
+pub use lfo::{Lfo, Waveform};
+pub use sample_hold::SampleHold;
+pub use random_walk::RandomWalk;
+pub use noise::ValueNoise1D;
+pub use lerp::Lerp;
+pub use lut::Lut;
+pub use curve::MonotonicCurve;
+pub use calibration::Calibration;
+pub use battery::BatteryCurve;
+#[cfg(feature = "float")]
+pub use pid::Pid;
+pub use hysteresis::Hysteresis;
+pub use bang_bang::{BangBang, Direction};
+pub use soft_start::{RampProfile, SoftStart};
+pub use fan_curve::FanCurve;
+pub use gamma::GammaTable;
+pub use breath::{Breath, BreathShape};
+pub use color::hsv_to_rgb;
+pub use gradient::Gradient;
+#[cfg(feature = "colormaps")]
+pub use colormap::Colormap;
+pub use dither::{bayer_threshold, TpdfDither};
+pub use reservoir::Reservoir;
+pub use backoff::Backoff;
+pub use ratio_budget::RatioBudget;
+pub use round_robin::WeightedRoundRobin;
+pub use success_rate::{ExponentialSuccessRate, SuccessRate};
+pub use rate_estimator::RateEstimator;
+pub use range::PrimantRange;
+pub use rounding::Rounding;
+pub use sweep::{PhaseCycle, SweepInclusive};
+pub use frac_index::FracIndex;
+pub use resample::ResamplePos;
+pub use crossfade::{crossfade_slices, CrossfadeSample, Easing};
+pub use arc::Arc;
+pub use split::split_budget;
+pub use top_k::top_k_by_score;
+pub use interval::PrimantInterval;
+pub use phasor::Phasor;
+pub use goertzel::Goertzel;
+pub use sync_detector::SyncDetector;
+pub use duty_meter::DutyMeter;
+pub use phase_meter::PhaseMeter;
+#[cfg(feature = "heapless")]
+pub use rolling::{RollingMean, RollingMinMax};
\ No newline at end of file
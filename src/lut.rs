@@ -0,0 +1,97 @@
+use crate::{Lerp, Phase, Primant};
+
+/// A lookup table with linear interpolation between its entries.
+///
+/// A [`Lut`] borrows a table of `N` values and samples it in two ways:
+/// - by a [`Primant`], with the first entry at `0.0` and the last entry at `1.0`,
+///   which suits transfer curves like gamma correction or sensor calibration;
+/// - by a [`Phase`], with the entries evenly spaced around the cycle and the last
+///   entry interpolating back to the first, which suits periodic waveforms.
+///
+/// As it can be constructed in a `const` context, the table can live in read-only
+/// memory.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Lut, Phase, Primant};
+/// const CURVE: Lut<u16, 3> = Lut::new(&[0, 100, 1000]);
+/// assert_eq!(CURVE.sample(Primant::from_ratio(3u32, 4u32)), 550);
+/// // Sampled by phase, the entries are spread evenly around the cycle.
+/// assert_eq!(CURVE.sample_phase(Phase::from_raw(1 << 31)), 550);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lut<'a, T, const N: usize> {
+    table: &'a [T; N],
+}
+
+impl<'a, T, const N: usize> Lut<'a, T, N> {
+    /// Creates a new [`Lut`] from a table of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table is empty.
+    pub const fn new(table: &'a [T; N]) -> Self {
+        assert!(N > 0, "table must not be empty");
+        Lut { table }
+    }
+
+    /// Returns the underlying table.
+    pub const fn table(&self) -> &'a [T; N] {
+        self.table
+    }
+}
+
+impl<T: Lerp + Copy, const N: usize> Lut<'_, T, N> {
+    /// Samples the table at `t`, with the first entry at `0.0` and the last entry
+    /// at `1.0`.
+    ///
+    /// Entries are hit exactly when `t` falls on them.
+    pub fn sample(&self, t: Primant) -> T {
+        let position = t.to_raw() as u64 * (N as u64 - 1);
+        let (index, fraction) = ((position / u32::MAX as u64) as usize, position % u32::MAX as u64);
+        if fraction == 0 {
+            return self.table[index];
+        }
+        self.table[index].lerp(self.table[index + 1], Primant::from_raw(fraction as u32))
+    }
+
+    /// Samples the table at `phase`, with the entries evenly spaced around the
+    /// cycle, starting with the first entry at [`Phase::MIN`].
+    ///
+    /// Between the last entry and the end of the cycle, the value is interpolated
+    /// back towards the first entry.
+    pub fn sample_phase(&self, phase: Phase) -> T {
+        let position = phase.to_raw() as u64 * N as u64;
+        let (index, fraction) = ((position >> 32) as usize, position & 0xFFFF_FFFF);
+        if fraction == 0 {
+            return self.table[index];
+        }
+        // The fraction is a part of 2^32, unlike the raw value of a `Primant`.
+        self.table[index].lerp(self.table[(index + 1) % N], Primant::ratio_of(fraction, 1 << 32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let lut = Lut::new(&[0u32, 1000, 3000]);
+        assert_eq!(lut.sample(Primant::ZERO), 0);
+        assert_eq!(lut.sample(Primant::from_raw(u32::MAX / 2)), 1000);
+        assert_eq!(lut.sample(Primant::MAX), 3000);
+        assert_eq!(lut.sample(Primant::from_ratio(1u32, 4u32)), 500);
+        assert_eq!(Lut::new(&[7u8]).sample(Primant::MAX), 7);
+    }
+
+    #[test]
+    fn test_sample_phase() {
+        let lut = Lut::new(&[0i16, 1000, 0, -1000]);
+        assert_eq!(lut.sample_phase(Phase::MIN), 0);
+        assert_eq!(lut.sample_phase(Phase::from_raw(1 << 30)), 1000);
+        assert_eq!(lut.sample_phase(Phase::from_raw(7 << 29)), -500);
+        assert_eq!(lut.sample_phase(Phase::MAX), 0);
+    }
+}
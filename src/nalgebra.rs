@@ -0,0 +1,113 @@
+//! Interoperability with `nalgebra`, so that normalized parameters can stay in the
+//! types of this crate.
+//!
+//! This is only available with the `nalgebra` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! # use nalgebra::{Rotation2, Vector2};
+//! # use unifrac::{Lerp, Phase, Primant};
+//! let start = Vector2::new(0.0f32, 2.0);
+//! let end = Vector2::new(4.0f32, 2.0);
+//! assert_eq!(start.lerp(end, Primant::from_ratio(1u32, 4u32)), Vector2::new(1.0, 2.0));
+//!
+//! let quarter_turn = Rotation2::<f32>::from(Phase::from_raw(0x4000_0000));
+//! let turned = quarter_turn * Vector2::x();
+//! assert!(turned.x.abs() < 1e-6 && (turned.y - 1.0).abs() < 1e-6);
+//! ```
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use ::nalgebra::{RealField, UnitQuaternion};
+use ::nalgebra::{Complex, Matrix2, Rotation2, SMatrix, Unit, UnitComplex};
+use crate::{Lerp, Phase, Primant};
+
+/// Interpolates between two rotations along the shortest arc, by `t`.
+///
+/// Unlike a raw float, `t` cannot stray outside of `0.0..=1.0`, so the result is
+/// always between the rotations. If they are opposite, so that the arc is
+/// ambiguous, `start` is returned.
+///
+/// This function is only available with the `std` or the `libm` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use nalgebra::{UnitQuaternion, Vector3};
+/// # use unifrac::Primant;
+/// let start = UnitQuaternion::identity();
+/// let end = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), core::f64::consts::FRAC_PI_2);
+/// let middle = unifrac::nalgebra::slerp(&start, &end, Primant::from_ratio(1u32, 2u32));
+/// assert!((middle.angle() - core::f64::consts::FRAC_PI_4).abs() < 1e-9);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn slerp<T: RealField + From<Primant>>(start: &UnitQuaternion<T>, end: &UnitQuaternion<T>, t: Primant) -> UnitQuaternion<T> {
+    start.try_slerp(end, T::from(t), T::default_epsilon()).unwrap_or(start.clone())
+}
+
+macro_rules! impl_nalgebra {
+    ($($t:ty),*) => {$(
+        impl<const R: usize, const C: usize> Lerp for SMatrix<$t, R, C> {
+            fn lerp(self, other: Self, t: Primant) -> Self {
+                let t = <$t>::from(t);
+                self + (other - self) * t
+            }
+        }
+
+        impl From<Phase> for UnitComplex<$t> {
+            fn from(phase: Phase) -> Self {
+                let (sin, cos) = phase.sin_cos();
+                Unit::new_unchecked(Complex::new(<$t>::from(cos), <$t>::from(sin)))
+            }
+        }
+
+        impl From<Phase> for Rotation2<$t> {
+            fn from(phase: Phase) -> Self {
+                let (sin, cos) = phase.sin_cos();
+                let (sin, cos) = (<$t>::from(sin), <$t>::from(cos));
+                Rotation2::from_matrix_unchecked(Matrix2::new(cos, -sin, sin, cos))
+            }
+        }
+    )*};
+}
+
+impl_nalgebra!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use ::nalgebra::{Matrix2, Vector3};
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use ::nalgebra::UnitQuaternion;
+    use super::*;
+
+    #[test]
+    fn test_lerp() {
+        let start = Vector3::new(1.0, -2.0, 0.5);
+        let end = Vector3::new(3.0, 2.0, 0.5);
+        assert_eq!(start.lerp(end, Primant::ZERO), start);
+        assert_eq!(start.lerp(end, Primant::MAX), end);
+        let matrix = Matrix2::<f32>::identity().lerp(Matrix2::zeros(), Primant::MAX);
+        assert_eq!(matrix, Matrix2::zeros());
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_slerp() {
+        let start = UnitQuaternion::<f32>::identity();
+        let end = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 2.0);
+        assert!(slerp(&start, &end, Primant::ZERO).angle_to(&start) < 1e-6);
+        assert!(slerp(&start, &end, Primant::MAX).angle_to(&end) < 1e-6);
+        let quarter = slerp(&start, &end, Primant::from_ratio(1u32, 4u32));
+        assert!((quarter.angle() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let half_turn = Phase::from_raw(0x8000_0000);
+        let complex = UnitComplex::<f64>::from(half_turn);
+        assert!((complex.re + 1.0).abs() < 1e-9 && complex.im.abs() < 1e-9);
+        let rotation = Rotation2::<f64>::from(Phase::from_raw(0x2000_0000));
+        let expected = core::f64::consts::FRAC_1_SQRT_2;
+        assert!((rotation.matrix()[(1, 0)] - expected).abs() < 1e-9);
+    }
+}
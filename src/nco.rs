@@ -0,0 +1,82 @@
+use crate::Phase;
+
+/// A numerically controlled oscillator.
+///
+/// An [`Nco`] is a phase accumulator: every tick, it advances its [`Phase`] by a
+/// constant increment, wrapping around at the end of the cycle. As the increment is
+/// an exact fraction of a turn, the oscillator does not drift.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Nco, Phase};
+/// // A 1 Hz oscillator ticked at 4 Hz.
+/// let mut nco = Nco::from_frequency(1, 4);
+/// assert_eq!(nco.tick(), Phase::from_raw(1 << 30));
+/// assert_eq!(nco.tick(), Phase::from_raw(1 << 31));
+/// nco.tick();
+/// assert!(!nco.wrapped());
+/// assert_eq!(nco.tick(), Phase::MIN);
+/// assert!(nco.wrapped());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Nco {
+    phase: Phase,
+    increment: Phase,
+    wrapped: bool,
+}
+
+impl Nco {
+    /// Creates a new [`Nco`] starting at [`Phase::MIN`], advancing by `increment`
+    /// every tick.
+    pub fn new(increment: Phase) -> Self {
+        Nco {
+            phase: Phase::MIN,
+            increment,
+            wrapped: false,
+        }
+    }
+
+    /// Creates a new [`Nco`] oscillating at `frequency`, when ticked at `tick_rate`.
+    ///
+    /// Both rates must be given in the same unit, such as hertz or millihertz. The
+    /// increment is rounded down, and frequencies above the tick rate alias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tick_rate` is zero.
+    pub fn from_frequency(frequency: u64, tick_rate: u64) -> Self {
+        Self::new(Phase::from_time(frequency, tick_rate))
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Sets the current phase.
+    pub fn set_phase(&mut self, phase: Phase) {
+        self.phase = phase;
+    }
+
+    /// Returns the increment applied every tick.
+    pub fn increment(&self) -> Phase {
+        self.increment
+    }
+
+    /// Sets the increment applied every tick, keeping the current phase.
+    pub fn set_increment(&mut self, increment: Phase) {
+        self.increment = increment;
+    }
+
+    /// Advances the oscillator by one tick and returns the new phase.
+    pub fn tick(&mut self) -> Phase {
+        (self.phase, self.wrapped) = self.phase.overflowing_add(self.increment);
+        self.phase
+    }
+
+    /// Returns whether the last tick wrapped around the end of the cycle.
+    pub fn wrapped(&self) -> bool {
+        self.wrapped
+    }
+}
@@ -0,0 +1,49 @@
+//! Conversions between the fractional types and the native `f16` type.
+//!
+//! These are only available with the `nightly-f16` feature, which requires a nightly
+//! compiler.
+//!
+//! Every conversion goes through `f64`, which represents every `f16` exactly. The
+//! other way around, an `f64` quotient of two 32-bit integers can never be exactly
+//! halfway between two `f16` values unless the quotient itself is, so rounding it to
+//! `f16` gives the correctly rounded result.
+
+use crate::{Bipolar, Phase, Primant};
+
+macro_rules! impl_f16 {
+    ($($t:ident),*) => {$(
+        impl TryFrom<f16> for $t {
+            type Error = ();
+
+            fn try_from(value: f16) -> Result<Self, Self::Error> {
+                $t::try_from(value as f64)
+            }
+        }
+
+        impl From<$t> for f16 {
+            fn from(value: $t) -> Self {
+                f64::from(value) as f16
+            }
+        }
+    )*};
+}
+
+impl_f16!(Primant, Phase, Bipolar);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(Primant::try_from(1.0f16), Ok(Primant::MAX));
+        assert_eq!(Primant::try_from(-0.5f16), Err(()));
+        assert_eq!(Phase::try_from(1.0f16), Err(()));
+        assert_eq!(f16::from(Bipolar::MIN), -1.0);
+        assert_eq!(f16::from(Primant::from_raw(1)), 2.3283064e-10f32 as f16);
+        for bits in 0..=0x3C00u16 {
+            let value = f16::from_bits(bits);
+            assert_eq!(f16::from(Primant::try_from(value).unwrap()), value);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Phase, Primant};
+
+/// One-dimensional value noise sampled around a cycle.
+///
+/// The cycle is divided into a number of cells. Each cell boundary is assigned a
+/// pseudo-random value derived from a hash of its index and a seed, and values in
+/// between are smoothly interpolated. As the last cell connects back to the first,
+/// the noise tiles seamlessly once per revolution, which makes it suitable for
+/// modulating LED rings or other circular layouts.
+///
+/// It is computed using integer arithmetic only.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Phase, ValueNoise1D};
+/// let noise = ValueNoise1D::new(8, 42);
+/// // The end of the cycle joins seamlessly with its start.
+/// let (end, start) = (noise.sample(Phase::MAX), noise.sample(Phase::MIN));
+/// assert!(end.to_raw().abs_diff(start.to_raw()) < 1 << 8);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValueNoise1D {
+    cells: u32,
+    seed: u32,
+}
+
+impl ValueNoise1D {
+    /// Creates a new [`ValueNoise1D`] with the given number of cells per revolution.
+    ///
+    /// More cells produce a more detailed noise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cells` is zero.
+    pub fn new(cells: u32, seed: u32) -> Self {
+        assert_ne!(cells, 0, "number of cells must not be zero");
+        ValueNoise1D { cells, seed }
+    }
+
+    /// Returns the number of cells per revolution.
+    pub fn cells(&self) -> u32 {
+        self.cells
+    }
+
+    /// Samples the noise at the given position around the cycle.
+    pub fn sample(&self, phase: Phase) -> Primant {
+        let position = phase.to_raw() as u64 * self.cells as u64;
+        let cell = (position >> 32) as u32;
+        let t = position & 0xFFFF_FFFF;
+
+        // Smoothstep, 3t² - 2t³, in 32-bit fixed point.
+        let t_squared = (t * t) >> 32;
+        let t_cubed = (t_squared * t) >> 32;
+        let weight = (3 * t_squared - 2 * t_cubed) as i128;
+
+        let start = self.lattice(cell) as i128;
+        let end = self.lattice((cell + 1) % self.cells) as i128;
+        Primant::from_raw((start + (((end - start) * weight) >> 32)) as u32)
+    }
+
+    /// Returns the value assigned to the given cell boundary.
+    fn lattice(&self, cell: u32) -> u32 {
+        // The "lowbias32" integer hash by Chris Wellons.
+        let mut x = cell ^ self.seed.wrapping_mul(0x9E37_79B9);
+        x ^= x >> 16;
+        x = x.wrapping_mul(0x7FEB_352D);
+        x ^= x >> 15;
+        x = x.wrapping_mul(0x846C_A68B);
+        x ^= x >> 16;
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_continuity() {
+        let noise = ValueNoise1D::new(16, 1);
+        let mut previous = noise.sample(Phase::MAX);
+        for raw in (0..=u32::MAX).step_by(1 << 16) {
+            let value = noise.sample(Phase::from_raw(raw));
+            assert!(value.to_raw().abs_diff(previous.to_raw()) < 1 << 24);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn test_lattice() {
+        let noise = ValueNoise1D::new(4, 1);
+        assert_eq!(noise.sample(Phase::MIN).to_raw(), noise.lattice(0));
+        assert_eq!(noise.sample(Phase::from_raw(1 << 30)).to_raw(), noise.lattice(1));
+    }
+}
@@ -0,0 +1,193 @@
+//! Wrappers choosing the overflow behavior of [`Primant`] arithmetic at the type
+//! level.
+//!
+//! Each wrapper implements the arithmetic operators with one behavior, so a
+//! codebase can pick it once, in its types, rather than at every call site.
+//! Multiplying two fractions cannot leave the unit interval, so products are the
+//! same under every policy, and rounded to the nearest value.
+//!
+//! # Examples
+//!
+//! ```
+//! # use unifrac::overflow::{Checked, Saturating, Wrapping};
+//! # use unifrac::Primant;
+//! let (a, b) = (Primant::from_ratio(3u32, 4u32), Primant::from_ratio(1u32, 2u32));
+//! assert_eq!(Saturating(a) + Saturating(b), Saturating(Primant::MAX));
+//! assert_eq!((Wrapping(a) + Wrapping(b)).0, Primant::from_ratio(1u32, 4u32));
+//! assert_eq!(Checked::new(a) + Checked::new(b), Checked(None));
+//! ```
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::Primant;
+
+/// A [`Primant`] whose arithmetic saturates at [`Primant::ZERO`] and
+/// [`Primant::MAX`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Saturating<T>(pub T);
+
+/// A [`Primant`] whose arithmetic wraps around by one whole, keeping only the
+/// fractional part of sums and differences, such as `0.75 + 0.5 = 0.25`.
+///
+/// Sums and differences are taken modulo one whole, so [`Primant::MAX`] counts as
+/// [`Primant::ZERO`], and they always yield [`Primant::ZERO`] rather than
+/// [`Primant::MAX`]. Addition and subtraction thus undo each other for every value.
+/// Products still take [`Primant::MAX`] as one whole.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Wrapping<T>(pub T);
+
+/// A [`Primant`] whose arithmetic yields [`None`] once a result leaves the unit
+/// interval, and stays [`None`] through any further operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checked<T>(pub Option<T>);
+
+impl<T> Checked<T> {
+    /// Wraps a valid value.
+    pub const fn new(value: T) -> Self {
+        Checked(Some(value))
+    }
+}
+
+impl Add for Saturating<Primant> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Saturating(self.0.saturating_add(other.0))
+    }
+}
+
+impl Sub for Saturating<Primant> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Saturating(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Mul for Saturating<Primant> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Saturating(self.0 * other.0)
+    }
+}
+
+impl Add for Wrapping<Primant> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let sum = self.0.to_raw() as u64 + other.0.to_raw() as u64;
+        Wrapping(Primant::from_raw((sum % u32::MAX as u64) as u32))
+    }
+}
+
+impl Sub for Wrapping<Primant> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let difference = self.0.to_raw() as i64 - other.0.to_raw() as i64;
+        Wrapping(Primant::from_raw(difference.rem_euclid(u32::MAX as i64) as u32))
+    }
+}
+
+impl Mul for Wrapping<Primant> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Wrapping(self.0 * other.0)
+    }
+}
+
+impl Add for Checked<Primant> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Checked(self.0.zip(other.0).and_then(|(a, b)| a.checked_add(b)))
+    }
+}
+
+impl Sub for Checked<Primant> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Checked(self.0.zip(other.0).and_then(|(a, b)| a.checked_sub(b)))
+    }
+}
+
+impl Mul for Checked<Primant> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Checked(self.0.zip(other.0).map(|(a, b)| a * b))
+    }
+}
+
+macro_rules! impl_assign {
+    ($($wrapper:ident),*) => {$(
+        impl AddAssign for $wrapper<Primant> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $wrapper<Primant> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl MulAssign for $wrapper<Primant> {
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+    )*};
+}
+
+impl_assign!(Saturating, Wrapping, Checked);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturating() {
+        let mut value = Saturating(Primant::from_raw(10));
+        value -= Saturating(Primant::from_raw(11));
+        assert_eq!(value.0, Primant::ZERO);
+        value += Saturating(Primant::MAX);
+        value += Saturating(Primant::from_raw(1));
+        assert_eq!(value.0, Primant::MAX);
+        value *= Saturating(Primant::from_ratio(1u32, 2u32));
+        assert_eq!(value.0, Primant::from_raw(u32::MAX / 2));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let raw = |raw| Wrapping(Primant::from_raw(raw));
+        assert_eq!(raw(u32::MAX) + raw(0), raw(0));
+        assert_eq!(raw(u32::MAX) + raw(1), raw(1));
+        assert_eq!(raw(u32::MAX - 1) + raw(u32::MAX), raw(u32::MAX - 1));
+        assert_eq!(raw(u32::MAX - 1) + raw(1), raw(0));
+        assert_eq!(raw(0) - raw(u32::MAX), raw(0));
+        assert_eq!(raw(u32::MAX) - raw(0), raw(0));
+        assert_eq!(raw(3) - raw(5), raw(u32::MAX - 2));
+        // Subtracting and adding back yields the same residue, even for one whole.
+        for (a, b) in [(3, 5), (0, u32::MAX), (u32::MAX, 7), (u32::MAX, u32::MAX)] {
+            assert_eq!(raw(a) - raw(b) + raw(b), raw(a) + raw(0));
+        }
+    }
+
+    #[test]
+    fn test_checked() {
+        let mut value = Checked::new(Primant::from_raw(5));
+        value -= Checked::new(Primant::from_raw(5));
+        assert_eq!(value, Checked::new(Primant::ZERO));
+        value -= Checked::new(Primant::from_raw(1));
+        assert_eq!(value, Checked(None));
+        // Overflow is sticky.
+        value += Checked::new(Primant::MAX);
+        value *= Checked::new(Primant::ZERO);
+        assert_eq!(value, Checked(None));
+        assert_eq!(Checked::new(Primant::MAX) * Checked::new(Primant::MAX), Checked::new(Primant::MAX));
+    }
+}
@@ -0,0 +1,186 @@
+use core::fmt::{Display, Formatter};
+use crate::{Bipolar, PartsUnit, Phase, Primant};
+
+/// An error returned when parsing a [`Primant`], a [`Bipolar`] or a [`Phase`] from a
+/// string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParsePrimantError {
+    /// The string contains no number.
+    Empty,
+    /// The number is not a valid non-negative decimal.
+    InvalidNumber,
+    /// The suffix after the number is not a known unit.
+    UnknownUnit,
+    /// The value is greater than one whole, or for a [`Phase`], not less than one
+    /// turn.
+    OutOfRange,
+}
+
+impl Display for ParsePrimantError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ParsePrimantError::Empty => "cannot parse a fraction from an empty string",
+            ParsePrimantError::InvalidNumber => "invalid decimal number",
+            ParsePrimantError::UnknownUnit => "unknown unit",
+            ParsePrimantError::OutOfRange => "fraction must not be greater than one",
+        })
+    }
+}
+
+impl core::error::Error for ParsePrimantError {}
+
+/// The largest mantissa that can still take another digit, keeping every product
+/// in the computation within 128 bits.
+const MANTISSA_LIMIT: u128 = 10u128.pow(26);
+
+/// The largest number of fractional digits kept exactly.
+const MAX_FRACTION_DIGITS: u32 = 26;
+
+/// Parses a decimal number, optionally followed by a [`PartsUnit`] suffix, into the
+/// nearest [`Primant`], with ties rounded up.
+pub(crate) fn parse_primant(text: &str) -> Result<Primant, ParsePrimantError> {
+    parse_magnitude(text.trim(), u32::MAX as u128, true, parts_of).map(|raw| Primant::from_raw(raw as u32))
+}
+
+/// Parses a decimal number, optionally followed by a [`PartsUnit`] suffix or a `°`
+/// sign for degrees, into the nearest [`Phase`], with ties rounded up.
+///
+/// A full turn is out of range, but values just below it may round up to it, which
+/// wraps around to [`Phase::MIN`].
+pub(crate) fn parse_phase(text: &str) -> Result<Phase, ParsePrimantError> {
+    let parts = |suffix: &str| if suffix == "°" { Some(360) } else { parts_of(suffix) };
+    parse_magnitude(text.trim(), 1 << 32, false, parts).map(|raw| Phase::from_raw(raw as u32))
+}
+
+/// Parses a decimal number with an optional sign, optionally followed by a
+/// [`PartsUnit`] suffix, into the nearest [`Bipolar`], with ties rounded away from
+/// zero.
+pub(crate) fn parse_bipolar(text: &str) -> Result<Bipolar, ParsePrimantError> {
+    let text = text.trim();
+    let (negative, magnitude) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    if magnitude.is_empty() && magnitude.len() < text.len() {
+        return Err(ParsePrimantError::InvalidNumber);
+    }
+    let raw = parse_magnitude(magnitude, i32::MAX as u128, true, parts_of)? as i32;
+    Ok(Bipolar::from_raw(if negative { -raw } else { raw }))
+}
+
+/// Returns the number of parts in a whole for the suffix of a [`PartsUnit`].
+fn parts_of(suffix: &str) -> Option<u128> {
+    PartsUnit::from_suffix(suffix).map(|unit| unit.parts() as u128)
+}
+
+/// Parses a non-negative decimal number, optionally followed by a suffix known to
+/// `parts`, into the nearest raw value with `max` representing one whole, which is
+/// itself only in range if `inclusive`.
+///
+/// Digits beyond the 26th decimal place or significant digit are ignored, as they
+/// change the value by far less than one raw step.
+fn parse_magnitude(text: &str, max: u128, inclusive: bool, parts: impl Fn(&str) -> Option<u128>) -> Result<u128, ParsePrimantError> {
+    let number_length = text.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(text.len());
+    let (number, suffix) = text.split_at(number_length);
+    if number.is_empty() {
+        return Err(if text.is_empty() { ParsePrimantError::Empty } else { ParsePrimantError::InvalidNumber });
+    }
+
+    let parts = match suffix.trim_start() {
+        "" => 1,
+        suffix => parts(suffix).ok_or(ParsePrimantError::UnknownUnit)?,
+    };
+
+    // The digits that do not fit are only remembered as being non-zero, so that a
+    // value just above one whole is still out of range.
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if integer.is_empty() && fraction.is_empty() || fraction.contains('.') {
+        return Err(ParsePrimantError::InvalidNumber);
+    }
+    let mut mantissa = 0u128;
+    for digit in integer.bytes() {
+        if mantissa >= MANTISSA_LIMIT { return Err(ParsePrimantError::OutOfRange); }
+        mantissa = mantissa * 10 + (digit - b'0') as u128;
+    }
+    let (mut fraction_digits, mut inexact) = (0, false);
+    for digit in fraction.bytes() {
+        if mantissa < MANTISSA_LIMIT && fraction_digits < MAX_FRACTION_DIGITS {
+            mantissa = mantissa * 10 + (digit - b'0') as u128;
+            fraction_digits += 1;
+        } else {
+            inexact |= digit != b'0';
+        }
+    }
+
+    let denominator = 10u128.pow(fraction_digits) * parts;
+    if mantissa > denominator || mantissa == denominator && (inexact || !inclusive) {
+        return Err(ParsePrimantError::OutOfRange);
+    }
+    let scaled = mantissa * max;
+    let (quotient, remainder) = (scaled / denominator, scaled % denominator);
+    let up = 2 * remainder >= denominator;
+    Ok(quotient + up as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units() {
+        assert_eq!(parse_primant("25bps"), Ok(Primant::from_raw(10_737_418)));
+        assert_eq!(parse_primant("2.5‰"), parse_primant("25 bp"));
+        assert_eq!(parse_primant("150ppm"), parse_primant("0.000150"));
+        assert_eq!(parse_primant("100%"), Ok(Primant::MAX));
+        assert_eq!(parse_primant(" .5 "), Ok(Primant::from_raw(u32::MAX / 2 + 1)));
+        assert_eq!(parse_primant("0"), Ok(Primant::ZERO));
+    }
+
+    #[test]
+    fn test_rounding() {
+        // One raw step is 0.2328306437... ppb.
+        assert_eq!(parse_primant("0.11641532185ppb"), Ok(Primant::from_raw(0)));
+        assert_eq!(parse_primant("0.11641532186ppb"), Ok(Primant::from_raw(1)));
+        assert_eq!(parse_primant("1.0000000000000000000000000000001"), Err(ParsePrimantError::OutOfRange));
+        assert_eq!(parse_primant("1.0000000000000000000000000000000"), Ok(Primant::MAX));
+    }
+
+    #[test]
+    fn test_bipolar() {
+        assert_eq!(parse_bipolar("-100%"), Ok(Bipolar::from_raw(-i32::MAX)));
+        assert_eq!(parse_bipolar(" +0.5"), Ok(Bipolar::from_raw(i32::MAX / 2 + 1)));
+        assert_eq!(parse_bipolar("-0.5"), Ok(Bipolar::from_raw(-(i32::MAX / 2 + 1))));
+        assert_eq!(parse_bipolar("0"), Ok(Bipolar::ZERO));
+        assert_eq!(parse_bipolar("-"), Err(ParsePrimantError::InvalidNumber));
+        assert_eq!(parse_bipolar("- 1"), Err(ParsePrimantError::InvalidNumber));
+        assert_eq!(parse_bipolar("--1"), Err(ParsePrimantError::InvalidNumber));
+        assert_eq!(parse_bipolar(""), Err(ParsePrimantError::Empty));
+        assert_eq!(parse_bipolar("-1.5"), Err(ParsePrimantError::OutOfRange));
+    }
+
+    #[test]
+    fn test_phase() {
+        assert_eq!(parse_phase("0.25"), Ok(Phase::from_raw(1 << 30)));
+        assert_eq!(parse_phase("90°"), Ok(Phase::from_raw(1 << 30)));
+        assert_eq!(parse_phase(" 270.0 ° "), Ok(Phase::from_raw(3 << 30)));
+        assert_eq!(parse_phase("50%"), Ok(Phase::from_raw(1 << 31)));
+        assert_eq!(parse_phase("0"), Ok(Phase::MIN));
+        assert_eq!(parse_phase("0.99999999999"), Ok(Phase::MIN));
+        assert_eq!(parse_phase("1"), Err(ParsePrimantError::OutOfRange));
+        assert_eq!(parse_phase("360°"), Err(ParsePrimantError::OutOfRange));
+        assert_eq!(parse_phase("-90°"), Err(ParsePrimantError::InvalidNumber));
+        assert_eq!(parse_phase("90 rad"), Err(ParsePrimantError::UnknownUnit));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse_primant(" "), Err(ParsePrimantError::Empty));
+        assert_eq!(parse_primant("-1%"), Err(ParsePrimantError::InvalidNumber));
+        assert_eq!(parse_primant("."), Err(ParsePrimantError::InvalidNumber));
+        assert_eq!(parse_primant("1.2.3"), Err(ParsePrimantError::InvalidNumber));
+        assert_eq!(parse_primant("5 percent"), Err(ParsePrimantError::UnknownUnit));
+        assert_eq!(parse_primant("101%"), Err(ParsePrimantError::OutOfRange));
+        assert_eq!(parse_primant("99999999999999999999999999999999"), Err(ParsePrimantError::OutOfRange));
+    }
+}
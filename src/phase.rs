@@ -1,5 +1,12 @@
 use core::fmt::{Debug, Display};
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use core::time::Duration;
+#[cfg(feature = "float")]
 use num_traits::float::FloatCore;
+use crate::{format, hsv_to_rgb, parse, Bipolar, ParsePrimantError, PhaseCycle, Primant};
+#[cfg(feature = "std")]
+use std::time::Instant;
 
 /// A fraction between 0 and 1 (exclusive).
 ///
@@ -23,16 +30,17 @@ impl Phase {
     pub const MAX: Phase = Phase(u32::MAX);
 
     /// Creates a new [`Phase`] from a raw representation.
-    pub fn from_raw(value: u32) -> Self {
+    pub const fn from_raw(value: u32) -> Self {
         Phase(value)
     }
 
     /// Returns the raw representation of the [`Phase`].
-    pub fn to_raw(self) -> u32 {
+    pub const fn to_raw(self) -> u32 {
         self.0
     }
 }
 
+#[cfg(feature = "float")]
 impl TryFrom<f32> for Phase {
     type Error = ();
 
@@ -40,11 +48,12 @@ impl TryFrom<f32> for Phase {
         if !(0.0..1.0).contains(&value) {
             Err(())
         } else {
-            Ok(Phase((value * u32::MAX as f32) as u32))
+            Ok(Phase((value * 4_294_967_296.0) as u32))
         }
     }
 }
 
+#[cfg(feature = "float")]
 impl TryFrom<f64> for Phase {
     type Error = ();
 
@@ -52,24 +61,194 @@ impl TryFrom<f64> for Phase {
         if !(0.0..1.0).contains(&value) {
             Err(())
         } else {
-            Ok(Phase((value * u32::MAX as f64) as u32))
+            Ok(Phase((value * 4_294_967_296.0) as u32))
         }
     }
 }
 
+#[cfg(feature = "float")]
 impl From<Phase> for f32 {
     fn from(value: Phase) -> Self {
-        value.0 as f32 / u32::MAX as f32
+        // The last raw values round up to a full turn in single precision, so they
+        // are kept just below it.
+        (value.0 as f32 / 4_294_967_296.0).min(1.0 - f32::EPSILON / 2.0)
     }
 }
 
+#[cfg(feature = "float")]
 impl From<Phase> for f64 {
     fn from(value: Phase) -> Self {
-        value.0 as f64 / u32::MAX as f64
+        value.0 as f64 / 4_294_967_296.0
+    }
+}
+
+/// Wrapping functions.
+impl Phase {
+    /// Adds two phases, wrapping around the end of the cycle.
+    ///
+    /// Returns the sum along with a boolean indicating whether it wrapped.
+    pub fn overflowing_add(self, other: Phase) -> (Self, bool) {
+        let (value, wrapped) = self.0.overflowing_add(other.0);
+        (Phase(value), wrapped)
+    }
+
+    /// Adds two phases, wrapping around the end of the cycle.
+    ///
+    /// This is the same as the [`Add`] operator, but usable in `const` contexts.
+    pub const fn wrapping_add(self, other: Phase) -> Self {
+        Phase(self.0.wrapping_add(other.0))
+    }
+
+    /// Subtracts `other` from this phase, wrapping around the start of the cycle.
+    ///
+    /// This is the same as the [`Sub`] operator, but usable in `const` contexts.
+    pub const fn wrapping_sub(self, other: Phase) -> Self {
+        Phase(self.0.wrapping_sub(other.0))
+    }
+
+    /// Returns the phase reflected around the start of the cycle, so that adding
+    /// both yields [`Phase::MIN`], such as for reversing a rotation.
+    ///
+    /// This is the same as the [`Neg`] operator, but usable in `const` contexts.
+    pub const fn reflect(self) -> Self {
+        Phase(self.0.wrapping_neg())
+    }
+
+    /// Returns whether moving forwards from `previous` to this phase crossed the end
+    /// of the cycle.
+    ///
+    /// This assumes that the phase moves forwards by less than a full turn between
+    /// both observations, as is the case when sampling an oscillator every tick.
+    pub fn wrapped_from(self, previous: Phase) -> bool {
+        self < previous
+    }
+
+    /// Restricts this phase to the arc going forwards from `start` to `end`, both
+    /// inclusive, across the end of the cycle if needed.
+    ///
+    /// A phase outside the arc is moved to the closer of its ends, measured around
+    /// the cycle, with ties going to `start`. If `start` and `end` are equal, the arc
+    /// is a single point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Phase;
+    /// let (start, end) = (Phase::from_raw(0xC000_0000), Phase::from_raw(0x4000_0000));
+    /// assert_eq!(Phase::MIN.clamp_arc(start, end), Phase::MIN);
+    /// assert_eq!(Phase::from_raw(0x5000_0000).clamp_arc(start, end), end);
+    /// assert_eq!(Phase::from_raw(0xB000_0000).clamp_arc(start, end), start);
+    /// ```
+    pub fn clamp_arc(self, start: Phase, end: Phase) -> Self {
+        let length = end.0.wrapping_sub(start.0);
+        if self.0.wrapping_sub(start.0) <= length { return self; }
+        let (past_end, before_start) = (self.0.wrapping_sub(end.0), start.0.wrapping_sub(self.0));
+        if past_end < before_start { end } else { start }
+    }
+
+    /// Returns the shortest distance to `other` around the cycle, in either
+    /// direction, such as for comparing angles.
+    ///
+    /// The distance is at most half a turn, and is the same in both directions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Phase;
+    /// let distance = Phase::from_raw(0xF000_0000).cyclic_distance(Phase::from_raw(0x1000_0000));
+    /// assert_eq!(distance, Phase::from_raw(0x2000_0000));
+    /// ```
+    pub const fn cyclic_distance(self, other: Phase) -> Self {
+        Phase(self.distance_raw(other))
+    }
+
+    /// Returns whether this phase is within `tolerance` of `target`, inclusive,
+    /// measured around the cycle in either direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Phase;
+    /// let tolerance = Phase::from_raw(0x0100_0000);
+    /// assert!(Phase::from_raw(0xFF80_0000).within(Phase::MIN, tolerance));
+    /// assert!(!Phase::from_raw(0xFE00_0000).within(Phase::MIN, tolerance));
+    /// ```
+    pub const fn within(self, target: Phase, tolerance: Phase) -> bool {
+        self.distance_raw(target) <= tolerance.0
+    }
+}
+
+/// Selection functions over slices.
+impl Phase {
+    /// Returns the index of the phase in `phases` closest to `anchor`, measured
+    /// around the cycle in either direction, or `None` if it is empty.
+    ///
+    /// Ties are resolved towards the first of the equally close phases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Phase;
+    /// let phases = [Phase::from_raw(1 << 31), Phase::from_raw(0xF000_0000)];
+    /// // Across the end of the cycle, the second phase is the closest.
+    /// assert_eq!(Phase::closest_to(&phases, Phase::from_raw(0x1000_0000)), Some(1));
+    /// ```
+    pub const fn closest_to(phases: &[Phase], anchor: Phase) -> Option<usize> {
+        if phases.is_empty() { return None; }
+        let mut best = 0;
+        let mut i = 1;
+        while i < phases.len() {
+            if phases[i].distance_raw(anchor) < phases[best].distance_raw(anchor) { best = i; }
+            i += 1;
+        }
+        Some(best)
+    }
+
+    /// Returns the largest empty arc between the phases in `phases`, as its start
+    /// and its length, or `None` if there are no phases.
+    ///
+    /// The arc goes forwards from one phase to the next, around the cycle. The
+    /// length has the same representation as in [`Arc`](crate::Arc), so `Arc::new(start,
+    /// length)` is exactly the gap, and a single phase leaves a gap of
+    /// [`Primant::MAX`]. Ties are resolved towards the smallest start.
+    ///
+    /// The phases may be in any order, and are left untouched. Without sorting them,
+    /// this takes time quadratic in their number, which suits small sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{Arc, Phase};
+    /// let antennas = [Phase::from_raw(0xC000_0000), Phase::from_raw(0x1000_0000), Phase::from_raw(0x4000_0000)];
+    /// let (start, length) = Phase::largest_gap(&antennas).unwrap();
+    /// assert_eq!(start, Phase::from_raw(0x4000_0000));
+    /// assert_eq!(Arc::new(start, length).end(), Phase::from_raw(0xC000_0000));
+    /// ```
+    pub fn largest_gap(phases: &[Phase]) -> Option<(Phase, Primant)> {
+        let mut best: Option<(Phase, u64)> = None;
+        for &start in phases {
+            // The gap reaches the next distinct phase, or all the way around.
+            let next = phases.iter().map(|phase| phase.0.wrapping_sub(start.0)).filter(|&gap| gap != 0).min();
+            let gap = next.map_or(1 << 32, |gap| gap as u64);
+            if best.is_none_or(|(best_start, best_gap)| gap > best_gap || gap == best_gap && start < best_start) {
+                best = Some((start, gap));
+            }
+        }
+        best.map(|(start, gap)| (start, Primant::from_raw(gap.min(u32::MAX as u64) as u32)))
+    }
+
+    /// Returns the shortest distance to `other` around the cycle, in raw units.
+    const fn distance_raw(self, other: Phase) -> u32 {
+        let forward = self.0.wrapping_sub(other.0);
+        let backward = other.0.wrapping_sub(self.0);
+        if forward < backward { forward } else { backward }
     }
 }
 
 /// Generic conversion functions to and from floating-point numbers.
+///
+/// These functions are only available with the `float` feature.
+#[cfg(feature = "float")]
 impl Phase {
     /// Creates a new [`Phase`] from a floating-point value.
     /// 
@@ -78,7 +257,7 @@ impl Phase {
     /// Panics if the value is not in the range `0.0..1.0`.
     pub fn from_float<T: FloatCore>(value: T) -> Self {
         assert!(value >= T::zero() && value < T::one(), "value must be in the range 0.0..1.0");
-        Phase((value * T::from(u32::MAX).unwrap()).to_u32().unwrap())
+        Self::from_float_in_range(value)
     }
     
     /// Creates a new [`Phase`] from a floating-point value.
@@ -86,8 +265,7 @@ impl Phase {
     /// Returns `None` if the value is not in the range `0.0..1.0`.
     pub fn try_from_float<T: FloatCore>(value: T) -> Option<Self> {
         if value < T::zero() || value >= T::one() { return None; }
-        let value = (value * T::from(u32::MAX)?).to_u32()?;
-        Some(Phase(value))
+        Some(Self::from_float_in_range(value))
     }
     
     /// Creates a new [`Phase`] from a floating-point value.
@@ -95,18 +273,838 @@ impl Phase {
     /// If the value is not in the range `0.0..1.0`, it saturates to the closest
     /// representable value.
     pub fn from_float_saturating<T: FloatCore>(value: T) -> Self {
-        Phase((value.clamp(T::zero(), T::one()) * T::from(u32::MAX).unwrap()).to_u32().unwrap())
+        Self::from_float_in_range(value.clamp(T::zero(), T::one()))
+    }
+
+    /// Converts a floating-point value in the range `0.0..=1.0` to a [`Phase`].
+    ///
+    /// The scaled value is clamped, as a full turn lies past the last raw value.
+    fn from_float_in_range<T: FloatCore>(value: T) -> Self {
+        let value = (value * T::from(1u64 << 32).unwrap()).to_u64().unwrap();
+        Phase(value.min(u32::MAX as u64) as u32)
     }
 }
 
+/// Generic conversion functions to and from [`Float`](num_traits::Float) types.
+///
+/// These accept types implementing [`Float`](num_traits::Float) but not
+/// [`FloatCore`], such as wrapper or scalar types of other crates. They are only
+/// available with the `float` feature, along with either `std` or `libm`.
+#[cfg(all(feature = "float", any(feature = "std", feature = "libm")))]
+impl Phase {
+    /// Creates a new [`Phase`] from a floating-point value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not in the range `0.0..1.0`.
+    pub fn from_real<T: num_traits::Float>(value: T) -> Self {
+        Self::from_float(value.to_f64().unwrap())
+    }
+
+    /// Creates a new [`Phase`] from a floating-point value.
+    ///
+    /// Returns `None` if the value is not in the range `0.0..1.0`.
+    pub fn try_from_real<T: num_traits::Float>(value: T) -> Option<Self> {
+        Self::try_from_float(value.to_f64()?)
+    }
+
+    /// Creates a new [`Phase`] from a floating-point value.
+    ///
+    /// If the value is not in the range `0.0..1.0`, it saturates to the closest
+    /// representable value.
+    pub fn from_real_saturating<T: num_traits::Float>(value: T) -> Self {
+        Self::from_float_saturating(value.to_f64().unwrap())
+    }
+}
+
+/// Conversion functions from time.
+impl Phase {
+    /// Creates a new [`Phase`] representing the position of `now_ticks` within a
+    /// repeating period of `period_ticks`.
+    ///
+    /// The result is `(now_ticks mod period_ticks) / period_ticks`, computed exactly
+    /// using 128-bit arithmetic and rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period_ticks` is zero.
+    pub fn from_time(now_ticks: u64, period_ticks: u64) -> Self {
+        assert_ne!(period_ticks, 0, "period must not be zero");
+        Self::from_time_u128(now_ticks as u128, period_ticks as u128)
+    }
+
+    /// Creates a new [`Phase`] representing the position of `elapsed` within a
+    /// repeating `period`.
+    ///
+    /// The result is computed exactly from the nanosecond counts of both durations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn from_duration(elapsed: Duration, period: Duration) -> Self {
+        assert!(!period.is_zero(), "period must not be zero");
+        Self::from_time_u128(elapsed.as_nanos(), period.as_nanos())
+    }
+
+    /// Creates a new [`Phase`] representing the position of `now` within a
+    /// repeating `period` starting at `epoch`.
+    ///
+    /// If `now` is earlier than `epoch`, the result is [`Phase::MIN`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    #[cfg(feature = "std")]
+    pub fn from_instant(now: Instant, epoch: Instant, period: Duration) -> Self {
+        Self::from_duration(now.saturating_duration_since(epoch), period)
+    }
+
+    /// Returns the number of ticks it takes to advance from this phase to `target`,
+    /// given a period of `period_ticks`.
+    ///
+    /// The distance is measured forwards, wrapping around the end of the cycle, and
+    /// is rounded up so that the target phase has been reached once the returned
+    /// number of ticks has passed. If `target` equals this phase, returns zero.
+    ///
+    /// This is the complement of [`Phase::from_time`], useful for programming a timer
+    /// to fire at a phase-aligned event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period_ticks` is zero.
+    pub fn ticks_until(self, target: Phase, period_ticks: u64) -> u64 {
+        assert_ne!(period_ticks, 0, "period must not be zero");
+        let distance = target.0.wrapping_sub(self.0) as u128;
+        (distance * period_ticks as u128).div_ceil(1 << 32) as u64
+    }
+
+    /// Computes `(now mod period) / period`.
+    ///
+    /// The caller must ensure that `period` is non-zero and fits in 96 bits.
+    pub(crate) fn from_time_u128(now: u128, period: u128) -> Self {
+        Phase((((now % period) << 32) / period) as u32)
+    }
+}
+
+/// Iteration functions.
+impl Phase {
+    /// Returns an iterator over `count` equally spaced phases covering exactly one
+    /// turn, such as for plotting a waveform or walking around an LED ring.
+    ///
+    /// The first phase is [`Phase::MIN`], and it is not repeated at the end, so the
+    /// iterator can be repeated with [`Iterator::cycle`] into an evenly spaced
+    /// stream. The phases are exact when `count` is a power of two, and rounded down
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Phase;
+    /// let quarters: Vec<_> = Phase::cycle(4).map(Phase::to_raw).collect();
+    /// assert_eq!(quarters, [0, 0x4000_0000, 0x8000_0000, 0xC000_0000]);
+    /// ```
+    pub fn cycle(count: u32) -> PhaseCycle {
+        PhaseCycle::new(count)
+    }
+}
+
+/// Conversion functions from hardware counters, such as timers and encoders.
+impl Phase {
+    /// Creates a new [`Phase`] from a counter wrapping around at `modulus`, such as
+    /// the position of a rotary encoder with `modulus` counts per revolution.
+    ///
+    /// The result is `(count mod modulus) / modulus`. It is exact when `modulus` is
+    /// a power of two, and rounded down otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Phase;
+    /// assert_eq!(Phase::from_wrapping_counter(1024, 4096), Phase::from_raw(0x4000_0000));
+    /// assert_eq!(Phase::from_wrapping_counter(4097, 4096), Phase::from_wrapping_counter(1, 4096));
+    /// assert_eq!(Phase::from_wrapping_counter(1, 3), Phase::from_raw(u32::MAX / 3));
+    /// ```
+    pub const fn from_wrapping_counter(count: u32, modulus: u32) -> Self {
+        assert!(modulus != 0, "modulus must not be zero");
+        if modulus.is_power_of_two() {
+            // A full turn is 2^32, so a power of two only needs a shift.
+            let count = count & (modulus - 1);
+            Phase(((count as u64) << (32 - modulus.trailing_zeros())) as u32)
+        } else {
+            Phase(((((count % modulus) as u64) << 32) / modulus as u64) as u32)
+        }
+    }
+
+    /// Creates a new [`Phase`] from a free-running 16-bit counter, such as a timer
+    /// register, with a full turn every 65536 counts.
+    ///
+    /// This conversion is exact.
+    pub const fn from_counter_u16(count: u16) -> Self {
+        Phase((count as u32) << 16)
+    }
+}
+
+/// Trigonometric functions.
+///
+/// These are computed using integer arithmetic only, with an error below one part in
+/// a billion.
+impl Phase {
+    /// Returns the sine of the angle represented by this phase, with a full turn
+    /// corresponding to `2π`.
+    pub fn sin(self) -> Bipolar {
+        let quadrant = self.0 >> 30;
+        let position = self.0 & ((1 << 30) - 1);
+        let position = if quadrant & 1 == 0 { position } else { (1 << 30) - position };
+        let value = Self::quarter_sine(position);
+        Bipolar::from_raw(if quadrant < 2 { value } else { -value })
+    }
+
+    /// Returns the cosine of the angle represented by this phase, with a full turn
+    /// corresponding to `2π`.
+    pub fn cos(self) -> Bipolar {
+        Phase(self.0.wrapping_add(1 << 30)).sin()
+    }
+
+    /// Returns both the sine and the cosine of the angle represented by this phase,
+    /// such as for building a rotation.
+    pub fn sin_cos(self) -> (Bipolar, Bipolar) {
+        (self.sin(), self.cos())
+    }
+
+    /// Computes `sin(x · π/2)` for `x` given as a 30-bit fraction in `0..=1`, using the
+    /// Taylor series evaluated in 62-bit fixed-point arithmetic.
+    ///
+    /// The result is scaled to the raw representation of a [`Bipolar`].
+    fn quarter_sine(position: u32) -> i32 {
+        // (π/2)^(2k+1) / (2k+1)!, in 62-bit fixed point.
+        const COEFFICIENTS: [u64; 8] = [
+            7244019458077122842,
+            2978983596875621757,
+            367517370231208053,
+            21590780087563799,
+            739904368663792,
+            16596735030340,
+            262505142787,
+            3084311801,
+        ];
+        let multiply = |a: u64, b: u64| ((a as u128 * b as u128) >> 62) as u64;
+
+        let x = (position as u64) << 32;
+        let x_squared = multiply(x, x);
+        let mut sum = 0;
+        for &coefficient in COEFFICIENTS.iter().rev() {
+            sum = coefficient - multiply(x_squared, sum);
+        }
+        let value = multiply(x, sum);
+        ((value as u128 * i32::MAX as u128 + (1 << 61)) >> 62).min(i32::MAX as u128) as i32
+    }
+}
+
+/// Conversion functions to `glam` rotations.
+///
+/// These functions are only available with the `glam` feature.
+#[cfg(feature = "glam")]
+impl Phase {
+    /// Returns the 2D rotation matrix turning counterclockwise by this phase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use glam::Vec2;
+    /// # use unifrac::Phase;
+    /// let turned = Phase::from_raw(0x4000_0000).to_rotation2() * Vec2::X;
+    /// assert!(turned.abs_diff_eq(Vec2::Y, 1e-6));
+    /// ```
+    pub fn to_rotation2(self) -> ::glam::Mat2 {
+        let (sin, cos) = self.sin_cos();
+        let (sin, cos) = (f32::from(sin), f32::from(cos));
+        ::glam::Mat2::from_cols(::glam::Vec2::new(cos, sin), ::glam::Vec2::new(-sin, cos))
+    }
+}
+
+/// Circular statistics functions.
+///
+/// These are computed using integer arithmetic only.
+impl Phase {
+    /// Returns the mean direction of `phases`, along with the length of their mean
+    /// resultant vector, or `None` if there are no phases, such as for averaging
+    /// wind directions.
+    ///
+    /// The resultant length measures how concentrated the phases are: it is
+    /// [`Primant::MAX`] when they are all equal, and close to [`Primant::ZERO`]
+    /// when they are spread evenly around the cycle. When it is exactly zero, the
+    /// mean direction is undefined, and [`Phase::MIN`] is returned.
+    ///
+    /// The unit vectors of the phases are summed exactly, and the direction of the
+    /// sum is found to within a few raw steps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are `2^32` phases or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{Phase, Primant};
+    /// // Across the end of the cycle, the mean is not the arithmetic mean.
+    /// let directions = [Phase::from_raw(0xF000_0000), Phase::from_raw(0x1000_0000)];
+    /// let (mean, length) = Phase::circular_mean(directions).unwrap();
+    /// assert!(mean.within(Phase::MIN, Phase::from_raw(4)));
+    /// // The cosine of a sixteenth of a turn.
+    /// assert!(length.within(Primant::from_ratio(92_388u32, 100_000u32), Primant::from_ratio(1u32, 100_000u32)));
+    /// ```
+    pub fn circular_mean<I: IntoIterator<Item = Phase>>(phases: I) -> Option<(Phase, Primant)> {
+        let (mut count, mut sin, mut cos) = (0u64, 0i128, 0i128);
+        for phase in phases {
+            let (s, c) = phase.sin_cos();
+            sin += s.to_raw() as i128;
+            cos += c.to_raw() as i128;
+            count += 1;
+        }
+        if count == 0 { return None; }
+        assert!(count < 1 << 32, "there must be fewer than 2^32 phases");
+        let length = ((sin * sin + cos * cos) as u128).isqrt() as u64;
+        let length = Primant::ratio_of_saturating(length, count * i32::MAX as u64);
+        Some((Self::atan2_raw(sin, cos), length))
+    }
+
+    /// Returns the angle of the point `(x, y)`, with the origin at an angle of zero,
+    /// using CORDIC vectoring.
+    pub(crate) fn atan2_raw(y: i128, x: i128) -> Phase {
+        // atan(2^-i), in raw units with 16 more fractional bits.
+        const ANGLES: [i64; 40] = [
+            35184372088832, 20770547670515, 10974586953444, 5570871696862, 2796246208089,
+            1399486241028, 699913886760, 349978300884, 174991820497, 87496244017, 43748163730,
+            21874087080, 10937044192, 5468522177, 2734261099, 1367130551, 683565276, 341782638,
+            170891319, 85445659, 42722830, 21361415, 10680707, 5340354, 2670177, 1335088, 667544,
+            333772, 166886, 83443, 41722, 20861, 10430, 5215, 2608, 1304, 652, 326, 163, 81,
+        ];
+        if x == 0 && y == 0 { return Phase::MIN; }
+        // Turning by half a turn first leaves an angle within a quarter turn, which
+        // the rotations below can reach.
+        let (mut x, mut y, mut angle) = if x < 0 { (-x, -y, 1i64 << 47) } else { (x, y, 0) };
+        // The rotations grow the vector by about 1.65, so it is scaled to leave
+        // room for that, and to keep precision in the shifts.
+        let shift = x.unsigned_abs().max(y.unsigned_abs()).leading_zeros() - 4;
+        (x, y) = (x << shift, y << shift);
+        for (i, &step) in ANGLES.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if y > 0 {
+                (x, y, angle) = (x + dx, y - dy, angle + step);
+            } else {
+                (x, y, angle) = (x - dx, y + dy, angle - step);
+            }
+        }
+        Phase(((angle + (1 << 15)) >> 16) as u32)
+    }
+}
+
+/// Floating-point trigonometric functions.
+///
+/// These functions are only available with the `libm` feature, so they work without
+/// the platform math library.
+#[cfg(feature = "libm")]
+impl Phase {
+    /// Returns the angle of the point `(x, y)` from the positive `x` axis, as a
+    /// fraction of a full turn counterclockwise.
+    ///
+    /// The origin itself has an angle of zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` or `x` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Phase;
+    /// assert_eq!(Phase::from_atan2(1.0, 0.0), Phase::from_raw(0x4000_0000));
+    /// assert_eq!(Phase::from_atan2(-1.0, 0.0), Phase::from_raw(0xC000_0000));
+    /// ```
+    pub fn from_atan2(y: f64, x: f64) -> Self {
+        let turns = libm::atan2(y, x) / core::f64::consts::TAU;
+        assert!(!turns.is_nan(), "coordinates must not be NaN");
+        // Rounding to the nearest raw value may reach a full turn, which wraps to zero.
+        let raw = libm::round((turns - libm::floor(turns)) * 4_294_967_296.0) as u64;
+        Phase(raw as u32)
+    }
+}
+
+/// Random sampling functions.
+///
+/// These functions are only available with the `rand_core` and `libm` features.
+#[cfg(all(feature = "rand_core", feature = "libm"))]
+impl Phase {
+    /// Returns a random phase from a von Mises distribution, the circular analogue
+    /// of a normal distribution, clustered around `mean`, such as for simulating
+    /// jittered periodic events.
+    ///
+    /// A `concentration` of zero yields uniformly distributed phases, and higher
+    /// values cluster them more tightly: for large values, the distribution
+    /// approaches a normal distribution with a standard deviation of
+    /// `1 / sqrt(concentration)` radians.
+    ///
+    /// Samples are drawn with the rejection method of Best and Fisher, or from the
+    /// normal approximation for very large concentrations, where the method loses
+    /// precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `concentration` is negative or NaN.
+    pub fn von_mises<R: rand_core::Rng + ?Sized>(mean: Phase, concentration: f64, rng: &mut R) -> Self {
+        use core::f64::consts::{PI, TAU};
+        assert!(concentration >= 0.0, "concentration must not be negative or NaN");
+        if concentration < 1e-8 { return Phase(rng.next_u32()); }
+        let mut uniform = || (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+
+        let angle = if concentration > 1e6 {
+            // Box-Muller, with the first uniform moved away from zero.
+            let (u, v) = (1.0 - uniform(), uniform());
+            libm::sqrt(-2.0 * libm::log(u) / concentration) * libm::cos(TAU * v)
+        } else {
+            let s = 0.5 / concentration;
+            let r = s + libm::sqrt(1.0 + s * s);
+            let w = loop {
+                let (u, v) = (uniform(), uniform());
+                let z = libm::cos(PI * u);
+                let w = (1.0 + r * z) / (r + z);
+                let y = concentration * (r - w);
+                if y * (2.0 - y) - v >= 0.0 || libm::log(y / v) + 1.0 - y >= 0.0 { break w; }
+            };
+            let angle = libm::acos(w.clamp(-1.0, 1.0));
+            if uniform() < 0.5 { -angle } else { angle }
+        };
+        let offset = libm::round(angle / TAU * 4_294_967_296.0) as i64;
+        Phase(mean.0.wrapping_add(offset as u32))
+    }
+}
+
+/// Color functions.
+impl Phase {
+    /// Returns the fully saturated, full brightness color at this hue on the color
+    /// wheel, as 8-bit RGB.
+    ///
+    /// See [`hsv_to_rgb`] for details.
+    pub fn to_rgb_hue(self) -> [u8; 3] {
+        hsv_to_rgb(self, Primant::MAX, Primant::MAX)
+    }
+}
+
+/// Timing transformations.
+impl Phase {
+    /// Applies a swing feel to this phase.
+    ///
+    /// The cycle is split in two halves, and the midpoint is delayed depending on
+    /// `amount`: [`Primant::ZERO`] keeps it at `0.5` (straight timing), while
+    /// [`Primant::MAX`] moves it to `0.75`. A swing of two-thirds, corresponding to
+    /// triplet feel, is reached at an amount of `2/3`. Each half is then stretched or
+    /// compressed linearly, so the start and the end of the cycle stay fixed.
+    pub fn swing(self, amount: Primant) -> Self {
+        let (half, mid, full) = (1u64 << 31, Self::swing_midpoint(amount), 1u64 << 32);
+        let phase = self.0 as u64;
+        if phase < half {
+            Phase((phase * mid / half) as u32)
+        } else {
+            Phase((mid + (phase - half) * (full - mid) / (full - half)) as u32)
+        }
+    }
+
+    /// Reverts the swing feel applied by [`Phase::swing`] with the same `amount`.
+    ///
+    /// For phases in the first half of the cycle, which swinging stretches,
+    /// `phase.swing(amount).unswing(amount)` returns exactly `phase`. The second half
+    /// is compressed, so some neighbouring phases there are merged, and are restored
+    /// to within two raw steps of the original value.
+    pub fn unswing(self, amount: Primant) -> Self {
+        let (half, mid, full) = (1u64 << 31, Self::swing_midpoint(amount), 1u64 << 32);
+        let phase = self.0 as u64;
+        if phase < mid {
+            Phase((phase * half).div_ceil(mid) as u32)
+        } else {
+            Phase((half + ((phase - mid) * (full - half)).div_ceil(full - mid)) as u32)
+        }
+    }
+
+    /// Returns the raw position of the midpoint of the cycle after applying swing.
+    fn swing_midpoint(amount: Primant) -> u64 {
+        (1 << 31) + amount.scale_u128(1 << 30, false) as u64
+    }
+}
+
+/// Parsing functions.
+impl Phase {
+    /// Parses a decimal fraction of a turn, such as `"0.25"`, or a number of
+    /// degrees, such as `"90°"`, optionally followed by the suffix of a
+    /// [`PartsUnit`](crate::PartsUnit) instead, such as `"25%"`.
+    ///
+    /// Whitespace around the number and before the unit is ignored. The result is
+    /// the nearest representable value, with ties rounded up, computed exactly from
+    /// the first 26 decimal places. A value rounding up to a full turn wraps around
+    /// to [`Phase::MIN`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParsePrimantError`] if the string is not a valid non-negative
+    /// decimal with a known unit, or if the value is not less than one turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{ParsePrimantError, Phase};
+    /// assert_eq!(Phase::parse("90°"), Ok(Phase::from_raw(0x4000_0000)));
+    /// assert_eq!("0.75".parse(), Ok(Phase::from_raw(0xC000_0000)));
+    /// assert_eq!(Phase::parse("360°"), Err(ParsePrimantError::OutOfRange));
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, ParsePrimantError> {
+        parse::parse_phase(text)
+    }
+}
+
+/// Adds two phases, wrapping around the end of the cycle.
+impl Add for Phase {
+    type Output = Phase;
+
+    fn add(self, other: Phase) -> Self::Output {
+        self.wrapping_add(other)
+    }
+}
+
+/// Subtracts two phases, wrapping around the start of the cycle.
+impl Sub for Phase {
+    type Output = Phase;
+
+    fn sub(self, other: Phase) -> Self::Output {
+        self.wrapping_sub(other)
+    }
+}
+
+impl AddAssign for Phase {
+    fn add_assign(&mut self, other: Phase) {
+        *self = self.wrapping_add(other);
+    }
+}
+
+impl SubAssign for Phase {
+    fn sub_assign(&mut self, other: Phase) {
+        *self = self.wrapping_sub(other);
+    }
+}
+
+/// Reflects a [`Phase`] around the start of the cycle.
+impl Neg for Phase {
+    type Output = Phase;
+
+    fn neg(self) -> Self::Output {
+        self.reflect()
+    }
+}
+
+/// Prints the approximate value, or with the alternate flag (`{:#?}`), also the exact
+/// raw value.
 impl Debug for Phase {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "Phase({})", f32::from(*self))
+        f.write_str("Phase(")?;
+        #[cfg(feature = "float")]
+        if f.alternate() {
+            write!(f, "{}", f64::from(*self))?;
+        } else {
+            write!(f, "{}", f32::from(*self))?;
+        }
+        #[cfg(not(feature = "float"))]
+        format::write_ratio(f, self.0 as u128, 1 << 32, 1, 10, "", 0)?;
+        if f.alternate() {
+            write!(f, " ≈ raw {:#010x}", self.0)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Parses a [`Phase`] the same way as [`Phase::parse`].
+impl FromStr for Phase {
+    type Err = ParsePrimantError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse::parse_phase(text)
     }
 }
 
+/// Parses a [`Phase`] the same way as [`Phase::parse`], for generic conversion
+/// bounds such as `T: TryFrom<&str>`.
+impl TryFrom<&str> for Phase {
+    type Error = ParsePrimantError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        parse::parse_phase(text)
+    }
+}
+
+/// Prints the phase as a fraction of a turn, or with the alternate flag (`{:#}`), in
+/// degrees with one decimal place, unless another precision is given.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::Phase;
+/// let phase = Phase::from_raw(0x4000_0000);
+/// assert_eq!(format!("{phase}"), "0.2500");
+/// assert_eq!(format!("{phase:#}"), "90.0°");
+/// assert_eq!(format!("{phase:#.2}"), "90.00°");
+/// ```
 impl Display for Phase {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.4}", f32::from(*self))
+        if f.alternate() {
+            let precision = f.precision().unwrap_or(1).min(20) as u8;
+            format::write_ratio(f, self.0 as u128, 1 << 32, 360, precision, "°", 0)
+        } else {
+            format::write_ratio(f, self.0 as u128, 1 << 32, 1, 4, "", 0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn test_parse() {
+        extern crate alloc;
+        use alloc::format;
+        let phase = Phase::from_raw(0x2000_0000);
+        assert_eq!("45°".parse(), Ok(phase));
+        assert_eq!(Phase::try_from("0.125"), Ok(phase));
+        assert_eq!(Phase::parse(&format!("{phase:#}")), Ok(phase));
+        assert_eq!(Phase::try_from(""), Err(ParsePrimantError::Empty));
+    }
+
+    #[test]
+    fn test_from_time() {
+        assert_eq!(Phase::from_time(0, 1000), Phase::MIN);
+        assert_eq!(Phase::from_time(1000, 1000), Phase::MIN);
+        assert_eq!(Phase::from_time(1250, 1000), Phase::from_raw(1 << 30));
+        assert_eq!(Phase::from_time(u64::MAX, u64::MAX), Phase::MIN);
+        assert_eq!(Phase::from_time(u64::MAX - 1, u64::MAX), Phase::from_raw(u32::MAX));
+    }
+
+    #[test]
+    fn test_ticks_until() {
+        let quarter = Phase::from_raw(1 << 30);
+        assert_eq!(Phase::MIN.ticks_until(quarter, 1000), 250);
+        assert_eq!(quarter.ticks_until(Phase::MIN, 1000), 750);
+        assert_eq!(quarter.ticks_until(quarter, 1000), 0);
+        let now = 123_456_789;
+        let ticks = Phase::from_time(now, 1000).ticks_until(quarter, 1000);
+        assert!(Phase::from_time(now + ticks, 1000) >= quarter);
+    }
+
+    #[test]
+    fn test_swing() {
+        let amount = Primant::from_ratio(2u32, 3u32);
+        assert_eq!(Phase::MIN.swing(amount), Phase::MIN);
+        assert_eq!(Phase::from_raw(1 << 31).swing(Primant::ZERO), Phase::from_raw(1 << 31));
+        assert_eq!(Phase::from_raw(1 << 31).swing(Primant::MAX), Phase::from_raw(3 << 30));
+        for raw in (0..u32::MAX).step_by(9_999_991) {
+            let phase = Phase::from_raw(raw);
+            let restored = phase.swing(amount).unswing(amount);
+            if raw < 1 << 31 {
+                assert_eq!(restored, phase);
+            } else {
+                assert!(restored.to_raw().abs_diff(raw) <= 2);
+            }
+        }
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_sin_cos() {
+        assert_eq!(Phase::MIN.sin(), Bipolar::ZERO);
+        assert_eq!(Phase::from_raw(1 << 30).sin(), Bipolar::MAX);
+        assert_eq!(Phase::from_raw(1 << 31).sin(), Bipolar::ZERO);
+        assert_eq!(Phase::from_raw(3 << 30).sin(), Bipolar::MIN);
+        assert_eq!(Phase::MIN.cos(), Bipolar::MAX);
+        for raw in (0..u32::MAX).step_by(7_777_777) {
+            let angle = raw as f64 / (1u64 << 32) as f64 * core::f64::consts::TAU;
+            let sin = f64::from(Phase::from_raw(raw).sin());
+            let cos = f64::from(Phase::from_raw(raw).cos());
+            assert!((sin - angle.sin()).abs() < 1e-9);
+            assert!((cos - angle.cos()).abs() < 1e-9);
+        }
+    }
+
+    #[cfg(all(feature = "rand_core", feature = "libm"))]
+    #[test]
+    fn test_von_mises() {
+        struct Xorshift(crate::random::Xorshift32);
+        impl rand_core::TryRng for Xorshift {
+            type Error = core::convert::Infallible;
+            fn try_next_u32(&mut self) -> Result<u32, Self::Error> { Ok(self.0.next_u32()) }
+            fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+                Ok((self.0.next_u32() as u64) << 32 | self.0.next_u32() as u64)
+            }
+            fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+                dst.fill_with(|| self.0.next_u32() as u8);
+                Ok(())
+            }
+        }
+
+        let mut rng = Xorshift(crate::random::Xorshift32::new(12345));
+        let mean = Phase::from_raw(0xF000_0000);
+        // The expected resultant length is `I1(κ) / I0(κ)`.
+        for (concentration, expected) in [(0.0, 0.0), (0.5, 0.2425), (2.0, 0.6978), (50.0, 0.9899)] {
+            let samples = (0..20_000).map(|_| Phase::von_mises(mean, concentration, &mut rng));
+            let (direction, length) = Phase::circular_mean(samples).unwrap();
+            assert!((length.into_float::<f64>() - expected).abs() < 0.02, "{concentration}");
+            if concentration > 0.0 {
+                assert!(direction.within(mean, Phase::from_raw(0x0200_0000)), "{concentration}");
+            }
+        }
+        for _ in 0..100 {
+            assert!(Phase::von_mises(mean, 1e12, &mut rng).within(mean, Phase::from_raw(0x0000_1000)));
+        }
+    }
+
+    #[test]
+    fn test_circular_mean() {
+        assert_eq!(Phase::circular_mean([]), None);
+        for raw in (0..u32::MAX).step_by(3_333_333).chain([u32::MAX, 1 << 30, 1 << 31, 3 << 30]) {
+            let (mean, length) = Phase::circular_mean([Phase::from_raw(raw); 3]).unwrap();
+            assert!(mean.within(Phase::from_raw(raw), Phase::from_raw(2)), "{raw}");
+            assert!(length.within(Primant::MAX, Primant::from_raw(2)));
+        }
+        let opposite = [Phase::from_raw(0x1234_5678), Phase::from_raw(0x9234_5678)];
+        assert!(Phase::circular_mean(opposite).unwrap().1.within(Primant::ZERO, Primant::from_raw(2)));
+        assert_eq!(Phase::circular_mean([Phase::MIN, Phase::from_raw(1 << 31)]), Some((Phase::MIN, Primant::ZERO)));
+        let (mean, length) = Phase::circular_mean(Phase::cycle(5).chain([Phase::from_raw(1 << 30)])).unwrap();
+        assert!(mean.within(Phase::from_raw(1 << 30), Phase::from_raw(2)));
+        assert!(length.within(Primant::from_ratio(1u32, 6u32), Primant::from_raw(2)));
+    }
+
+    #[test]
+    fn test_from_duration() {
+        let period = Duration::from_millis(400);
+        assert_eq!(Phase::from_duration(Duration::from_millis(500), period), Phase::from_raw(1 << 30));
+        assert_eq!(Phase::from_duration(Duration::MAX, Duration::MAX), Phase::MIN);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_clamp_arc() {
+        let (start, end) = (Phase::from_raw(100), Phase::from_raw(200));
+        assert_eq!(Phase::from_raw(150).clamp_arc(start, end), Phase::from_raw(150));
+        assert_eq!(Phase::from_raw(250).clamp_arc(start, end), end);
+        assert_eq!(Phase::from_raw(50).clamp_arc(start, end), start);
+        // Halfway around the cycle from the arc, the tie goes to the start.
+        let opposite = Phase::from_raw(150u32.wrapping_add(1 << 31));
+        assert_eq!(opposite.clamp_arc(start, end), start);
+        assert_eq!(Phase::from_raw(7).clamp_arc(end, end), end);
+    }
+
+    #[test]
+    fn test_closest_to() {
+        let phases = [Phase::from_raw(10), Phase::from_raw(u32::MAX - 4), Phase::from_raw(u32::MAX - 4)];
+        assert_eq!(Phase::closest_to(&phases, Phase::MIN), Some(1));
+        assert_eq!(Phase::closest_to(&phases, Phase::from_raw(1 << 31)), Some(0));
+        assert_eq!(Phase::closest_to(&[], Phase::MIN), None);
+    }
+
+    #[test]
+    fn test_largest_gap() {
+        assert_eq!(Phase::largest_gap(&[]), None);
+        assert_eq!(Phase::largest_gap(&[Phase::from_raw(7)]), Some((Phase::from_raw(7), Primant::MAX)));
+        assert_eq!(Phase::largest_gap(&[Phase::from_raw(7); 3]), Some((Phase::from_raw(7), Primant::MAX)));
+        // The gap across the end of the cycle wins, and the phases keep their order.
+        let phases = [Phase::from_raw(0x9000_0000), Phase::from_raw(0x1000_0000), Phase::from_raw(0x5000_0000)];
+        assert_eq!(Phase::largest_gap(&phases), Some((Phase::from_raw(0x9000_0000), Primant::from_raw(0x8000_0000))));
+        assert_eq!(phases[0], Phase::from_raw(0x9000_0000));
+        // Equal gaps go to the smallest start.
+        let phases = [Phase::from_raw(3 << 30), Phase::from_raw(1 << 30), Phase::from_raw(2 << 30), Phase::MIN];
+        assert_eq!(Phase::largest_gap(&phases), Some((Phase::MIN, Primant::from_raw(1 << 30))));
+        // Duplicates leave no gap between them.
+        let phases = [Phase::from_raw(1 << 30), Phase::MIN, Phase::from_raw(1 << 30)];
+        assert_eq!(Phase::largest_gap(&phases), Some((Phase::from_raw(1 << 30), Primant::from_raw(3 << 30))));
+    }
+
+    #[test]
+    fn test_wrapping_arithmetic() {
+        let three_quarters = Phase::from_raw(3 << 30);
+        assert_eq!(three_quarters + three_quarters, Phase::from_raw(1 << 31));
+        assert_eq!(Phase::from_raw(1 << 30) - three_quarters, Phase::from_raw(1 << 31));
+        let mut phase = Phase::MAX;
+        phase += Phase::from_raw(1);
+        assert_eq!(phase, Phase::MIN);
+        phase -= Phase::from_raw(1);
+        assert_eq!(phase, Phase::MAX);
+        const SUM: Phase = Phase::MAX.wrapping_add(Phase::MAX).wrapping_sub(Phase::MAX);
+        assert_eq!(SUM, Phase::MAX);
+        assert_eq!(-Phase::from_raw(1 << 30), three_quarters);
+        assert_eq!(-Phase::from_raw(1 << 31), Phase::from_raw(1 << 31));
+        assert_eq!(Phase::MIN.reflect(), Phase::MIN);
+        assert_eq!(three_quarters + three_quarters.reflect(), Phase::MIN);
+    }
+
+    #[test]
+    fn test_within() {
+        let tolerance = Phase::from_raw(10);
+        assert!(Phase::from_raw(u32::MAX - 9).within(Phase::MIN, tolerance));
+        assert!(!Phase::from_raw(u32::MAX - 10).within(Phase::MIN, tolerance));
+        assert!(Phase::from_raw(5).within(Phase::from_raw(15), tolerance));
+        assert_eq!(Phase::from_raw(5).cyclic_distance(Phase::from_raw(15)), tolerance);
+        assert_eq!(Phase::MAX.cyclic_distance(Phase::from_raw(9)), tolerance);
+        assert_eq!(Phase::MIN.cyclic_distance(Phase::from_raw(1 << 31)), Phase::from_raw(1 << 31));
+        assert_eq!(Phase::from_raw(1 << 31).cyclic_distance(Phase::MIN), Phase::from_raw(1 << 31));
+        assert_eq!(Phase::from_raw(7).cyclic_distance(Phase::from_raw(7)), Phase::MIN);
+    }
+
+    #[test]
+    fn test_display_degrees() {
+        extern crate alloc;
+        use alloc::format;
+        assert_eq!(format!("{:#}", Phase::MIN), "0.0°");
+        assert_eq!(format!("{:#}", Phase::from_raw(0xC000_0000)), "270.0°");
+        assert_eq!(format!("{:#.0}", Phase::from_raw(0x2000_0000)), "45°");
+        #[cfg(feature = "float")]
+        assert_eq!(format!("{:#?}", Phase::MIN), "Phase(0 ≈ raw 0x00000000)");
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_float_round_trip() {
+        for raw in (0..u32::MAX).step_by(7_777_777).chain([1, u32::MAX - 1, u32::MAX]) {
+            let phase = Phase::from_raw(raw);
+            assert_eq!(Phase::from_float(f64::from(phase)), phase);
+            assert_eq!(Phase::try_from(f64::from(phase)), Ok(phase));
+            assert!(f32::from(phase) < 1.0);
+            assert!(Phase::from_float(f32::from(phase)).within(phase, Phase::from_raw(256)));
+        }
+        assert_eq!(Phase::from_float(0.25f64), Phase::from_raw(1 << 30));
+        assert_eq!(f64::from(Phase::from_raw(3 << 30)), 0.75);
+        assert_eq!(f32::from(Phase::MAX), 1.0 - f32::EPSILON / 2.0);
+        assert_eq!(Phase::from_float_saturating(1.0f32), Phase::MAX);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_from_atan2() {
+        assert_eq!(Phase::from_atan2(0.0, 0.0), Phase::MIN);
+        assert_eq!(Phase::from_atan2(0.0, -1.0), Phase::from_raw(0x8000_0000));
+        assert_eq!(Phase::from_atan2(-1e-300, 1.0), Phase::MIN);
+        let angle = Phase::from_raw(0x1234_5678);
+        let (sin, cos) = (f64::from(angle.sin()), f64::from(angle.cos()));
+        assert!(Phase::from_atan2(sin, cos).within(angle, Phase::from_raw(16)));
+    }
+
+    #[test]
+    fn test_from_wrapping_counter() {
+        assert_eq!(Phase::from_wrapping_counter(u32::MAX, 1), Phase::MIN);
+        assert_eq!(Phase::from_wrapping_counter(3, 1 << 31), Phase::from_raw(6));
+        assert_eq!(Phase::from_wrapping_counter(u32::MAX, 1 << 31), Phase::from_raw(u32::MAX - 1));
+        assert_eq!(Phase::from_wrapping_counter(u32::MAX - 1, u32::MAX), Phase::from_raw(u32::MAX - 1));
+        assert_eq!(Phase::from_wrapping_counter(u32::MAX, u32::MAX), Phase::MIN);
+        assert_eq!(Phase::from_wrapping_counter(5, 10), Phase::from_raw(1 << 31));
+        assert_eq!(Phase::from_counter_u16(0x4000), Phase::from_raw(0x4000_0000));
+        assert_eq!(Phase::from_counter_u16(u16::MAX), Phase::from_wrapping_counter(u16::MAX as u32, 1 << 16));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{Bipolar, Phase};
+
+/// A phase and frequency meter for a periodic input, measured from input capture
+/// timestamps, such as the sensing side of a phase-locked loop.
+///
+/// A [`PhaseMeter`] compares every captured edge of the input against a reference
+/// clock, ticking with a known nominal period from a reference timestamp. The phase
+/// of the reference at the edge tells how far the input lags it, and the time since
+/// the previous edge tells how fast the input runs compared to the nominal period.
+///
+/// Time is given as a monotonic tick count with every call, so the meter works with
+/// any clock.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Bipolar, Phase, PhaseMeter};
+/// // A nominal period of 1000 ticks, with the input running 1% fast.
+/// let mut meter = PhaseMeter::new(0, 1000);
+/// meter.capture(250);
+/// meter.capture(1240);
+/// assert_eq!(meter.phase(), Some(Phase::from_time(240, 1000)));
+/// assert_eq!(meter.period(), Some(990));
+/// let error = meter.frequency_error().unwrap();
+/// assert_eq!(error, Bipolar::from_raw((i32::MAX as i64 * 10 / 990) as i32));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhaseMeter {
+    reference_ticks: u64,
+    nominal_period: u64,
+    last_capture: Option<u64>,
+    phase: Option<Phase>,
+    period: Option<u64>,
+}
+
+impl PhaseMeter {
+    /// Creates a new [`PhaseMeter`] against a reference clock at [`Phase::MIN`] at
+    /// `reference_ticks`, with a period of `nominal_period` ticks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nominal_period` is zero.
+    pub fn new(reference_ticks: u64, nominal_period: u64) -> Self {
+        assert_ne!(nominal_period, 0, "period must not be zero");
+        PhaseMeter {
+            reference_ticks,
+            nominal_period,
+            last_capture: None,
+            phase: None,
+            period: None,
+        }
+    }
+
+    /// Returns the nominal period of the reference clock, in ticks.
+    pub fn nominal_period(&self) -> u64 {
+        self.nominal_period
+    }
+
+    /// Moves the reference clock to [`Phase::MIN`] at `reference_ticks`, keeping the
+    /// last measured period.
+    pub fn set_reference(&mut self, reference_ticks: u64) {
+        self.reference_ticks = reference_ticks;
+        self.phase = self.last_capture.map(|capture| self.reference_phase(capture));
+    }
+
+    /// Records an edge of the input captured at `capture_ticks`.
+    ///
+    /// A capture at the same tick as the previous one is ignored.
+    pub fn capture(&mut self, capture_ticks: u64) {
+        if let Some(last) = self.last_capture {
+            let period = capture_ticks.wrapping_sub(last);
+            if period == 0 { return; }
+            self.period = Some(period);
+        }
+        self.last_capture = Some(capture_ticks);
+        self.phase = Some(self.reference_phase(capture_ticks));
+    }
+
+    /// Returns the phase of the reference clock at the last captured edge, or `None`
+    /// before the first capture.
+    ///
+    /// This is how far the input lags the reference, or equivalently, the reference
+    /// leads the input.
+    pub fn phase(&self) -> Option<Phase> {
+        self.phase
+    }
+
+    /// Returns the time between the last two captured edges in ticks, or `None`
+    /// before the second capture.
+    pub fn period(&self) -> Option<u64> {
+        self.period
+    }
+
+    /// Returns the relative frequency error of the input, or `None` before the
+    /// second capture.
+    ///
+    /// The error is `(nominal_period - period) / period`, which is positive when the
+    /// input runs faster than nominal. It is rounded towards zero, and saturates at
+    /// [`Bipolar::MAX`] when the input runs at least twice as fast.
+    pub fn frequency_error(&self) -> Option<Bipolar> {
+        let period = self.period? as i128;
+        let error = (self.nominal_period as i128 - period) * i32::MAX as i128 / period;
+        Some(Bipolar::from_raw(error.clamp(-i32::MAX as i128, i32::MAX as i128) as i32))
+    }
+
+    /// Forgets every capture, keeping the reference clock.
+    pub fn reset(&mut self) {
+        *self = PhaseMeter::new(self.reference_ticks, self.nominal_period);
+    }
+
+    /// Returns the phase of the reference clock at `ticks`, which may be before the
+    /// reference timestamp.
+    fn reference_phase(&self, ticks: u64) -> Phase {
+        if ticks >= self.reference_ticks {
+            Phase::from_time(ticks - self.reference_ticks, self.nominal_period)
+        } else {
+            let before = self.reference_ticks - ticks;
+            Phase::MIN.wrapping_sub(Phase::from_time(before, self.nominal_period))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase() {
+        let mut meter = PhaseMeter::new(1000, 400);
+        assert_eq!(meter.phase(), None);
+        meter.capture(1100);
+        assert_eq!(meter.phase(), Some(Phase::from_raw(1 << 30)));
+        assert_eq!(meter.period(), None);
+        assert_eq!(meter.frequency_error(), None);
+        meter.capture(2600);
+        assert_eq!(meter.phase(), Some(Phase::MIN));
+
+        // Captures before the reference.
+        meter.set_reference(2800);
+        assert_eq!(meter.phase(), Some(Phase::from_raw(1 << 31)));
+        meter.capture(2700);
+        assert_eq!(meter.phase(), Some(Phase::from_raw(3 << 30)));
+
+        meter.reset();
+        assert_eq!(meter.phase(), None);
+        assert_eq!(meter.period(), None);
+    }
+
+    #[test]
+    fn test_frequency_error() {
+        let mut meter = PhaseMeter::new(0, 1000);
+        meter.capture(0);
+        meter.capture(1000);
+        assert_eq!(meter.frequency_error(), Some(Bipolar::ZERO));
+        meter.capture(3000);
+        assert_eq!(meter.frequency_error(), Some(Bipolar::from_raw(-i32::MAX / 2)));
+        meter.capture(3500);
+        assert_eq!(meter.frequency_error(), Some(Bipolar::MAX));
+        meter.capture(3501);
+        assert_eq!(meter.frequency_error(), Some(Bipolar::MAX));
+        meter.capture(3501);
+        assert_eq!(meter.period(), Some(1));
+
+        // Across the wrap of the tick count.
+        meter.capture(u64::MAX - 9);
+        meter.capture(990);
+        assert_eq!(meter.period(), Some(1000));
+    }
+}
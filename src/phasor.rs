@@ -0,0 +1,167 @@
+use core::ops::{Mul, MulAssign};
+use crate::{Bipolar, Phase, Primant};
+
+/// A complex number with both parts between -1 and 1, such as a point on the unit
+/// circle for rotating IQ samples.
+///
+/// Multiplying two [`Phasor`]s multiplies their magnitudes and adds their phases,
+/// so a signal can be mixed or rotated by a fixed angle every sample with a
+/// multiplication, rather than evaluating the sine and cosine again. Rounding makes
+/// the magnitude drift slowly over many multiplications, which
+/// [`Phasor::normalize`] corrects.
+///
+/// Computations use integer arithmetic only.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Phase, Phasor};
+/// // Rotating by an eighth of a turn, eight times.
+/// let step = Phasor::from_phase(Phase::from_raw(1 << 29));
+/// let mut phasor = Phasor::ONE;
+/// for _ in 0..8 {
+///     phasor *= step;
+/// }
+/// assert!(phasor.phase().within(Phase::MIN, Phase::from_raw(16)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Phasor {
+    cos: Bipolar,
+    sin: Bipolar,
+}
+
+impl Phasor {
+    /// The phasor of magnitude one at [`Phase::MIN`], which is the identity of
+    /// multiplication.
+    pub const ONE: Phasor = Phasor::new(Bipolar::MAX, Bipolar::ZERO);
+
+    /// Creates a new [`Phasor`] from its real part `cos` and its imaginary part `sin`.
+    pub const fn new(cos: Bipolar, sin: Bipolar) -> Self {
+        Phasor { cos, sin }
+    }
+
+    /// Creates a new [`Phasor`] of magnitude one at `phase`.
+    pub fn from_phase(phase: Phase) -> Self {
+        let (sin, cos) = phase.sin_cos();
+        Phasor { cos, sin }
+    }
+
+    /// Returns the real part, which is the cosine of the phase for a phasor of
+    /// magnitude one.
+    pub const fn cos(&self) -> Bipolar {
+        self.cos
+    }
+
+    /// Returns the imaginary part, which is the sine of the phase for a phasor of
+    /// magnitude one.
+    pub const fn sin(&self) -> Bipolar {
+        self.sin
+    }
+
+    /// Returns the phase of the phasor, to within a few raw steps.
+    ///
+    /// The phase of a phasor of magnitude zero is [`Phase::MIN`].
+    pub fn phase(&self) -> Phase {
+        Phase::atan2_raw(self.sin.to_raw() as i128, self.cos.to_raw() as i128)
+    }
+
+    /// Returns the magnitude of the phasor, saturating at [`Primant::MAX`], as a
+    /// phasor may reach beyond the unit circle.
+    pub fn magnitude(&self) -> Primant {
+        Primant::ratio_of_saturating(self.magnitude_raw(), i32::MAX as u64)
+    }
+
+    /// Returns the complex conjugate of the phasor, with the opposite phase.
+    pub const fn conjugate(&self) -> Self {
+        Phasor { cos: self.cos, sin: Bipolar::from_raw(-self.sin.to_raw()) }
+    }
+
+    /// Scales the phasor to a magnitude of one, keeping its phase, such as after
+    /// many multiplications.
+    ///
+    /// A phasor of magnitude zero is returned unchanged.
+    pub fn normalize(&self) -> Self {
+        let magnitude = self.magnitude_raw() as i64;
+        if magnitude == 0 { return *self; }
+        let scale = |part: Bipolar| {
+            let scaled = part.to_raw() as i64 * i32::MAX as i64;
+            Bipolar::from_raw(((scaled + scaled.signum() * magnitude / 2) / magnitude) as i32)
+        };
+        Phasor { cos: scale(self.cos), sin: scale(self.sin) }
+    }
+
+    /// Returns the magnitude in raw units, rounded down.
+    fn magnitude_raw(&self) -> u64 {
+        let (cos, sin) = (self.cos.to_raw() as i64, self.sin.to_raw() as i64);
+        ((cos * cos) as u64 + (sin * sin) as u64).isqrt()
+    }
+}
+
+impl From<Phase> for Phasor {
+    fn from(phase: Phase) -> Self {
+        Phasor::from_phase(phase)
+    }
+}
+
+/// Multiplies two [`Phasor`]s as complex numbers, adding their phases.
+///
+/// The parts are rounded to the nearest value, and saturate at both ends.
+impl Mul for Phasor {
+    type Output = Phasor;
+
+    fn mul(self, other: Phasor) -> Self::Output {
+        let (a, b) = (self.cos.to_raw() as i64, self.sin.to_raw() as i64);
+        let (c, d) = (other.cos.to_raw() as i64, other.sin.to_raw() as i64);
+        Phasor { cos: part(a * c - b * d), sin: part(a * d + b * c) }
+    }
+}
+
+impl MulAssign for Phasor {
+    fn mul_assign(&mut self, other: Phasor) {
+        *self = *self * other;
+    }
+}
+
+/// Converts a product of two raw parts back to a part, rounding to the nearest
+/// value and saturating.
+fn part(product: i64) -> Bipolar {
+    let max = i32::MAX as i64;
+    let rounded = (product + product.signum() * (max / 2)) / max;
+    Bipolar::from_raw(rounded.clamp(-max, max) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiplication() {
+        let mut seed = 13u32;
+        for _ in 0..1000 {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let (a, b) = (Phase::from_raw(seed), Phase::from_raw(seed.rotate_left(16)));
+            let product = Phasor::from_phase(a) * Phasor::from_phase(b);
+            let expected = Phasor::from_phase(a + b);
+            assert!(product.cos().to_raw().abs_diff(expected.cos().to_raw()) <= 2);
+            assert!(product.sin().to_raw().abs_diff(expected.sin().to_raw()) <= 2);
+            assert!(product.phase().within(a + b, Phase::from_raw(8)));
+        }
+        let half = Phasor::from_phase(Phase::from_raw(1 << 31));
+        assert_eq!(half * half, Phasor::ONE);
+        assert_eq!(Phasor::ONE * Phasor::ONE, Phasor::ONE);
+        let corner = Phasor::new(Bipolar::MAX, Bipolar::MAX);
+        assert_eq!(corner * corner, Phasor::new(Bipolar::ZERO, Bipolar::MAX));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let small = Phasor::new(Bipolar::from_raw(3_000_000), Bipolar::from_raw(-4_000_000));
+        let normalized = small.normalize();
+        assert_eq!(normalized.magnitude(), Primant::MAX);
+        assert_eq!(normalized.cos(), Bipolar::from_raw((i32::MAX as i64 * 3 / 5) as i32));
+        assert!(normalized.phase().within(small.phase(), Phase::from_raw(8)));
+        assert_eq!(Phasor::new(Bipolar::ZERO, Bipolar::ZERO).normalize().magnitude(), Primant::ZERO);
+        assert_eq!(small.conjugate().sin(), Bipolar::from_raw(4_000_000));
+        assert_eq!(Phasor::new(Bipolar::MAX, Bipolar::MAX).magnitude(), Primant::MAX);
+    }
+}
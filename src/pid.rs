@@ -0,0 +1,132 @@
+use num_traits::float::FloatCore;
+use crate::{Bipolar, Primant};
+
+/// A proportional–integral–derivative controller with a bounded output.
+///
+/// The setpoint and the measurement are given as [`Primant`]s, and the output is
+/// either a [`Primant`], such as the duty cycle of a heater or a fan, or a
+/// [`Bipolar`], such as the drive of a motor in both directions.
+///
+/// The gains are expressed per call of the update method, so they must be scaled by
+/// the control period if it changes. The derivative term is computed from the
+/// measurement rather than the error, so changing the setpoint does not cause a
+/// spike in the output.
+///
+/// # Anti-windup
+///
+/// When the output saturates at one of its bounds, the integral term stops
+/// accumulating in the direction of the saturation, and it is always kept within
+/// the output bounds. This keeps the controller from overshooting after a long
+/// period of saturation.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Pid, Primant};
+/// let mut pid = Pid::new(2.0f32, 0.1, 0.0);
+/// let setpoint = Primant::from_ratio(1u32, 2u32);
+/// let mut temperature = Primant::ZERO;
+/// for _ in 0..100 {
+///     let duty = pid.update(setpoint, temperature);
+///     let heat = temperature.into_float::<f32>() + 0.1 * (duty.into_float::<f32>() - 0.5);
+///     temperature = Primant::from_float_saturating(heat);
+/// }
+/// assert!((temperature.into_float::<f32>() - 0.5).abs() < 0.01);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pid<T> {
+    kp: T,
+    ki: T,
+    kd: T,
+    integral: T,
+    previous: Option<T>,
+}
+
+impl<T: FloatCore> Pid<T> {
+    /// Creates a new [`Pid`] controller with the given gains.
+    pub fn new(kp: T, ki: T, kd: T) -> Self {
+        Pid {
+            kp,
+            ki,
+            kd,
+            integral: T::zero(),
+            previous: None,
+        }
+    }
+
+    /// Returns the proportional, integral, and derivative gains.
+    pub fn gains(&self) -> (T, T, T) {
+        (self.kp, self.ki, self.kd)
+    }
+
+    /// Changes the gains, keeping the accumulated state.
+    pub fn set_gains(&mut self, kp: T, ki: T, kd: T) {
+        self.kp = kp;
+        self.ki = ki;
+        self.kd = kd;
+    }
+
+    /// Clears the integral term and the previous measurement.
+    pub fn reset(&mut self) {
+        self.integral = T::zero();
+        self.previous = None;
+    }
+
+    /// Updates the controller, returning an output between `0.0` and `1.0`.
+    pub fn update(&mut self, setpoint: Primant, measurement: Primant) -> Primant {
+        let output = self.step(setpoint, measurement, T::zero(), T::one());
+        Primant::from_float_saturating(output)
+    }
+
+    /// Updates the controller, returning an output between `-1.0` and `1.0`.
+    pub fn update_bipolar(&mut self, setpoint: Primant, measurement: Primant) -> Bipolar {
+        let output = self.step(setpoint, measurement, -T::one(), T::one());
+        Bipolar::from_float_saturating(output)
+    }
+
+    /// Computes the output, bounded between `low` and `high`, and updates the state.
+    fn step(&mut self, setpoint: Primant, measurement: Primant, low: T, high: T) -> T {
+        let measurement = measurement.into_float::<T>();
+        let error = setpoint.into_float::<T>() - measurement;
+
+        let proportional = self.kp * error;
+        let derivative = match self.previous {
+            Some(previous) => -self.kd * (measurement - previous),
+            None => T::zero(),
+        };
+        self.previous = Some(measurement);
+
+        let integral = (self.integral + self.ki * error).clamp(low, high);
+        let output = proportional + integral + derivative;
+        let saturated_high = output > high && integral > self.integral;
+        let saturated_low = output < low && integral < self.integral;
+        if !saturated_high && !saturated_low {
+            self.integral = integral;
+        }
+
+        (proportional + self.integral + derivative).clamp(low, high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anti_windup() {
+        let mut pid = Pid::new(1.0f64, 0.5, 0.0);
+        for _ in 0..1000 {
+            assert_eq!(pid.update(Primant::MAX, Primant::ZERO), Primant::MAX);
+        }
+        // The integral did not wind up, so the output reacts immediately.
+        assert_eq!(pid.update(Primant::ZERO, Primant::MAX), Primant::ZERO);
+    }
+
+    #[test]
+    fn test_bipolar() {
+        let mut pid = Pid::new(1.0f32, 0.0, 0.0);
+        assert_eq!(pid.update_bipolar(Primant::ZERO, Primant::MAX), Bipolar::MIN);
+        let half = Primant::from_ratio(1u32, 2u32);
+        assert!((pid.update_bipolar(half, Primant::ZERO).into_float::<f32>() - 0.5).abs() < 1e-6);
+    }
+}
@@ -1,6 +1,15 @@
 use core::fmt::{Debug, Display};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Not, Sub, SubAssign};
+use core::str::FromStr;
+use core::time::Duration;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "float")]
 use num_traits::float::FloatCore;
-use num_traits::{PrimInt, ToPrimitive};
+use crate::{lerp, parse, shaping, FracIndex, Integer, Lerp, ParsePrimantError, PercentFormat, PrimantRange, Rounding, SweepInclusive};
+#[cfg(feature = "libm")]
+use crate::beta;
 
 /// A fraction between 0 and 1 (inclusive).
 ///
@@ -34,16 +43,17 @@ impl Primant {
     pub const MAX: Primant = Primant(u32::MAX);
 
     /// Creates a new [`Primant`] from a raw representation.
-    pub fn from_raw(value: u32) -> Self {
+    pub const fn from_raw(value: u32) -> Self {
         Primant(value)
     }
 
     /// Returns the raw representation of the [`Primant`].
-    pub fn to_raw(self) -> u32 {
+    pub const fn to_raw(self) -> u32 {
         self.0
     }
 }
 
+#[cfg(feature = "float")]
 impl TryFrom<f32> for Primant {
     type Error = ();
 
@@ -56,6 +66,7 @@ impl TryFrom<f32> for Primant {
     }
 }
 
+#[cfg(feature = "float")]
 impl TryFrom<f64> for Primant {
     type Error = ();
 
@@ -68,93 +79,1232 @@ impl TryFrom<f64> for Primant {
     }
 }
 
+#[cfg(feature = "float")]
 impl From<Primant> for f32 {
     fn from(value: Primant) -> Self {
-        value.0 as f32 / u32::MAX as f32
+        // `u32::MAX` rounds to `2^32` in single precision, so dividing by it is an
+        // exact scaling.
+        value.0 as f32 * (1.0 / 4_294_967_296.0)
     }
 }
 
+/// Converts without any floating-point operation, which is slow on cores without a
+/// floating-point unit, but with the same result as dividing by `u32::MAX`.
+#[cfg(feature = "float")]
 impl From<Primant> for f64 {
     fn from(value: Primant) -> Self {
-        value.0 as f64 / u32::MAX as f64
+        if value.0 == 0 { return 0.0; }
+        // The raw value repeated twice is the quotient scaled by `2^64`, less a part
+        // below one, so rounding it half up to 53 bits rounds the quotient correctly.
+        let repeated = (value.0 as u64 * 0x1_0000_0001) << value.0.leading_zeros();
+        let mantissa = (repeated >> 11) + ((repeated >> 10) & 1);
+        // The leading bit of the mantissa, or a carry out of it, adds one to the
+        // exponent.
+        f64::from_bits(((1021 - value.0.leading_zeros() as u64) << 52) + mantissa)
     }
 }
 
 /// Generic conversion functions to and from floating-point numbers.
+///
+/// These functions are only available with the `float` feature.
+#[cfg(feature = "float")]
+impl Primant {
+    /// Creates a new [`Primant`] from a floating-point value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not in the range `0.0..=1.0`.
+    pub fn from_float<T: FloatCore>(value: T) -> Self {
+        assert!(value >= T::zero() && value <= T::one(), "value must be in the range 0.0..=1.0");
+        Self::from_float_in_range(value)
+    }
+
+    /// Creates a new [`Primant`] from a floating-point value.
+    ///
+    /// Returns `None` if the value is not in the range `0.0..=1.0`.
+    pub fn try_from_float<T: FloatCore>(value: T) -> Option<Self> {
+        if value < T::zero() || value > T::one() { return None; }
+        Some(Self::from_float_in_range(value))
+    }
+
+    /// Creates a new [`Primant`] from a floating-point value.
+    ///
+    /// If the value is not in the range `0.0..=1.0`, it saturates to the closest
+    /// representable value.
+    pub fn from_float_saturating<T: FloatCore>(value: T) -> Self {
+        Self::from_float_in_range(value.clamp(T::zero(), T::one()))
+    }
+
+    /// Returns the value as a floating-point number.
+    ///
+    /// The value is converted through `f64`, so it is rounded correctly in types of
+    /// up to double precision.
+    pub fn into_float<T: FloatCore>(self) -> T {
+        T::from(f64::from(self)).unwrap()
+    }
+
+    /// Converts a floating-point value in the range `0.0..=1.0` to a [`Primant`].
+    ///
+    /// The scaled value is clamped, as `u32::MAX` is not exactly representable in
+    /// single precision.
+    fn from_float_in_range<T: FloatCore>(value: T) -> Self {
+        let value = (value * T::from(u32::MAX).unwrap()).to_u64().unwrap();
+        Primant(value.min(u32::MAX as u64) as u32)
+    }
+}
+
+/// Generic conversion functions to and from [`Float`](num_traits::Float) types.
+///
+/// These accept types implementing [`Float`](num_traits::Float) but not
+/// [`FloatCore`], such as wrapper or scalar types of other crates. They are only
+/// available with the `float` feature, along with either `std` or `libm`.
+#[cfg(all(feature = "float", any(feature = "std", feature = "libm")))]
+impl Primant {
+    /// Creates a new [`Primant`] from a floating-point value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not in the range `0.0..=1.0`.
+    pub fn from_real<T: num_traits::Float>(value: T) -> Self {
+        Self::from_float(value.to_f64().unwrap())
+    }
+
+    /// Creates a new [`Primant`] from a floating-point value.
+    ///
+    /// Returns `None` if the value is not in the range `0.0..=1.0`.
+    pub fn try_from_real<T: num_traits::Float>(value: T) -> Option<Self> {
+        Self::try_from_float(value.to_f64()?)
+    }
+
+    /// Creates a new [`Primant`] from a floating-point value.
+    ///
+    /// If the value is not in the range `0.0..=1.0`, it saturates to the closest
+    /// representable value.
+    pub fn from_real_saturating<T: num_traits::Float>(value: T) -> Self {
+        Self::from_float_saturating(value.to_f64().unwrap())
+    }
+
+    /// Returns the value as a floating-point number.
+    pub fn into_real<T: num_traits::Float>(self) -> T {
+        T::from(self.into_float::<f64>()).unwrap()
+    }
+}
+
+/// Conversion functions to and from integer ratios.
+impl Primant {
+    /// Creates a new [`Primant`] from a numerator and a denominator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero or if the result would not fit in a `Primant`.
+    pub fn from_ratio<T: Integer>(numerator: T, denominator: T) -> Self {
+        assert!(!Self::is_zero(denominator), "denominator must not be zero");
+        assert!(numerator <= denominator, "numerator must not be greater than the denominator");
+        Self::ratio_of(Self::to_u64(numerator), Self::to_u64(denominator))
+    }
+
+    /// Creates a new [`Primant`] from a numerator and a denominator.
+    ///
+    /// Returns `None` if the denominator is zero or if the result would not fit in a `Primant`.
+    pub fn try_from_ratio<T: Integer>(numerator: T, denominator: T) -> Option<Self> {
+        if Self::is_zero(denominator) || numerator > denominator { return None; }
+        Some(Self::from_ratio(numerator, denominator))
+    }
+
+    /// Creates a new [`Primant`] from a numerator and a denominator.
+    ///
+    /// If the result would not fit in a `Primant`, it saturates to the closest representable
+    /// value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero.
+    pub fn from_ratio_saturating<T: Integer>(numerator: T, denominator: T) -> Self {
+        assert!(!Self::is_zero(denominator), "denominator must not be zero");
+        if numerator > denominator { return Primant::MAX; }
+        Self::from_ratio(numerator, denominator)
+    }
+
+    /// Creates a new [`Primant`] representing `part / whole`.
+    ///
+    /// Unlike [`Primant::from_ratio`], this accepts full 64-bit counters (such as
+    /// transferred bytes or elapsed cycles) and computes the result exactly using
+    /// 128-bit intermediate arithmetic. It can also be used in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `whole` is zero or if `part` is greater than `whole`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// const THIRD: Primant = Primant::ratio_of(1, 3);
+    /// assert_eq!(THIRD, Primant::from_raw(u32::MAX / 3));
+    /// ```
+    pub const fn ratio_of(part: u64, whole: u64) -> Self {
+        assert!(whole != 0, "whole must not be zero");
+        assert!(part <= whole, "part must not be greater than the whole");
+        Primant(Self::ratio_raw(part, whole))
+    }
+
+    /// Creates a new [`Primant`] representing `part / whole`.
+    ///
+    /// Returns `None` if `whole` is zero or if `part` is greater than `whole`.
+    pub const fn try_ratio_of(part: u64, whole: u64) -> Option<Self> {
+        if whole == 0 || part > whole { return None; }
+        Some(Primant(Self::ratio_raw(part, whole)))
+    }
+
+    /// Creates a new [`Primant`] representing `part / whole`.
+    ///
+    /// If `part` is greater than `whole`, it saturates to [`Primant::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `whole` is zero.
+    pub const fn ratio_of_saturating(part: u64, whole: u64) -> Self {
+        assert!(whole != 0, "whole must not be zero");
+        if part > whole { return Primant::MAX; }
+        Primant(Self::ratio_raw(part, whole))
+    }
+
+    /// Returns whether an integer is zero.
+    fn is_zero<T: Integer>(value: T) -> bool {
+        value.to_sign_magnitude().1 == 0
+    }
+
+    /// Converts a non-negative integer to a `u64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is negative or does not fit in a `u64`.
+    fn to_u64<T: Integer>(value: T) -> u64 {
+        let (negative, magnitude) = value.to_sign_magnitude();
+        assert!(!negative, "value must not be negative");
+        u64::try_from(magnitude).expect("value must fit in 64 bits")
+    }
+
+    /// Computes the raw value of `part / whole`, rounded down.
+    ///
+    /// The caller must ensure that `whole` is non-zero and `part <= whole`, so the
+    /// result fits and nothing overflows.
+    #[inline]
+    const fn ratio_raw(part: u64, whole: u64) -> u32 {
+        if whole <= u32::MAX as u64 {
+            // Both fit in 32 bits, so their product fits in 64 bits, which avoids
+            // the slower 128-bit division.
+            (part * u32::MAX as u64 / whole) as u32
+        } else {
+            (part as u128 * u32::MAX as u128 / whole as u128) as u32
+        }
+    }
+}
+
+/// Arithmetic functions.
+impl Primant {
+    /// Adds two [`Primant`]s, saturating at [`Primant::MAX`].
+    ///
+    /// This is the same as the [`Add`] operator, but usable in `const` contexts.
+    pub const fn saturating_add(self, other: Primant) -> Self {
+        Primant(self.0.saturating_add(other.0))
+    }
+
+    /// Subtracts `other` from this [`Primant`], saturating at [`Primant::ZERO`].
+    ///
+    /// This is the same as the [`Sub`] operator, but usable in `const` contexts.
+    pub const fn saturating_sub(self, other: Primant) -> Self {
+        Primant(self.0.saturating_sub(other.0))
+    }
+
+    /// Adds two [`Primant`]s, returning `None` if the sum is greater than one.
+    pub const fn checked_add(self, other: Primant) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(sum) => Some(Primant(sum)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `other` from this [`Primant`], returning `None` if the difference
+    /// is negative.
+    pub const fn checked_sub(self, other: Primant) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(difference) => Some(Primant(difference)),
+            None => None,
+        }
+    }
+
+    /// Returns the complement of this [`Primant`], `1 - self`, such as the
+    /// probability of the opposite event or the remaining opacity.
+    ///
+    /// The complement is exact, so applying it twice yields the original value.
+    /// This is the same as the [`Not`] operator, but usable in `const` contexts.
+    pub const fn complement(self) -> Self {
+        Primant(u32::MAX - self.0)
+    }
+
+    /// Multiplies two [`Primant`]s, rounding to the nearest value.
+    ///
+    /// The product of two fractions never leaves the unit interval, so this always
+    /// returns `Some`. It is provided for symmetry with [`Primant::checked_add`]
+    /// and [`Primant::checked_sub`], such as in generic code.
+    pub const fn checked_mul(self, other: Primant) -> Option<Self> {
+        Some(self.mul_round(other))
+    }
+
+    /// Multiplies two [`Primant`]s with a widening multiply, rounding to the nearest
+    /// value (with ties rounded up).
+    ///
+    /// This is the same as the [`Mul`] operator, but usable in `const` contexts.
+    /// The product is computed exactly before rounding, so [`Primant::MAX`] is the
+    /// identity and [`Primant::ZERO`] always yields zero.
+    pub const fn mul_round(self, other: Primant) -> Self {
+        let max = u32::MAX as u64;
+        Primant(((self.0 as u64 * other.0 as u64 + max / 2) / max) as u32)
+    }
+
+    /// Returns the mean of two [`Primant`]s, rounded down, without overflowing.
+    pub const fn midpoint(self, other: Primant) -> Self {
+        Primant(((self.0 as u64 + other.0 as u64) / 2) as u32)
+    }
+
+    /// Returns the mean of this [`Primant`] and `other`, weighting `other` by
+    /// `weight` and this one by `1 - weight`, rounded to the nearest value.
+    ///
+    /// This is the same as [`Lerp::lerp`], but usable in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let (low, high) = (Primant::from_raw(100), Primant::from_raw(200));
+    /// assert_eq!(low.weighted_mean(high, Primant::ratio_of(1, 4)), Primant::from_raw(125));
+    /// assert_eq!(low.weighted_mean(high, Primant::MAX), high);
+    /// ```
+    pub const fn weighted_mean(self, other: Primant, weight: Primant) -> Self {
+        let max = u32::MAX as u64;
+        let sum = self.0 as u64 * (max - weight.0 as u64) + other.0 as u64 * weight.0 as u64;
+        Primant(((sum + max / 2) / max) as u32)
+    }
+
+    /// Returns the arithmetic mean of `values`, rounded down, or `None` if there are
+    /// no values.
+    ///
+    /// The values are summed exactly, so any number of them can be averaged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let values = [Primant::MAX, Primant::MAX, Primant::ZERO, Primant::ZERO];
+    /// assert_eq!(Primant::mean(values), Some(Primant::from_raw(u32::MAX / 2)));
+    /// assert_eq!(Primant::mean([]), None);
+    /// ```
+    pub fn mean<I: IntoIterator<Item = Primant>>(values: I) -> Option<Self> {
+        let (mut count, mut sum) = (0u64, 0u128);
+        for value in values {
+            sum += value.0 as u128;
+            count += 1;
+        }
+        if count == 0 { return None; }
+        Some(Primant((sum / count as u128) as u32))
+    }
+
+    /// Divides this [`Primant`] by `other`, saturating at [`Primant::MAX`] if this
+    /// [`Primant`] is greater, such as for the progress relative to a budget.
+    ///
+    /// The quotient is computed the same way as [`Primant::ratio_of`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let (spent, budget) = (Primant::from_raw(1000), Primant::from_raw(4000));
+    /// assert_eq!(spent.div_saturating(budget), Primant::ratio_of(1, 4));
+    /// assert_eq!(budget.div_saturating(spent), Primant::MAX);
+    /// ```
+    pub const fn div_saturating(self, other: Primant) -> Self {
+        Primant::ratio_of_saturating(self.0 as u64, other.0 as u64)
+    }
+
+    /// Divides this [`Primant`] by `other`, returning `None` if `other` is zero or
+    /// if the quotient is greater than one.
+    pub const fn checked_div(self, other: Primant) -> Option<Self> {
+        Primant::try_ratio_of(self.0 as u64, other.0 as u64)
+    }
+}
+
+/// Decay and growth functions.
+impl Primant {
+    /// Decays this [`Primant`] by `rate`, multiplying it by `1 - rate`, such as for
+    /// one tick of a leaky integrator or of a decaying score.
+    ///
+    /// The product is rounded down, so any value reaches [`Primant::ZERO`] after
+    /// enough ticks with a non-zero rate, rather than getting stuck. Each tick lags
+    /// the exact exponential by less than one raw step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let rate = Primant::decay_per_tick_from_half_life(8);
+    /// let mut score = Primant::MAX;
+    /// for _ in 0..8 {
+    ///     score = score.decay(rate);
+    /// }
+    /// assert!(score.to_raw().abs_diff(u32::MAX / 2) < 16);
+    /// ```
+    pub const fn decay(self, rate: Primant) -> Self {
+        let keep = rate.complement();
+        Primant((self.0 as u64 * keep.0 as u64 / u32::MAX as u64) as u32)
+    }
+
+    /// Returns the rate for [`Primant::decay`] halving a value every
+    /// `half_life_ticks` ticks, as `1 - 2^(-1 / half_life_ticks)`.
+    ///
+    /// The rate is the one whose exact compounding over the half-life comes closest
+    /// to one half, found with integer arithmetic only, so it is the same on every
+    /// target.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `half_life_ticks` is zero.
+    pub fn decay_per_tick_from_half_life(half_life_ticks: u32) -> Self {
+        assert_ne!(half_life_ticks, 0, "half-life must not be zero");
+        const HALF: u128 = 1 << 61;
+        // Raises a retained fraction to the half-life, in 62-bit fixed point.
+        let compound = |keep: u32| {
+            let mut base = ((keep as u128) << 62) / u32::MAX as u128;
+            let (mut power, mut exponent) = (1u128 << 62, half_life_ticks);
+            while exponent > 0 {
+                if exponent & 1 == 1 { power = (power * base + HALF) >> 62; }
+                base = (base * base + HALF) >> 62;
+                exponent >>= 1;
+            }
+            power
+        };
+
+        // Finds the largest retained fraction compounding to at most one half.
+        let (mut low, mut high) = (0u32, u32::MAX);
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            if compound(middle) <= HALF { low = middle; } else { high = middle; }
+        }
+        let keep = if HALF - compound(low) <= compound(high) - HALF { low } else { high };
+        Primant(keep).complement()
+    }
+
+    /// Grows this [`Primant`] by one step of the discrete logistic map,
+    /// `self + rate * self * (1 - self)`, such as for a trust score or a congestion
+    /// window ramping up smoothly towards one.
+    ///
+    /// The growth is fastest halfway, and slows down near both ends. As `rate` is at
+    /// most one, the result never exceeds [`Primant::MAX`]. The growth is rounded
+    /// up, so any non-zero value reaches [`Primant::MAX`] after enough steps with a
+    /// non-zero rate, while [`Primant::ZERO`] never grows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let mut trust = Primant::from_ratio(1u32, 100u32);
+    /// for _ in 0..20 {
+    ///     trust = trust.logistic_step(Primant::from_ratio(1u32, 2u32));
+    /// }
+    /// assert!(trust > Primant::from_ratio(99u32, 100u32));
+    /// ```
+    pub const fn logistic_step(self, rate: Primant) -> Self {
+        let max = u32::MAX as u128;
+        let product = self.0 as u128 * self.complement().0 as u128 * rate.0 as u128;
+        let growth = product.div_ceil(max * max);
+        Primant(self.0 + growth as u32)
+    }
+}
+
+/// Interpolation functions.
+///
+/// These take a [`Primant`] as the interpolation parameter, and are named so as not
+/// to shadow [`Lerp::lerp`], which interpolates between two [`Primant`]s.
+impl Primant {
+    /// Interpolates linearly from `a` to `b` at this fraction, rounded to the
+    /// nearest integer (with ties rounded up).
+    ///
+    /// The result is computed exactly, so [`Primant::ZERO`] yields `a` and
+    /// [`Primant::MAX`] yields `b`. This is the same as [`Lerp::lerp`] for `u32`,
+    /// but usable in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// const WIDTH: u32 = Primant::ratio_of(1, 4).lerp_u32(200, 1000);
+    /// assert_eq!(WIDTH, 400);
+    /// ```
+    pub const fn lerp_u32(self, a: u32, b: u32) -> u32 {
+        lerp::lerp_i128(a as i128, b as i128, self) as u32
+    }
+
+    /// Interpolates linearly from `a` to `b` at this fraction, rounded to the
+    /// nearest integer (with ties rounded up).
+    ///
+    /// The result is computed exactly, so [`Primant::ZERO`] yields `a` and
+    /// [`Primant::MAX`] yields `b`. This is the same as [`Lerp::lerp`] for `i64`,
+    /// but usable in `const` contexts.
+    pub const fn lerp_i64(self, a: i64, b: i64) -> i64 {
+        lerp::lerp_i128(a as i128, b as i128, self) as i64
+    }
+
+    /// Creates a new [`Primant`] representing how far `value` lies from `a` to `b`,
+    /// rounded down, such as for mapping a sensor reading onto its calibrated range.
+    ///
+    /// This is the inverse of [`Lerp::lerp`]: `a` yields [`Primant::ZERO`] and `b`
+    /// yields [`Primant::MAX`]. `a` may be greater than `b`, for a reversed range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` are equal, or if `value` does not lie between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// assert_eq!(Primant::inverse_lerp(-10, 30, 0), Primant::ratio_of(1, 4));
+    /// assert_eq!(Primant::inverse_lerp(200u8, 100, 150), Primant::ratio_of(1, 2));
+    /// ```
+    pub fn inverse_lerp<T: Integer>(a: T, b: T, value: T) -> Self {
+        Self::try_inverse_lerp(a, b, value).expect("value must lie between distinct ends")
+    }
+
+    /// Creates a new [`Primant`] representing how far `value` lies from `a` to `b`,
+    /// rounded down.
+    ///
+    /// Returns `None` if `a` and `b` are equal, or if `value` does not lie between
+    /// them.
+    pub fn try_inverse_lerp<T: Integer>(a: T, b: T, value: T) -> Option<Self> {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        if a == b || value < low || value > high { return None; }
+        Some(Self::inverse_lerp_in_range(a, b, value))
+    }
+
+    /// Creates a new [`Primant`] representing how far `value` lies from `a` to `b`,
+    /// rounded down.
+    ///
+    /// If `value` does not lie between `a` and `b`, it saturates to the closer end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` are equal.
+    pub fn inverse_lerp_saturating<T: Integer>(a: T, b: T, value: T) -> Self {
+        assert!(a != b, "ends must be distinct");
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        let value = if value < low { low } else if value > high { high } else { value };
+        Self::inverse_lerp_in_range(a, b, value)
+    }
+
+    /// Computes the inverse interpolation of a value between distinct `a` and `b`.
+    fn inverse_lerp_in_range<T: Integer>(a: T, b: T, value: T) -> Self {
+        // Distances between any two integers fit in 128 bits.
+        let distance = |from: T, to: T| {
+            let ((from_negative, from), (to_negative, to)) = (from.to_sign_magnitude(), to.to_sign_magnitude());
+            if from_negative == to_negative { from.abs_diff(to) } else { from + to }
+        };
+        let (part, whole) = (distance(a, value), distance(a, b));
+        if part == whole { return Primant::MAX; }
+
+        // Divides `part * 2^32` by `whole` one bit at a time, as the product may not
+        // fit in 128 bits, then corrects it to `part * u32::MAX`.
+        let (mut quotient, mut remainder) = (0u32, part);
+        for _ in 0..32 {
+            let carry = remainder >> 127 == 1;
+            remainder <<= 1;
+            quotient <<= 1;
+            if carry || remainder >= whole {
+                remainder = remainder.wrapping_sub(whole);
+                quotient |= 1;
+            }
+        }
+        Primant(if remainder >= part { quotient } else { quotient - 1 })
+    }
+}
+
+/// Interpolation functions for floating-point numbers.
+///
+/// These functions are only available with the `float` feature.
+#[cfg(feature = "float")]
+impl Primant {
+    /// Interpolates linearly from `a` to `b` at this fraction.
+    ///
+    /// [`Primant::ZERO`] yields `a` and [`Primant::MAX`] yields `b` exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let opacity = Primant::from_ratio(1u32, 2u32).lerp_float(0.2f32, 0.8);
+    /// assert!((opacity - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn lerp_float<T: FloatCore>(self, a: T, b: T) -> T {
+        if self == Primant::MAX { return b; }
+        a + (b - a) * self.into_float::<T>()
+    }
+}
+
+/// Scaling of integers by a [`Primant`].
+impl Primant {
+    /// Returns this fraction of `whole`, rounded to the nearest integer.
+    ///
+    /// The result is computed exactly, so [`Primant::ZERO`] always yields zero and
+    /// [`Primant::MAX`] always yields `whole` itself. Negative values of `whole`
+    /// are scaled by magnitude, so the result keeps the sign of `whole`.
+    pub fn percent_of<T: Integer>(self, whole: T) -> T {
+        let (negative, magnitude) = whole.to_sign_magnitude();
+        T::from_sign_magnitude(negative, self.scale_u128(magnitude, true))
+    }
+
+    /// Returns this fraction of `len` as a position between elements, split exactly
+    /// into an integer index and a fraction, such as for reading a wavetable.
+    ///
+    /// [`Primant::MAX`] yields the index `len` itself, which
+    /// [`FracIndex::interpolate`] wraps to the start of a circular buffer. To read
+    /// the last element of a buffer that does not wrap, use `len - 1` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{FracIndex, Primant};
+    /// let position = Primant::from_ratio(3u32, 4u32).split_index(2);
+    /// assert_eq!(position.index(), 1);
+    /// assert_eq!(position.fraction(), Primant::from_ratio(1u32, 2u32));
+    /// ```
+    pub fn split_index(self, len: usize) -> FracIndex {
+        let max = u32::MAX as u128;
+        let position = self.0 as u128 * len as u128;
+        FracIndex::new((position / max) as usize, Primant((position % max) as u32))
+    }
+
+    /// Computes `value * self` exactly, either rounded down or to the nearest
+    /// integer (with ties rounded up).
+    ///
+    /// The result never exceeds `value`, so it cannot overflow.
+    pub(crate) fn scale_u128(self, value: u128, round: bool) -> u128 {
+        let max = u32::MAX as u128;
+        let (quotient, remainder) = (value / max, value % max);
+        let bias = if round { max / 2 } else { 0 };
+        quotient * self.0 as u128 + (remainder * self.0 as u128 + bias) / max
+    }
+}
+
+/// Conversion functions from time.
+impl Primant {
+    /// Creates a new [`Primant`] representing the progress of `elapsed` out of
+    /// `total`, such as for a progress bar.
+    ///
+    /// The result is computed exactly from the nanosecond counts of both durations,
+    /// and rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `total` is zero or if `elapsed` is greater than `total`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// # use unifrac::Primant;
+    /// let progress = Primant::from_durations(Duration::from_millis(250), Duration::from_secs(1));
+    /// assert_eq!(progress, Primant::from_ratio(1u32, 4u32));
+    /// ```
+    pub fn from_durations(elapsed: Duration, total: Duration) -> Self {
+        assert!(!total.is_zero(), "total must not be zero");
+        assert!(elapsed <= total, "elapsed must not be greater than the total");
+        Self::durations_raw(elapsed, total)
+    }
+
+    /// Creates a new [`Primant`] representing the progress of `elapsed` out of
+    /// `total`.
+    ///
+    /// Returns `None` if `total` is zero or if `elapsed` is greater than `total`.
+    pub fn try_from_durations(elapsed: Duration, total: Duration) -> Option<Self> {
+        if total.is_zero() || elapsed > total { return None; }
+        Some(Self::durations_raw(elapsed, total))
+    }
+
+    /// Creates a new [`Primant`] representing the progress of `elapsed` out of
+    /// `total`.
+    ///
+    /// If `elapsed` is greater than `total`, it saturates to [`Primant::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `total` is zero.
+    pub fn from_durations_saturating(elapsed: Duration, total: Duration) -> Self {
+        assert!(!total.is_zero(), "total must not be zero");
+        Self::durations_raw(elapsed.min(total), total)
+    }
+
+    /// Computes `elapsed / total`, rounded down.
+    ///
+    /// The caller must ensure that `total` is non-zero and `elapsed <= total`. A
+    /// duration has fewer than 96 bits of nanoseconds, so nothing overflows.
+    fn durations_raw(elapsed: Duration, total: Duration) -> Self {
+        Primant((elapsed.as_nanos() * u32::MAX as u128 / total.as_nanos()) as u32)
+    }
+}
+
+/// Iteration functions.
+impl Primant {
+    /// Returns an iterator over `count` equally spaced values, from exactly
+    /// [`Primant::ZERO`] to exactly [`Primant::MAX`], such as for a parameter sweep
+    /// in a test.
+    ///
+    /// The values in between are rounded down, like [`Primant::from_ratio`]. A
+    /// single value is just [`Primant::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let values: Vec<_> = Primant::sweep_inclusive(3).collect();
+    /// assert_eq!(values, [Primant::ZERO, Primant::from_ratio(1u32, 2u32), Primant::MAX]);
+    /// ```
+    pub fn sweep_inclusive(count: u32) -> SweepInclusive {
+        SweepInclusive::new(count)
+    }
+}
+
+/// Conversion functions to servo pulse widths.
+impl Primant {
+    /// Returns the width of an RC servo pulse, in microseconds, for this position.
+    ///
+    /// [`Primant::ZERO`] maps to exactly `min_us` and [`Primant::MAX`] to exactly
+    /// `max_us`, with the positions in between rounded to the nearest microsecond.
+    /// `min_us` may be greater than `max_us` to reverse the direction of the servo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// assert_eq!(Primant::ZERO.to_servo_us(1000, 2000), 1000);
+    /// assert_eq!(Primant::from_ratio(1u32, 4u32).to_servo_us(1000, 2000), 1250);
+    /// assert_eq!(Primant::MAX.to_servo_us(1000, 2000), 2000);
+    /// ```
+    pub fn to_servo_us(self, min_us: u32, max_us: u32) -> u32 {
+        min_us.lerp(max_us, self)
+    }
+}
+
+/// Quantization functions.
+impl Primant {
+    /// Snaps this value to a grid of `steps` equal intervals, with `rounding`
+    /// choosing between the grid values around it.
+    ///
+    /// The grid values are `k / steps` for every `k` from zero to `steps`, rounded
+    /// down as by [`Primant::from_ratio`].
+    ///
+    /// The grid values themselves are kept unchanged, so quantizing is idempotent,
+    /// and a larger value never snaps to a smaller grid value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `steps` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{Primant, Rounding};
+    /// let value = Primant::from_ratio(2u32, 3u32);
+    /// assert_eq!(value.quantize(10, Rounding::HalfUp), Primant::from_ratio(7u32, 10u32));
+    /// assert_eq!(value.quantize(10, Rounding::Down), Primant::from_ratio(6u32, 10u32));
+    /// assert_eq!(Primant::MAX.quantize(10, Rounding::Up), Primant::MAX);
+    /// ```
+    pub fn quantize(self, steps: u32, rounding: Rounding) -> Self {
+        assert_ne!(steps, 0, "number of steps must not be zero");
+        let grid = |k: u64| Self::grid_raw(k, steps);
+        let raw = self.0 as u64;
+
+        // The estimate is off by at most one step, as the grid values are rounded down.
+        let mut k = raw * steps as u64 / u32::MAX as u64;
+        if k < steps as u64 && grid(k + 1) <= raw { k += 1; }
+        if grid(k) > raw { k -= 1; }
+
+        let lower = grid(k);
+        if lower == raw { return Primant(raw as u32); }
+        let upper = grid(k + 1);
+        let up = rounding.rounds_up((raw - lower) as u128, (upper - raw) as u128, k % 2 == 1);
+        Primant(if up { upper } else { lower } as u32)
+    }
+
+    /// Computes the raw value of `k / steps`, rounded down.
+    fn grid_raw(k: u64, steps: u32) -> u64 {
+        k * u32::MAX as u64 / steps as u64
+    }
+}
+
+/// Decimal rounding functions.
+///
+/// These round to the nearest decimal, then return the representable value nearest
+/// to it, so the result is stable when printed with the same number of decimals and
+/// parsed back. Ties cannot occur, as no decimal lies exactly halfway between two
+/// representable values.
+impl Primant {
+    /// Rounds this value to `decimals` decimal places.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decimals` is greater than 19.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let value = Primant::ratio_of(12_345, 100_000);
+    /// assert_eq!(value.round_to_decimals(2), Primant::from_ratio(12u32, 100u32));
+    /// assert_eq!(value.round_to_decimals(0), Primant::ZERO);
+    /// ```
+    pub fn round_to_decimals(self, decimals: u32) -> Self {
+        assert!(decimals <= 19, "number of decimals must not be greater than 19");
+        let scale = 10u128.pow(decimals);
+        let max = u32::MAX as u128;
+        let scaled = self.0 as u128 * scale;
+        let decimal = scaled / max + (2 * (scaled % max) > max) as u128;
+        Primant(((decimal * max + scale / 2) / scale) as u32)
+    }
+
+    /// Rounds the percentage of this value to `decimals` decimal places.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decimals` is greater than 17.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let value = Primant::ratio_of(12_345, 100_000);
+    /// assert_eq!(value.round_to_percent_decimals(1), Primant::from_ratio(123u32, 1000u32));
+    /// ```
+    pub fn round_to_percent_decimals(self, decimals: u32) -> Self {
+        assert!(decimals <= 17, "number of decimals must not be greater than 17");
+        self.round_to_decimals(decimals + 2)
+    }
+}
+
+/// Parsing functions.
+impl Primant {
+    /// Parses a decimal number, optionally followed by the suffix of a
+    /// [`PartsUnit`](crate::PartsUnit), such as `"25bps"`, `"2.5‰"`, or `"150ppm"`.
+    ///
+    /// A number without a unit is a plain fraction, such as `"0.25"`. Whitespace
+    /// around the number and before the unit is ignored. The result is the nearest
+    /// representable value, with ties rounded up, computed exactly from the first 26
+    /// decimal places.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParsePrimantError`] if the string is not a valid non-negative
+    /// decimal with a known unit, or if the value is greater than one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{ParsePrimantError, Primant};
+    /// assert_eq!(Primant::parse("2.5‰"), Primant::parse("25bps"));
+    /// assert_eq!(Primant::parse("100%"), Ok(Primant::MAX));
+    /// assert_eq!(Primant::parse("150 kg"), Err(ParsePrimantError::UnknownUnit));
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, ParsePrimantError> {
+        parse::parse_primant(text)
+    }
+}
+
+/// Adds two [`Primant`]s, saturating at [`Primant::MAX`].
+impl Add for Primant {
+    type Output = Primant;
+
+    fn add(self, other: Primant) -> Self::Output {
+        self.saturating_add(other)
+    }
+}
+
+/// Subtracts two [`Primant`]s, saturating at [`Primant::ZERO`].
+impl Sub for Primant {
+    type Output = Primant;
+
+    fn sub(self, other: Primant) -> Self::Output {
+        self.saturating_sub(other)
+    }
+}
+
+/// Multiplies two [`Primant`]s, rounding to the nearest value.
+impl Mul for Primant {
+    type Output = Primant;
+
+    fn mul(self, other: Primant) -> Self::Output {
+        self.mul_round(other)
+    }
+}
+
+impl AddAssign for Primant {
+    fn add_assign(&mut self, other: Primant) {
+        *self = self.saturating_add(other);
+    }
+}
+
+impl SubAssign for Primant {
+    fn sub_assign(&mut self, other: Primant) {
+        *self = self.saturating_sub(other);
+    }
+}
+
+impl MulAssign for Primant {
+    fn mul_assign(&mut self, other: Primant) {
+        *self = self.mul_round(other);
+    }
+}
+
+/// Returns the complement of a [`Primant`], `1 - self`.
+impl Not for Primant {
+    type Output = Primant;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+/// Sums [`Primant`]s, saturating at [`Primant::MAX`].
+impl Sum for Primant {
+    fn sum<I: Iterator<Item = Primant>>(values: I) -> Self {
+        values.fold(Primant::ZERO, Primant::saturating_add)
+    }
+}
+
+/// Sums [`Primant`]s, saturating at [`Primant::MAX`].
+impl<'a> Sum<&'a Primant> for Primant {
+    fn sum<I: Iterator<Item = &'a Primant>>(values: I) -> Self {
+        values.copied().sum()
+    }
+}
+
+macro_rules! impl_mul_integer {
+    ($($t:ty),*) => {$(
+        /// Scales an integer by a [`Primant`], rounding down.
+        ///
+        /// The result is computed exactly, so [`Primant::MAX`] always yields the
+        /// integer itself. To round to the nearest value instead, use
+        /// [`Primant::percent_of`].
+        impl Mul<$t> for Primant {
+            type Output = $t;
+
+            fn mul(self, value: $t) -> Self::Output {
+                self.scale_u128(value as u128, false) as $t
+            }
+        }
+    )*};
+}
+
+impl_mul_integer!(u8, u16, u32, u64, u128, usize);
+
+/// Parses a [`Primant`] the same way as [`Primant::parse`].
+impl FromStr for Primant {
+    type Err = ParsePrimantError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse::parse_primant(text)
+    }
+}
+
+/// Parses a [`Primant`] the same way as [`Primant::parse`], for generic conversion
+/// bounds such as `T: TryFrom<&str>`.
+impl TryFrom<&str> for Primant {
+    type Error = ParsePrimantError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        parse::parse_primant(text)
+    }
+}
+
+/// Conversion functions to integer parts of a whole, with a rounding mode.
+///
+/// No representable value lies exactly halfway between two parts, so the modes
+/// rounding to the nearest part always agree.
+impl Primant {
+    /// Returns this value in basis points, hundredths of a percent, from 0 to
+    /// 10000, rounded with `rounding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::{Primant, Rounding};
+    /// let value = Primant::from_ratio(2u32, 3u32);
+    /// assert_eq!(value.to_basis_points_with(Rounding::Down), 6666);
+    /// assert_eq!(value.to_basis_points_with(Rounding::HalfEven), 6667);
+    /// ```
+    pub fn to_basis_points_with(self, rounding: Rounding) -> u32 {
+        self.to_parts_with(10_000, rounding)
+    }
+
+    /// Returns this value in parts per ten thousand, from 0 to 10000, rounded with
+    /// `rounding`.
+    ///
+    /// This is the same as [`Primant::to_basis_points_with`].
+    pub fn to_permyriad_with(self, rounding: Rounding) -> u32 {
+        self.to_parts_with(10_000, rounding)
+    }
+
+    /// Returns this value in parts per thousand, from 0 to 1000, rounded with
+    /// `rounding`.
+    pub fn to_permille_with(self, rounding: Rounding) -> u32 {
+        self.to_parts_with(1_000, rounding)
+    }
+
+    /// Computes `self * parts` exactly, rounded with `rounding`.
+    fn to_parts_with(self, parts: u32, rounding: Rounding) -> u32 {
+        let max = u32::MAX as u128;
+        let scaled = self.0 as u128 * parts as u128;
+        let (quotient, remainder) = (scaled / max, scaled % max);
+        let up = rounding.rounds_up(remainder, max - remainder, quotient % 2 == 1);
+        (quotient + up as u128) as u32
+    }
+}
+
+/// Comparison functions.
+impl Primant {
+    /// Returns the absolute difference between two [`Primant`]s.
+    ///
+    /// The difference is exact, and never overflows.
+    pub const fn abs_diff(self, other: Primant) -> Self {
+        Primant(self.0.abs_diff(other.0))
+    }
+
+    /// Returns whether this value is within `tolerance` of `target`, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let target = Primant::from_ratio(1u32, 2u32);
+    /// let tolerance = Primant::from_ratio(2u32, 100u32);
+    /// assert!(Primant::from_ratio(51u32, 100u32).within(target, tolerance));
+    /// assert!(!Primant::from_ratio(53u32, 100u32).within(target, tolerance));
+    /// ```
+    pub const fn within(self, target: Primant, tolerance: Primant) -> bool {
+        self.abs_diff(target).0 <= tolerance.0
+    }
+}
+
+/// Clamping functions.
+impl Primant {
+    /// Restricts the value to the range from `min` to `max`, both inclusive.
+    ///
+    /// Unlike [`Ord::clamp`], this can be used in a `const` context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Primant, max: Primant) -> Self {
+        assert!(min.0 <= max.0, "min must not be greater than max");
+        if self.0 < min.0 {
+            min
+        } else if self.0 > max.0 {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Restricts the value to the given range.
+    pub const fn clamp_to(self, range: PrimantRange) -> Self {
+        self.clamp(range.start(), range.end())
+    }
+}
+
+/// Selection functions over slices.
+///
+/// Ties are resolved towards the first of the equal values.
+impl Primant {
+    /// Returns the smallest value in `values`, or `None` if it is empty.
+    pub const fn min_of(values: &[Primant]) -> Option<Self> {
+        match Self::argmin(values) {
+            Some(index) => Some(values[index]),
+            None => None,
+        }
+    }
+
+    /// Returns the largest value in `values`, or `None` if it is empty.
+    pub const fn max_of(values: &[Primant]) -> Option<Self> {
+        match Self::argmax(values) {
+            Some(index) => Some(values[index]),
+            None => None,
+        }
+    }
+
+    /// Returns the index of the smallest value in `values`, or `None` if it is
+    /// empty.
+    pub const fn argmin(values: &[Primant]) -> Option<usize> {
+        if values.is_empty() { return None; }
+        let mut best = 0;
+        let mut i = 1;
+        while i < values.len() {
+            if values[i].0 < values[best].0 { best = i; }
+            i += 1;
+        }
+        Some(best)
+    }
+
+    /// Returns the index of the largest value in `values`, or `None` if it is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let scores = [Primant::from_raw(3), Primant::from_raw(9), Primant::from_raw(9)];
+    /// assert_eq!(Primant::argmax(&scores), Some(1));
+    /// assert_eq!(Primant::max_of(&scores), Some(Primant::from_raw(9)));
+    /// assert_eq!(Primant::argmax(&[]), None);
+    /// ```
+    pub const fn argmax(values: &[Primant]) -> Option<usize> {
+        if values.is_empty() { return None; }
+        let mut best = 0;
+        let mut i = 1;
+        while i < values.len() {
+            if values[i].0 > values[best].0 { best = i; }
+            i += 1;
+        }
+        Some(best)
+    }
+}
+
+/// Collection functions, such as for batch processing in host-side tooling.
+///
+/// These functions are only available with the `alloc` feature.
+#[cfg(feature = "alloc")]
 impl Primant {
-    /// Creates a new [`Primant`] from a floating-point value.
+    /// Scales `weights` so that their raw values add up to exactly [`Primant::MAX`],
+    /// keeping their proportions as closely as possible.
     ///
-    /// # Panics
+    /// Every share is rounded down, and the remaining raw steps go to the shares
+    /// with the largest remainders, so no share is off by more than one raw step.
+    /// This is [`split_budget`](crate::split_budget) with a budget of
+    /// [`Primant::MAX`]. Returns `None` if every weight is zero.
     ///
-    /// Panics if the value is not in the range `0.0..=1.0`.
-    pub fn from_float<T: FloatCore>(value: T) -> Self {
-        assert!(value >= T::zero() && value <= T::one(), "value must be in the range 0.0..=1.0");
-        Primant((value * T::from(u32::MAX).unwrap()).to_u32().unwrap())
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let third = Primant::from_ratio(1u32, 3u32);
+    /// let shares = Primant::normalize(&[third, third, third]).unwrap();
+    /// let total: u64 = shares.iter().map(|share| share.to_raw() as u64).sum();
+    /// assert_eq!(total, u32::MAX as u64);
+    /// ```
+    pub fn normalize(weights: &[Primant]) -> Option<Vec<Primant>> {
+        if weights.iter().all(|weight| weight.0 == 0) { return None; }
+
+        let mut parts = alloc::vec![0; weights.len()];
+        crate::split_budget(u32::MAX as u64, weights, &mut parts);
+        Some(parts.into_iter().map(|part| Primant(part as u32)).collect())
     }
 
-    /// Creates a new [`Primant`] from a floating-point value.
+    /// Returns the running totals of `values`, saturating at [`Primant::MAX`].
     ///
-    /// Returns `None` if the value is not in the range `0.0..=1.0`.
-    pub fn try_from_float<T: FloatCore>(value: T) -> Option<Self> {
-        if value < T::zero() || value > T::one() { return None; }
-        let value = (value * T::from(u32::MAX)?).to_u32()?;
-        Some(Primant(value))
+    /// For normalized probabilities, this is their cumulative distribution.
+    pub fn cumulative_sums(values: &[Primant]) -> Vec<Primant> {
+        let mut total = Primant::ZERO;
+        values.iter().map(|value| {
+            total = Primant(total.0.saturating_add(value.0));
+            total
+        }).collect()
     }
 
-    /// Creates a new [`Primant`] from a floating-point value.
+    /// Returns the percentiles `ranks` of `values`, interpolating linearly between
+    /// the closest values, or `None` if `values` is empty.
     ///
-    /// If the value is not in the range `0.0..=1.0`, it saturates to the closest
-    /// representable value.
-    pub fn from_float_saturating<T: FloatCore>(value: T) -> Self {
-        Primant((value.clamp(T::zero(), T::one()) * T::from(u32::MAX).unwrap()).to_u32().unwrap())
-    }
+    /// The values are sorted once, so asking for several percentiles at a time is
+    /// cheaper than one by one. [`Primant::ZERO`] is the smallest value and
+    /// [`Primant::MAX`] is the largest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let values = [Primant::MAX, Primant::ZERO, Primant::from_ratio(1u32, 2u32)];
+    /// let half = Primant::from_ratio(1u32, 2u32);
+    /// let percentiles = Primant::percentiles(&values, &[Primant::ZERO, half]).unwrap();
+    /// assert_eq!(percentiles, [Primant::ZERO, half]);
+    /// ```
+    pub fn percentiles(values: &[Primant], ranks: &[Primant]) -> Option<Vec<Primant>> {
+        if values.is_empty() { return None; }
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable();
 
-    /// Returns the value as a floating-point number.
-    pub fn into_float<T: FloatCore>(self) -> T {
-        T::from(self.0).unwrap() / T::from(u32::MAX).unwrap()
+        let last = sorted.len() as u64 - 1;
+        Some(ranks.iter().map(|rank| {
+            let position = rank.0 as u64 * last;
+            let (index, remainder) = (position / u32::MAX as u64, position % u32::MAX as u64);
+            let below = sorted[index as usize];
+            match sorted.get(index as usize + 1) {
+                // The remainder is the fraction of the way to the next value.
+                Some(&above) => below.lerp(above, Primant(remainder as u32)),
+                None => below,
+            }
+        }).collect())
     }
 }
 
-/// Conversion functions to and from integer ratios.
+/// Conversion functions between slices of floating-point numbers and vectors.
+///
+/// These functions are only available with the `alloc` and `float` features.
+#[cfg(all(feature = "alloc", feature = "float"))]
 impl Primant {
-    /// Creates a new [`Primant`] from a numerator and a denominator.
-    ///
-    /// # Panics
+    /// Converts every value of `values` to a [`Primant`].
     ///
-    /// Panics if the denominator is zero or if the result would not fit in a `Primant`.
-    pub fn from_ratio<T: PrimInt + Debug>(numerator: T, denominator: T) -> Self {
-        assert_ne!(denominator, T::zero(), "denominator must not be zero");
-        assert!(numerator <= denominator, "numerator must not be greater than the denominator");
-        let numerator = numerator.to_u64().unwrap();
-        let denominator = denominator.to_u64().unwrap();
-        let value = numerator * u32::MAX as u64 / denominator;
-        Primant(value.to_u32().unwrap())
+    /// Returns `None` if any value is not in the range `0.0..=1.0`.
+    pub fn try_from_f32_slice(values: &[f32]) -> Option<Vec<Primant>> {
+        values.iter().map(|&value| Primant::try_from(value).ok()).collect()
     }
 
-    /// Creates a new [`Primant`] from a numerator and a denominator.
+    /// Converts every value of `values` to a [`Primant`].
     ///
-    /// Returns `None` if the denominator is zero or if the result would not fit in a `Primant`.
-    pub fn try_from_ratio<T: PrimInt + Debug>(numerator: T, denominator: T) -> Option<Self> {
-        if denominator.is_zero() || numerator > denominator { return None; }
-        Some(Self::from_ratio(numerator, denominator))
+    /// Values not in the range `0.0..=1.0` saturate to the closest representable
+    /// value, and NaN becomes [`Primant::ZERO`].
+    pub fn from_f32_slice_saturating(values: &[f32]) -> Vec<Primant> {
+        values.iter().map(|&value| {
+            if value.is_nan() { Primant::ZERO } else { Primant::from_float_saturating(value) }
+        }).collect()
     }
 
-    /// Creates a new [`Primant`] from a numerator and a denominator.
-    ///
-    /// If the result would not fit in a `Primant`, it saturates to the closest representable
-    /// value.
-    ///
-    /// # Panics
+    /// Converts every value of `values` to an `f32`.
+    pub fn to_f32_vec(values: &[Primant]) -> Vec<f32> {
+        values.iter().map(|&value| f32::from(value)).collect()
+    }
+}
+
+/// Response shaping, such as for conditioning the input of a control.
+impl Primant {
+    /// Applies a dead zone, mapping values up to `threshold` to zero and stretching
+    /// the rest of the range so that [`Primant::MAX`] stays the same.
+    pub fn deadzone(self, threshold: Primant) -> Self {
+        Primant(shaping::deadzone(self.0 as u64, u32::MAX as u64, threshold) as u32)
+    }
+
+    /// Applies an exponential response curve, making the value less sensitive near
+    /// zero while keeping both endpoints.
     ///
-    /// Panics if the denominator is zero.
-    pub fn from_ratio_saturating<T: PrimInt + Debug>(numerator: T, denominator: T) -> Self {
-        assert_ne!(denominator, T::zero(), "denominator must not be zero");
-        if numerator > denominator { return Primant::MAX; }
-        Self::from_ratio(numerator, denominator)
+    /// The result is `(1 - amount)·x + amount·x³`, so [`Primant::ZERO`] keeps the
+    /// response linear and [`Primant::MAX`] makes it fully cubic.
+    pub fn expo(self, amount: Primant) -> Self {
+        Primant(shaping::expo(self.0 as u64, u32::MAX as u64, amount) as u32)
     }
 }
 
 /// Conversion functions to and from percentages.
+///
+/// These functions are only available with the `float` feature.
+#[cfg(feature = "float")]
 impl Primant {
     /// Returns the value as a percentage.
     pub fn to_percentage<T: FloatCore>(self) -> T {
@@ -187,15 +1337,240 @@ impl Primant {
     }
 }
 
+/// Random sampling functions.
+///
+/// These functions are only available with the `rand_core` feature.
+#[cfg(feature = "rand_core")]
+impl Primant {
+    /// Returns a random sample within the `i`-th of `n` equal strata of the unit
+    /// interval.
+    ///
+    /// Drawing one sample from each stratum, with `i` going from `0` to `n - 1`,
+    /// covers the interval more evenly than drawing `n` independent samples, which
+    /// reduces the variance of Monte Carlo estimates. The sample is uniformly
+    /// distributed within its stratum, including its lower bound but excluding its
+    /// upper bound.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `n`.
+    pub fn stratified<R: rand_core::Rng + ?Sized>(n: u32, i: u32, rng: &mut R) -> Self {
+        assert!(i < n, "stratum index must be less than the number of strata");
+        let bound = |index: u32| (index as u64 * u32::MAX as u64 / n as u64) as u32;
+        let (low, high) = (bound(i), bound(i + 1));
+        let offset = (rng.next_u32() as u64 * (high - low) as u64) >> 32;
+        Primant(low + offset as u32)
+    }
+
+    /// Returns a random multiplier for a retry delay, uniformly distributed
+    /// between `0.0` and `1.0`.
+    ///
+    /// Multiplying every delay by a full jitter spreads out retries the most, so
+    /// that clients failing at the same time do not retry in lockstep.
+    pub fn jitter_full<R: rand_core::Rng + ?Sized>(rng: &mut R) -> Self {
+        Primant(rng.next_u32())
+    }
+
+    /// Returns a random multiplier for a retry delay, uniformly distributed
+    /// between `0.5` and `1.0`.
+    ///
+    /// Unlike [`Primant::jitter_full`], an equal jitter keeps at least half of
+    /// every delay, which prevents retrying immediately.
+    pub fn jitter_equal<R: rand_core::Rng + ?Sized>(rng: &mut R) -> Self {
+        Primant((1 << 31) + (rng.next_u32() >> 1))
+    }
+}
+
+/// Transcendental functions, such as for audio levels and probabilities.
+///
+/// These functions are only available with the `libm` feature, so they work without
+/// the platform math library.
+#[cfg(feature = "libm")]
+impl Primant {
+    /// Returns this value as an amplitude ratio in decibels, `20·log10(x)`.
+    ///
+    /// [`Primant::MAX`] is 0 dB, and [`Primant::ZERO`] is negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let half = Primant::from_ratio(1u32, 2u32);
+    /// assert_eq!(half.to_decibels().round(), -6.0);
+    /// let tenth = Primant::from_ratio(1u32, 10u32);
+    /// assert!(Primant::from_decibels(-20.0).within(tenth, Primant::from_raw(1)));
+    /// ```
+    pub fn to_decibels(self) -> f64 {
+        20.0 * libm::log10(self.into_float::<f64>())
+    }
+
+    /// Creates a new [`Primant`] from an amplitude ratio in decibels.
+    ///
+    /// Levels above 0 dB saturate to [`Primant::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decibels` is NaN.
+    pub fn from_decibels(decibels: f64) -> Self {
+        Self::from_float_saturating(libm::pow(10.0, decibels / 20.0))
+    }
+
+    /// Raises this value to the power of `exponent`, such as for gamma correction.
+    ///
+    /// Results above one, from negative exponents, saturate to [`Primant::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exponent` is NaN.
+    pub fn powf(self, exponent: f64) -> Self {
+        Self::from_float_saturating(libm::pow(self.into_float::<f64>(), exponent))
+    }
+
+    /// Returns the log-odds of this value as a probability, `ln(p / (1 - p))`.
+    ///
+    /// [`Primant::ZERO`] and [`Primant::MAX`] are negative and positive infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let value = Primant::from_ratio(3u32, 4u32);
+    /// assert!((value.logit() - 3f64.ln()).abs() < 1e-9);
+    /// assert_eq!(Primant::from_logit(value.logit()), value);
+    /// ```
+    pub fn logit(self) -> f64 {
+        let p = self.into_float::<f64>();
+        libm::log(p) - libm::log1p(-p)
+    }
+
+    /// Creates a new [`Primant`] from log-odds, using the logistic function
+    /// `1 / (1 + e^-x)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `log_odds` is NaN.
+    pub fn from_logit(log_odds: f64) -> Self {
+        Self::from_float_saturating(1.0 / (1.0 + libm::exp(-log_odds)))
+    }
+}
+
+/// Statistical functions.
+///
+/// These functions are only available with the `libm` feature.
+#[cfg(feature = "libm")]
+impl Primant {
+    /// Returns the Wilson score confidence interval of a proportion, given the
+    /// number of `successes` out of `trials`.
+    ///
+    /// `z` is the quantile of the standard normal distribution for the desired
+    /// confidence, such as `1.96` for 95% confidence. Unlike the naive interval
+    /// around `successes / trials`, the Wilson interval stays within the unit
+    /// interval and remains meaningful for few trials or extreme proportions.
+    /// Without any trial, the interval covers the whole unit interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `successes` is greater than `trials`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// let (low, high) = Primant::wilson_interval(8, 10, 1.96);
+    /// assert_eq!(low.to_percentage::<f64>().round(), 49.0);
+    /// assert_eq!(high.to_percentage::<f64>().round(), 94.0);
+    /// ```
+    pub fn wilson_interval(successes: u64, trials: u64, z: f64) -> (Self, Self) {
+        assert!(successes <= trials, "successes must not be greater than trials");
+        if trials == 0 { return (Primant::ZERO, Primant::MAX); }
+
+        let (n, p, z_squared) = (trials as f64, successes as f64 / trials as f64, z * z);
+        let denominator = 1.0 + z_squared / n;
+        let center = (p + z_squared / (2.0 * n)) / denominator;
+        let margin = z * libm::sqrt(p * (1.0 - p) / n + z_squared / (4.0 * n * n)) / denominator;
+        (
+            Primant::from_float_saturating(center - margin),
+            Primant::from_float_saturating(center + margin),
+        )
+    }
+
+    /// Returns the `p`-quantile of the beta distribution with shape parameters
+    /// `alpha` and `beta`.
+    ///
+    /// The beta distribution describes the uncertainty about a success rate: after
+    /// observing `s` successes and `f` failures with a uniform prior, the rate
+    /// follows `Beta(s + 1, f + 1)`. Its quantiles give credible intervals, and
+    /// evaluating it at a random `p` draws a sample for Thompson sampling.
+    ///
+    /// The quantile is found by bisection of the regularized incomplete beta
+    /// function, to within the precision of a [`Primant`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` or `beta` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use unifrac::Primant;
+    /// // A credible interval after 8 successes and 2 failures.
+    /// let low = Primant::beta_quantile(9.0, 3.0, Primant::from_ratio(25u32, 1000u32));
+    /// let high = Primant::beta_quantile(9.0, 3.0, Primant::from_ratio(975u32, 1000u32));
+    /// assert_eq!(low.to_percentage::<f64>().round(), 48.0);
+    /// assert_eq!(high.to_percentage::<f64>().round(), 94.0);
+    /// ```
+    pub fn beta_quantile(alpha: f64, beta: f64, p: Primant) -> Self {
+        assert!(alpha > 0.0 && beta > 0.0, "shape parameters must be positive");
+        if p == Primant::ZERO { return Primant::ZERO; }
+        if p == Primant::MAX { return Primant::MAX; }
+        Primant::from_float_saturating(beta::beta_quantile(p.into_float(), alpha, beta))
+    }
+}
+
+/// Prints the approximate value, or with the alternate flag (`{:#?}`), also the exact
+/// raw value.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::Primant;
+/// let value = Primant::from_raw(0x8000_0000);
+/// assert!(format!("{value:#?}").ends_with(" ≈ raw 0x80000000)"));
+/// # #[cfg(feature = "float")]
+/// assert_eq!(format!("{value:#?}"), "Primant(0.5000000001164153 ≈ raw 0x80000000)");
+/// ```
 impl Debug for Primant {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "Primant({})", f64::from(*self))
+        f.write_str("Primant(")?;
+        #[cfg(feature = "float")]
+        write!(f, "{}", f64::from(*self))?;
+        #[cfg(not(feature = "float"))]
+        crate::format::write_ratio(f, self.0 as u128, u32::MAX as u128, 1, 10, "", 0)?;
+        if f.alternate() {
+            write!(f, " ≈ raw {:#010x}", self.0)?;
+        }
+        f.write_str(")")
     }
 }
 
+/// Prints the value as a percentage, or with the alternate flag (`{:#}`), as the
+/// exact ratio of its raw value to [`u32::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::Primant;
+/// let value = Primant::from_ratio(1u32, 2u32);
+/// assert_eq!(format!("{value}"), "50.00%");
+/// assert_eq!(format!("{value:#}"), "2147483647/4294967295");
+/// ```
 impl Display for Primant {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:.2}%", f64::from(*self) * 100.0)
+        if f.alternate() {
+            write!(f, "{}/{}", self.0, u32::MAX)
+        } else {
+            Display::fmt(&PercentFormat::new(*self), f)
+        }
     }
 }
 
@@ -205,15 +1580,18 @@ mod tests {
     extern crate std;
     extern crate alloc;
     use alloc::format;
-    use core::str::FromStr;
+    #[cfg(feature = "float")]
     use std::println;
 
+    #[cfg(feature = "float")]
     const EPSILON: f64 = 1e-6;
 
+    #[cfg(feature = "float")]
     fn assert_approx_eq<T: Into<f64>>(a: T, b: T) {
         assert!((a.into() - b.into()).abs() < EPSILON);
     }
 
+    #[cfg(feature = "float")]
     #[test]
     fn test_f64_conversion() {
         let fraction = Primant::try_from(0.5f64).unwrap();
@@ -221,13 +1599,37 @@ mod tests {
         assert_approx_eq(f, 0.5);
     }
 
+    #[cfg(feature = "float")]
     #[test]
     fn test_f32_conversion() {
         let fraction = Primant::try_from(0.5f32).unwrap();
         let f: f32 = fraction.into();
         assert_approx_eq(f, 0.5);
+        assert_eq!(Primant::from_float(1.0f32), Primant::MAX);
+        assert_eq!(Primant::from_float_saturating(2.0f32), Primant::MAX);
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_float_conversion_matches_division() {
+        let mut raw = 0u32;
+        for step in 1..20000u32 {
+            for raw in [raw, u32::MAX - raw, 1 << (step % 32), (1 << (step % 32)) + 1024 * step] {
+                let value = Primant::from_raw(raw);
+                assert_eq!(f64::from(value), raw as f64 / u32::MAX as f64);
+                assert_eq!(f32::from(value), raw as f32 / u32::MAX as f32);
+                assert_eq!(value.into_float::<f64>(), raw as f64 / u32::MAX as f64);
+            }
+            raw = raw.wrapping_mul(2_654_435_761).wrapping_add(step);
+        }
+        // Halfway between two `f64`s once repeated, but the quotient lies above.
+        let value = Primant::from_raw(0xFFFF_FC00);
+        assert_eq!(f64::from(value), 0xFFFF_FC00u32 as f64 / u32::MAX as f64);
+        assert_eq!(f64::from(Primant::MAX), 1.0);
+        assert_eq!(f32::from(Primant::MAX), 1.0);
     }
 
+    #[cfg(feature = "float")]
     #[test]
     fn test_into_percent() {
         let fraction = Primant::try_from(0.5f64).unwrap();
@@ -235,6 +1637,7 @@ mod tests {
         assert_approx_eq(percent, 50.0);
     }
 
+    #[cfg(feature = "float")]
     #[test]
     fn test_from_percent() {
         let fraction = Primant::try_from_percentage(50.0).unwrap();
@@ -244,9 +1647,57 @@ mod tests {
     #[test]
     fn test_from_ratio() {
         let fraction = Primant::try_from_ratio(1u32, 2u32).unwrap();
+        assert_eq!(fraction, Primant::from_raw(u32::MAX / 2));
+        #[cfg(feature = "float")]
         assert_approx_eq(fraction.into_float(), 0.5);
     }
 
+    #[test]
+    fn test_ratio_of() {
+        assert_eq!(Primant::ratio_of(0, u64::MAX), Primant::ZERO);
+        assert_eq!(Primant::ratio_of(u64::MAX, u64::MAX), Primant::MAX);
+        assert_eq!(Primant::ratio_of(1 << 40, 1 << 41), Primant::from_raw(u32::MAX / 2));
+        #[cfg(feature = "float")]
+        assert_approx_eq(Primant::ratio_of(1 << 40, 1 << 41).into_float(), 0.5);
+        assert_eq!(Primant::try_ratio_of(3, 2), None);
+        assert_eq!(Primant::try_ratio_of(1, 0), None);
+        assert_eq!(Primant::ratio_of_saturating(3, 2), Primant::MAX);
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_prim_int_bound() {
+        fn half<T: num_traits::PrimInt>(whole: T) -> T {
+            Primant::from_ratio(T::one(), T::one() + T::one()).percent_of(whole)
+        }
+        assert_eq!(half(1000u16), 500);
+        assert_eq!(half(-1000i64), -500);
+    }
+
+    #[test]
+    fn test_percent_of() {
+        let fraction = Primant::from_ratio(35u32, 100u32);
+        assert_eq!(fraction.percent_of(1280u32), 448);
+        assert_eq!(fraction.percent_of(-1280i32), -448);
+        assert_eq!(Primant::MAX.percent_of(u128::MAX), u128::MAX);
+        assert_eq!(Primant::MAX.percent_of(i64::MIN), i64::MIN);
+        assert_eq!(Primant::ZERO.percent_of(u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_shaping() {
+        let quarter = Primant::from_ratio(1u32, 4u32);
+        assert_eq!(quarter.deadzone(quarter), Primant::ZERO);
+        assert_eq!(Primant::MAX.deadzone(quarter), Primant::MAX);
+        #[cfg(feature = "float")]
+        assert_approx_eq(Primant::ratio_of(5, 8).deadzone(quarter).into_float(), 0.5);
+        assert_eq!(Primant::MAX.expo(Primant::MAX), Primant::MAX);
+        assert_eq!(quarter.expo(Primant::ZERO), quarter);
+        #[cfg(feature = "float")]
+        assert_approx_eq(Primant::ratio_of(1, 2).expo(Primant::MAX).into_float(), 0.125);
+    }
+
+    #[cfg(feature = "float")]
     #[test]
     fn test_debug() {
         let fraction = Primant::try_from(0.5f64).unwrap();
@@ -256,13 +1707,483 @@ mod tests {
         assert_eq!(&output[output.len() - 1..], ")");
         let value_str = &output[8..output.len() - 1];
         assert_approx_eq(f64::from_str(value_str).unwrap(), 0.5);
+        assert_eq!(format!("{:#?}", Primant::MAX), "Primant(1 ≈ raw 0xffffffff)");
     }
 
     #[test]
     fn test_display() {
-        let fraction = Primant::try_from(0.5f64).unwrap();
-        let output = format!("{}", fraction);
-        println!("{}", output);
+        let fraction = Primant::from_ratio(1u32, 2u32);
         assert_eq!(format!("{}", fraction), "50.00%");
+        assert_eq!(format!("{:#}", Primant::MAX), "4294967295/4294967295");
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_random_sampling() {
+        struct Fixed(u32);
+        impl rand_core::TryRng for Fixed {
+            type Error = core::convert::Infallible;
+            fn try_next_u32(&mut self) -> Result<u32, Self::Error> { Ok(self.0) }
+            fn try_next_u64(&mut self) -> Result<u64, Self::Error> { Ok(self.0 as u64) }
+            fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+                dst.fill(self.0 as u8);
+                Ok(())
+            }
+        }
+
+        assert_eq!(Primant::stratified(4, 1, &mut Fixed(0)), Primant::from_ratio(1u32, 4u32));
+        let top = Primant::stratified(4, 3, &mut Fixed(u32::MAX));
+        assert!(top < Primant::MAX && top > Primant::from_ratio(99u32, 100u32));
+        let middle = Primant::stratified(2, 0, &mut Fixed(1 << 31));
+        assert_eq!(middle, Primant::from_raw(u32::MAX / 4));
+
+        assert_eq!(Primant::jitter_full(&mut Fixed(u32::MAX)), Primant::MAX);
+        assert_eq!(Primant::jitter_equal(&mut Fixed(0)), Primant::from_raw(1 << 31));
+        assert_eq!(Primant::jitter_equal(&mut Fixed(u32::MAX)), Primant::MAX);
+    }
+
+    #[cfg(all(feature = "float", any(feature = "std", feature = "libm")))]
+    #[test]
+    fn test_real_conversion() {
+        assert_eq!(Primant::from_real(1.0f32), Primant::MAX);
+        assert_eq!(Primant::try_from_real(1.5f64), None);
+        assert_eq!(Primant::from_real_saturating(-1.0f64), Primant::ZERO);
+        assert_eq!(Primant::MAX.into_real::<f64>(), 1.0);
+        assert_eq!(crate::Phase::from_real(0.5f64), crate::Phase::from_float(0.5f64));
+        assert_eq!(crate::Bipolar::from_real_saturating(-2.0f32).into_real::<f32>(), -1.0);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_transcendental() {
+        assert_eq!(Primant::MAX.to_decibels(), 0.0);
+        assert_eq!(Primant::ZERO.to_decibels(), f64::NEG_INFINITY);
+        assert_eq!(Primant::from_decibels(f64::NEG_INFINITY), Primant::ZERO);
+        assert_eq!(Primant::from_decibels(6.0), Primant::MAX);
+        let quarter = Primant::from_ratio(1u32, 4u32);
+        assert!(Primant::from_ratio(1u32, 2u32).powf(2.0).within(quarter, Primant::from_raw(1)));
+        assert_eq!(Primant::ZERO.powf(-1.0), Primant::MAX);
+        assert_eq!(Primant::MAX.logit(), f64::INFINITY);
+        assert_eq!(Primant::from_logit(f64::NEG_INFINITY), Primant::ZERO);
+        assert_eq!(Primant::from_logit(0.0), Primant::from_float(0.5f64));
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_wilson_interval() {
+        assert_eq!(Primant::wilson_interval(0, 0, 1.96), (Primant::ZERO, Primant::MAX));
+        let (low, high) = Primant::wilson_interval(0, 10, 1.96);
+        assert_eq!(low, Primant::ZERO);
+        assert!((high.into_float::<f64>() - 0.2775).abs() < 1e-4);
+        let (low, high) = Primant::wilson_interval(500, 1000, 0.0);
+        assert_eq!((low, high), (Primant::from_float(0.5f64), Primant::from_float(0.5f64)));
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_beta_quantile() {
+        let quarter = Primant::from_ratio(1u32, 4u32);
+        let uniform = Primant::beta_quantile(1.0, 1.0, quarter);
+        assert!(uniform.to_raw().abs_diff(quarter.to_raw()) <= 2);
+        let median = Primant::beta_quantile(2.0, 5.0, Primant::from_ratio(1u32, 2u32));
+        assert!((median.into_float::<f64>() - 0.26445).abs() < 1e-5);
+        let arcsine = Primant::beta_quantile(0.5, 0.5, quarter);
+        assert!((arcsine.into_float::<f64>() - 0.1464466).abs() < 1e-6);
+        assert_eq!(Primant::beta_quantile(3.0, 2.0, Primant::MAX), Primant::MAX);
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        let (quarter, half) = (Primant::from_ratio(1u32, 4u32), Primant::from_ratio(1u32, 2u32));
+        assert_eq!(quarter + quarter, Primant::from_raw(quarter.to_raw() * 2));
+        assert_eq!(half + Primant::MAX, Primant::MAX);
+        assert_eq!(quarter - half, Primant::ZERO);
+        assert_eq!(half - quarter, Primant::from_raw(half.to_raw() - quarter.to_raw()));
+        let mut value = Primant::MAX;
+        value += Primant::from_raw(1);
+        assert_eq!(value, Primant::MAX);
+        value -= Primant::MAX;
+        value -= Primant::from_raw(1);
+        assert_eq!(value, Primant::ZERO);
+        const SUM: Primant = Primant::MAX.saturating_sub(Primant::ZERO.saturating_add(Primant::from_raw(5)));
+        assert_eq!(SUM, Primant::from_raw(u32::MAX - 5));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let (three, five) = (Primant::from_raw(3), Primant::from_raw(5));
+        assert_eq!(three.checked_add(five), Some(Primant::from_raw(8)));
+        assert_eq!(Primant::MAX.checked_add(Primant::ZERO), Some(Primant::MAX));
+        assert_eq!(Primant::MAX.checked_add(three), None);
+        assert_eq!(five.checked_sub(three), Some(Primant::from_raw(2)));
+        assert_eq!(three.checked_sub(five), None);
+        assert_eq!(Primant::MAX.checked_mul(five), Some(five));
+        assert_eq!(Primant::from_raw(u32::MAX / 2).checked_mul(Primant::from_raw(3)), Some(Primant::from_raw(1)));
+        assert_eq!(Primant::from_raw(u32::MAX / 2 + 1).checked_mul(Primant::from_raw(3)), Some(Primant::from_raw(2)));
+    }
+
+    #[test]
+    fn test_multiplication() {
+        let (quarter, half) = (Primant::from_ratio(1u32, 4u32), Primant::from_ratio(1u32, 2u32));
+        assert_eq!(half * half, Primant::from_ratio(1u32, 4u32));
+        assert_eq!(quarter * Primant::MAX, quarter);
+        assert_eq!(Primant::MAX * Primant::MAX, Primant::MAX);
+        assert_eq!(Primant::MAX * Primant::ZERO, Primant::ZERO);
+        let mut seed = 7u32;
+        for _ in 0..1000 {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let (a, b) = (Primant::from_raw(seed), Primant::from_raw(seed.rotate_left(13)));
+            assert_eq!(a * b, b * a);
+            assert_eq!(a * b, Primant::ZERO.lerp(a, b));
+        }
+        let mut value = half;
+        value *= half;
+        assert_eq!(value, Primant::from_ratio(1u32, 4u32));
+        const PRODUCT: Primant = Primant::MAX.mul_round(Primant::from_raw(9));
+        assert_eq!(PRODUCT, Primant::from_raw(9));
+    }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(Primant::ZERO.complement(), Primant::MAX);
+        assert_eq!(!Primant::MAX, Primant::ZERO);
+        let third = Primant::from_ratio(1u32, 3u32);
+        assert_eq!(!!third, third);
+        assert_eq!(third + !third, Primant::MAX);
+        assert_eq!(!Primant::from_raw(5), Primant::from_raw(u32::MAX - 5));
+    }
+
+    #[test]
+    fn test_decay() {
+        let half = Primant::decay_per_tick_from_half_life(1);
+        assert!(half.complement().to_raw().abs_diff(u32::MAX / 2) <= 1);
+        assert_eq!(Primant::MAX.decay(Primant::ZERO), Primant::MAX);
+        assert_eq!(Primant::MAX.decay(Primant::MAX), Primant::ZERO);
+        assert_eq!(Primant::from_raw(1).decay(Primant::from_raw(1)), Primant::ZERO);
+
+        // Every half-life halves the value, to within the rounding of each tick.
+        for half_life in [2, 10, 100, 1000] {
+            let rate = Primant::decay_per_tick_from_half_life(half_life);
+            let mut value = Primant::MAX;
+            for _ in 0..half_life {
+                value = value.decay(rate);
+            }
+            assert!(value <= Primant::from_raw(u32::MAX / 2 + 1));
+            assert!(value.to_raw() >= u32::MAX / 2 - half_life - 1);
+        }
+        assert!(Primant::decay_per_tick_from_half_life(u32::MAX) > Primant::ZERO);
+    }
+
+    #[test]
+    fn test_logistic_step() {
+        let half = Primant::from_ratio(1u32, 2u32);
+        assert_eq!(Primant::ZERO.logistic_step(Primant::MAX), Primant::ZERO);
+        assert_eq!(Primant::MAX.logistic_step(Primant::MAX), Primant::MAX);
+        assert_eq!(half.logistic_step(Primant::ZERO), half);
+        assert_eq!(Primant::from_raw(1).logistic_step(Primant::from_raw(1)), Primant::from_raw(2));
+        assert_eq!(Primant::from_raw(u32::MAX - 1).logistic_step(Primant::from_raw(1)), Primant::MAX);
+        let mut value = Primant::from_raw(1);
+        let mut steps = 0;
+        while value < Primant::MAX {
+            let next = value.logistic_step(Primant::MAX);
+            assert!(next > value);
+            value = next;
+            steps += 1;
+        }
+        assert!(steps < 100);
+    }
+
+    #[test]
+    fn test_integer_multiplication() {
+        let percent = Primant::ratio_of(37, 100);
+        assert_eq!(percent * 1920u32, 710);
+        assert_eq!(percent.percent_of(1920u32), 710);
+        assert_eq!(Primant::from_ratio(1u32, 2u32) * 255u8, 127);
+        assert_eq!(Primant::MAX * u128::MAX, u128::MAX);
+        assert_eq!(Primant::MAX * usize::MAX, usize::MAX);
+        assert_eq!(Primant::from_raw(u32::MAX - 1) * u64::MAX, u64::MAX - 4_294_967_297);
+        assert_eq!(Primant::ZERO * u16::MAX, 0);
+        for value in [0u64, 1, 99, 1 << 40, u64::MAX / 3] {
+            let expected = (value as u128 * percent.to_raw() as u128 / u32::MAX as u128) as u64;
+            assert_eq!(percent * value, expected);
+        }
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(Primant::MAX.midpoint(Primant::MAX), Primant::MAX);
+        assert_eq!(Primant::MAX.midpoint(Primant::ZERO), Primant::from_raw(u32::MAX / 2));
+        assert_eq!(Primant::from_raw(3).midpoint(Primant::from_raw(6)), Primant::from_raw(4));
+        const MEAN: Primant = Primant::MAX.weighted_mean(Primant::MAX, Primant::from_raw(12345));
+        assert_eq!(MEAN, Primant::MAX);
+        let mut seed = 11u32;
+        for _ in 0..1000 {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let (a, b, weight) = (Primant::from_raw(seed), Primant::from_raw(seed.rotate_left(11)), Primant::from_raw(seed.rotate_left(22)));
+            assert_eq!(a.weighted_mean(b, weight), a.lerp(b, weight));
+            assert_eq!(a.midpoint(b), b.midpoint(a));
+        }
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [Primant::from_raw(1), Primant::from_raw(2), Primant::from_raw(3)];
+        assert_eq!(values.iter().sum::<Primant>(), Primant::from_raw(6));
+        assert_eq!([Primant::MAX, Primant::from_raw(1)].into_iter().sum::<Primant>(), Primant::MAX);
+        assert_eq!(core::iter::empty::<Primant>().sum::<Primant>(), Primant::ZERO);
+        assert_eq!(Primant::mean(values), Some(Primant::from_raw(2)));
+        assert_eq!(Primant::mean(core::iter::repeat_n(Primant::MAX, 100_000)), Some(Primant::MAX));
+    }
+
+    #[test]
+    fn test_lerp_parameter() {
+        let quarter = Primant::ratio_of(1, 4);
+        assert_eq!(Primant::ZERO.lerp_u32(7, u32::MAX), 7);
+        assert_eq!(Primant::MAX.lerp_u32(u32::MAX, 0), 0);
+        assert_eq!(Primant::MAX.lerp_i64(i64::MIN, i64::MAX), i64::MAX);
+        assert_eq!(quarter.lerp_i64(-100, 100), -50);
+        let mut seed = 17u32;
+        for _ in 0..1000 {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let (t, a, b) = (Primant::from_raw(seed), seed.rotate_left(8), seed.rotate_left(20));
+            assert_eq!(t.lerp_u32(a, b), a.lerp(b, t));
+            let (a, b) = ((a as i64) << 31, -((b as i64) << 30));
+            assert_eq!(t.lerp_i64(a, b), a.lerp(b, t));
+        }
+    }
+
+    #[test]
+    fn test_inverse_lerp() {
+        assert_eq!(Primant::inverse_lerp(10u32, 20, 10), Primant::ZERO);
+        assert_eq!(Primant::inverse_lerp(10u32, 20, 20), Primant::MAX);
+        assert_eq!(Primant::inverse_lerp(20u32, 10, 10), Primant::MAX);
+        assert_eq!(Primant::inverse_lerp(0u32, 3, 1), Primant::ratio_of(1, 3));
+        assert_eq!(Primant::inverse_lerp(i128::MIN, i128::MAX, 0), Primant::from_raw(u32::MAX / 2));
+        assert_eq!(Primant::inverse_lerp(0, u128::MAX, u128::MAX / 3), Primant::ratio_of(1, 3));
+        assert_eq!(Primant::try_inverse_lerp(5, 5, 5), None);
+        assert_eq!(Primant::try_inverse_lerp(0i8, -10, 1), None);
+        assert_eq!(Primant::inverse_lerp_saturating(0i8, -10, 1), Primant::ZERO);
+        assert_eq!(Primant::inverse_lerp_saturating(0i8, -10, -11), Primant::MAX);
+        let mut seed = 19u32;
+        for _ in 0..1000 {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let (a, b) = ((seed as i64) << 20, -((seed.rotate_left(16) as i64) << 24) - 1);
+            let t = Primant::from_raw(seed.rotate_left(5));
+            let value = t.lerp_i64(a, b);
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+            let expected = (value - a) as i128 * u32::MAX as i128 / (b - a) as i128;
+            assert_eq!(Primant::inverse_lerp(a, b, value).to_raw() as i128, expected);
+            assert!(Primant::inverse_lerp(low, high, value).within(if a <= b { t } else { !t }, Primant::from_raw(1)));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn test_lerp_float() {
+        assert_eq!(Primant::ZERO.lerp_float(0.1f64, 0.7), 0.1);
+        assert_eq!(Primant::MAX.lerp_float(0.1f64, 0.7), 0.7);
+        assert_eq!(Primant::MAX.lerp_float(-1.5f32, 3.0), 3.0);
+        assert!(Primant::from_ratio(1u32, 2u32).lerp_float(-2.0f64, 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_division() {
+        let (quarter, half) = (Primant::from_ratio(1u32, 4u32), Primant::from_ratio(1u32, 2u32));
+        assert_eq!(half.div_saturating(Primant::MAX), half);
+        assert_eq!(half.div_saturating(half), Primant::MAX);
+        assert_eq!(Primant::MAX.div_saturating(quarter), Primant::MAX);
+        assert_eq!(Primant::ZERO.div_saturating(Primant::from_raw(1)), Primant::ZERO);
+        assert_eq!(Primant::from_raw(1).div_saturating(Primant::from_raw(3)), Primant::from_raw(u32::MAX / 3));
+        assert_eq!(quarter.checked_div(half), Some(Primant::ratio_of(quarter.to_raw() as u64, half.to_raw() as u64)));
+        assert_eq!(half.checked_div(quarter), None);
+        assert_eq!(Primant::ZERO.checked_div(Primant::ZERO), None);
+        let mut seed = 3u32;
+        for _ in 0..1000 {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let (a, b) = (Primant::from_raw(seed >> 1), Primant::from_raw(seed | 1 << 31));
+            // Dividing a product by one factor recovers the other to within rounding.
+            assert!((a * b).div_saturating(b).to_raw().abs_diff(a.to_raw()) <= 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_division_by_zero() {
+        Primant::MAX.div_saturating(Primant::ZERO);
+    }
+
+    #[test]
+    fn test_clamp() {
+        const CLAMPED: Primant = Primant::MAX.clamp(Primant::ZERO, Primant::from_raw(10));
+        assert_eq!(CLAMPED, Primant::from_raw(10));
+        let range = PrimantRange::from(Primant::from_raw(20)..=Primant::from_raw(10));
+        assert_eq!(range, PrimantRange::new(Primant::from_raw(10), Primant::from_raw(20)));
+        assert_eq!(Primant::ZERO.clamp_to(range), Primant::from_raw(10));
+        assert_eq!(Primant::from_raw(15).clamp_to(range), Primant::from_raw(15));
+        assert_eq!(Primant::MAX.clamp_to(PrimantRange::FULL), Primant::MAX);
+    }
+
+    #[test]
+    fn test_selection() {
+        let values = [Primant::from_raw(5), Primant::ZERO, Primant::MAX, Primant::ZERO];
+        assert_eq!(Primant::argmin(&values), Some(1));
+        assert_eq!(Primant::argmax(&values), Some(2));
+        assert_eq!(Primant::min_of(&values), Some(Primant::ZERO));
+        assert_eq!(Primant::max_of(&values), Some(Primant::MAX));
+        assert_eq!(Primant::min_of(&[]), None);
+    }
+
+    #[test]
+    fn test_within() {
+        let tolerance = Primant::from_raw(10);
+        assert!(Primant::from_raw(10).within(Primant::ZERO, tolerance));
+        assert!(!Primant::from_raw(11).within(Primant::ZERO, tolerance));
+        assert!(Primant::MAX.within(Primant::ZERO, Primant::MAX));
+        assert_eq!(Primant::from_raw(3).abs_diff(Primant::from_raw(10)), Primant::from_raw(7));
+        assert_eq!(Primant::MAX.abs_diff(Primant::ZERO), Primant::MAX);
+        assert_eq!(Primant::ZERO.abs_diff(Primant::MAX), Primant::MAX);
+    }
+
+    #[test]
+    fn test_quantize() {
+        let modes = [Rounding::Down, Rounding::Up, Rounding::HalfUp, Rounding::HalfDown, Rounding::HalfEven];
+        for steps in [1, 2, 3, 7, 10, 255, 1 << 20, u32::MAX] {
+            for mode in modes {
+                let mut previous = Primant::ZERO;
+                for raw in (0..=u32::MAX).step_by(999_983).chain([u32::MAX - 1, u32::MAX]) {
+                    let snapped = Primant(raw).quantize(steps, mode);
+                    assert!(snapped >= previous);
+                    assert_eq!(snapped.quantize(steps, mode), snapped);
+                    previous = snapped;
+                }
+            }
+        }
+        let third = Primant::from_ratio(1u32, 3u32);
+        assert_eq!(third.quantize(1, Rounding::Up), Primant::MAX);
+        assert_eq!(third.quantize(1, Rounding::HalfUp), Primant::ZERO);
+        // Exactly halfway between the grid values of one half and one.
+        let tie = Primant::from_raw(3_221_225_471);
+        assert_eq!(tie.quantize(2, Rounding::HalfUp), Primant::MAX);
+        assert_eq!(tie.quantize(2, Rounding::HalfDown), Primant::from_ratio(1u32, 2u32));
+        assert_eq!(tie.quantize(2, Rounding::HalfEven), Primant::MAX);
+        assert_eq!(Primant::from_ratio(1u32, 2u32).quantize(2, Rounding::Down), Primant::from_ratio(1u32, 2u32));
+    }
+
+    #[test]
+    fn test_round_to_decimals() {
+        // 0.13 * u32::MAX is 558345748.35.
+        assert_eq!(Primant::ratio_of(126, 1000).round_to_decimals(2), Primant::from_raw(558_345_748));
+        assert_eq!(Primant::ratio_of(124, 1000).round_to_decimals(2), Primant::ratio_of(12, 100).round_to_decimals(2));
+        assert_eq!(Primant::from_raw(u32::MAX / 2).round_to_decimals(0), Primant::ZERO);
+        assert_eq!(Primant::from_raw(u32::MAX / 2 + 1).round_to_decimals(0), Primant::MAX);
+        assert_eq!(Primant::MAX.round_to_decimals(3), Primant::MAX);
+        for raw in (0..=u32::MAX).step_by(7_777_777) {
+            let value = Primant(raw);
+            assert_eq!(value.round_to_decimals(19), value);
+            let rounded = value.round_to_percent_decimals(1);
+            assert_eq!(rounded.round_to_percent_decimals(1), rounded);
+            #[cfg(feature = "float")]
+            {
+                let tenths = rounded.to_percentage::<f64>() * 10.0;
+                assert!((tenths - tenths.round()).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parts_with_rounding() {
+        // Around 2.5 basis points, every nearest mode must agree.
+        let below = Primant::from_raw(1_073_741);
+        let above = Primant::from_raw(1_073_742);
+        for mode in [Rounding::HalfUp, Rounding::HalfDown, Rounding::HalfEven] {
+            assert_eq!(below.to_basis_points_with(mode), 2);
+            assert_eq!(above.to_basis_points_with(mode), 3);
+        }
+        assert_eq!(below.to_basis_points_with(Rounding::Up), 3);
+        assert_eq!(above.to_basis_points_with(Rounding::Down), 2);
+        assert_eq!(Primant::MAX.to_permille_with(Rounding::Up), 1000);
+        assert_eq!(Primant::ZERO.to_permyriad_with(Rounding::Up), 0);
+        assert_eq!(Primant::from_ratio(1u32, 4u32).to_permille_with(Rounding::Down), 249);
+        assert_eq!(Primant::from_ratio(1u32, 4u32).to_permille_with(Rounding::HalfDown), 250);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_normalize() {
+        assert_eq!(Primant::normalize(&[Primant::ZERO, Primant::ZERO]), None);
+        assert_eq!(Primant::normalize(&[Primant::ZERO, Primant::from_raw(5)]).unwrap(), [Primant::ZERO, Primant::MAX]);
+        // 2^32 - 1 split in two leaves one raw step for the first share.
+        let shares = Primant::normalize(&[Primant::from_raw(7), Primant::from_raw(7)]).unwrap();
+        assert_eq!(shares, [Primant::from_raw(u32::MAX / 2 + 1), Primant::from_raw(u32::MAX / 2)]);
+        let shares = Primant::normalize(&[Primant::from_raw(1); 7]).unwrap();
+        assert_eq!(shares.iter().map(|share| share.to_raw() as u64).sum::<u64>(), u32::MAX as u64);
+        assert!(shares.iter().all(|share| share.to_raw().abs_diff(u32::MAX / 7) <= 1));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_cumulative_sums() {
+        let values = [Primant::from_raw(1), Primant::from_raw(2), Primant::MAX];
+        assert_eq!(Primant::cumulative_sums(&values), [Primant::from_raw(1), Primant::from_raw(3), Primant::MAX]);
+        assert!(Primant::cumulative_sums(&[]).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_percentiles() {
+        assert_eq!(Primant::percentiles(&[], &[Primant::ZERO]), None);
+        let values = [Primant::from_raw(40), Primant::from_raw(0), Primant::from_raw(20), Primant::from_raw(10)];
+        let ranks = [Primant::ZERO, Primant::from_ratio(1u32, 3u32), Primant::from_ratio(1u32, 2u32), Primant::MAX];
+        let expected = [Primant::from_raw(0), Primant::from_raw(10), Primant::from_raw(15), Primant::from_raw(40)];
+        assert_eq!(Primant::percentiles(&values, &ranks).unwrap(), expected);
+        assert_eq!(Primant::percentiles(&[Primant::from_raw(9)], &ranks).unwrap(), [Primant::from_raw(9); 4]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "float"))]
+    fn test_f32_slices() {
+        assert_eq!(Primant::try_from_f32_slice(&[0.0, 1.0]).unwrap(), [Primant::ZERO, Primant::MAX]);
+        assert_eq!(Primant::try_from_f32_slice(&[0.5, 1.5]), None);
+        assert_eq!(Primant::try_from_f32_slice(&[f32::NAN]), None);
+        assert_eq!(Primant::from_f32_slice_saturating(&[-1.0, 2.0, f32::NAN]), [Primant::ZERO, Primant::MAX, Primant::ZERO]);
+        assert_eq!(Primant::to_f32_vec(&[Primant::ZERO, Primant::MAX]), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_ratio_of_paths() {
+        const HALF: Primant = Primant::ratio_of(1, 2);
+        assert_eq!(HALF, Primant::from_raw(u32::MAX / 2));
+        // The 32-bit and the 128-bit paths agree around their boundary.
+        let whole = u32::MAX as u64;
+        for part in [0, 1, whole / 3, whole - 1, whole] {
+            let wide = (part as u128 * 2 * u32::MAX as u128 / (2 * whole as u128)) as u32;
+            assert_eq!(Primant::ratio_of(part, whole).to_raw(), wide);
+            assert_eq!(Primant::ratio_of(part * 2, whole * 2).to_raw(), wide);
+        }
+        assert_eq!(Primant::ratio_of(u64::MAX - 1, u64::MAX), Primant::from_raw(u32::MAX - 1));
+    }
+
+    #[test]
+    fn test_from_str() {
+        fn convert<T: for<'a> TryFrom<&'a str>>(text: &str) -> Option<T> {
+            T::try_from(text).ok()
+        }
+
+        assert_eq!("50%".parse::<Primant>(), Primant::parse("0.5"));
+        assert_eq!(convert::<Primant>("1"), Some(Primant::MAX));
+        assert_eq!(Primant::try_from("2"), Err(ParsePrimantError::OutOfRange));
+    }
+
+    #[test]
+    fn test_from_durations() {
+        let total = Duration::MAX;
+        assert_eq!(Primant::from_durations(total, total), Primant::MAX);
+        assert_eq!(Primant::from_durations(Duration::ZERO, total), Primant::ZERO);
+        assert_eq!(Primant::from_durations(total / 2, total), Primant::from_raw(u32::MAX / 2));
+        let second = Duration::from_secs(1);
+        assert_eq!(Primant::from_durations(Duration::from_nanos(1), second), Primant::from_raw(4));
+        assert_eq!(Primant::try_from_durations(second, Duration::ZERO), None);
+        assert_eq!(Primant::try_from_durations(second * 2, second), None);
+        assert_eq!(Primant::from_durations_saturating(second * 2, second), Primant::MAX);
     }
 }
@@ -0,0 +1,270 @@
+use core::fmt::Debug;
+use core::ops::Mul;
+use crate::{Lerp, Primant};
+
+/// The ratio between the raw representations of a [`Primant128`] and a
+/// [`Primant`] of the same value. `u32::MAX` divides `u128::MAX` exactly.
+const WIDEN: u128 = u128::MAX / u32::MAX as u128;
+
+/// A fraction between 0 and 1 (inclusive), with 128 bits of precision.
+///
+/// A [`Primant128`] is meant for values that are multiplied many times over, such
+/// as compound interest or decay over millions of steps, where the rounding error
+/// of a [`Primant`] would add up. Convert to a [`Primant`] once the computation is
+/// done.
+///
+/// # Representation
+///
+/// A [`Primant128`] is represented as a 128-bit unsigned integer.
+/// The value `0` represents `0.0`, and the maximum value represents `1.0`.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, Primant128};
+/// let keep = Primant128::from_ratio(999_999, 1_000_000);
+/// let left = keep.pow(1_000_000).to_primant();
+/// // About 1/e.
+/// let tolerance = Primant::from_ratio(1u32, 1_000_000u32);
+/// assert!(left.within(Primant::from_ratio(367_879u32, 1_000_000u32), tolerance));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Primant128(u128);
+
+/// Raw conversion functions.
+///
+/// These functions should never panic, as every [`Primant128`] is a valid value.
+impl Primant128 {
+    pub const MIN: Primant128 = Primant128(0);
+    pub const ZERO: Primant128 = Primant128(0);
+    pub const MAX: Primant128 = Primant128(u128::MAX);
+
+    /// Creates a new [`Primant128`] from a raw representation.
+    pub const fn from_raw(value: u128) -> Self {
+        Primant128(value)
+    }
+
+    /// Returns the raw representation of the [`Primant128`].
+    pub const fn to_raw(self) -> u128 {
+        self.0
+    }
+}
+
+/// Conversion functions to and from integer ratios.
+impl Primant128 {
+    /// Creates a new [`Primant128`] from a numerator and a denominator, rounding
+    /// down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero or if the result would not fit in a
+    /// `Primant128`.
+    pub const fn from_ratio(numerator: u64, denominator: u64) -> Self {
+        assert!(denominator != 0, "denominator must not be zero");
+        assert!(numerator <= denominator, "ratio must not be greater than 1");
+        let (numerator, denominator) = (numerator as u128, denominator as u128);
+        // Both factors of the second term are below 2^64, so it cannot overflow.
+        let (step, rest) = (u128::MAX / denominator, u128::MAX % denominator);
+        Primant128(numerator * step + numerator * rest / denominator)
+    }
+}
+
+/// Conversion functions to the smaller widths.
+impl Primant128 {
+    /// Converts a [`Primant`] to a [`Primant128`]. This conversion is exact.
+    pub const fn from_primant(value: Primant) -> Self {
+        Primant128(value.to_raw() as u128 * WIDEN)
+    }
+
+    /// Returns the nearest [`Primant`].
+    pub const fn to_primant(self) -> Primant {
+        let (quotient, remainder) = (self.0 / WIDEN, self.0 % WIDEN);
+        // `WIDEN` is odd, so there are no ties.
+        Primant::from_raw((quotient + (remainder > WIDEN / 2) as u128) as u32)
+    }
+}
+
+/// Arithmetic functions.
+impl Primant128 {
+    /// Multiplies two [`Primant128`]s, rounding to the nearest value.
+    ///
+    /// This is the same as the [`Mul`] operator, but usable in `const` contexts.
+    pub const fn mul(self, other: Primant128) -> Self {
+        Primant128(scale(self.0, other.0))
+    }
+
+    /// Multiplies the [`Primant128`] by itself `exponent` times, by repeated
+    /// squaring.
+    ///
+    /// Every multiplication is rounded, so the result may be off by a few raw
+    /// steps, which is still far below the precision of a [`Primant`].
+    pub const fn pow(self, mut exponent: u32) -> Self {
+        let mut base = self;
+        let mut result = Primant128::MAX;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(base);
+            }
+        }
+        result
+    }
+
+    /// Blends `self` towards `other` by `t`, rounding to the nearest value.
+    ///
+    /// Returns `self` when `t` is [`Primant128::ZERO`] and `other` when `t` is
+    /// [`Primant128::MAX`].
+    pub const fn blend(self, other: Primant128, t: Primant128) -> Self {
+        if self.0 <= other.0 {
+            Primant128(self.0 + scale(other.0 - self.0, t.0))
+        } else {
+            Primant128(self.0 - scale(self.0 - other.0, t.0))
+        }
+    }
+}
+
+/// Returns `value * t / u128::MAX`, rounded to the nearest integer.
+const fn scale(value: u128, t: u128) -> u128 {
+    let (high, low) = widening_mul(value, t);
+    // With `2^128 = u128::MAX + 1`, the product is `high * u128::MAX + high + low`.
+    let (sum, carry) = high.overflowing_add(low);
+    let mut quotient = high;
+    let mut remainder = sum;
+    if carry {
+        // The sum is `2^128 + sum`, that is `u128::MAX + sum + 1`.
+        quotient += 1;
+        remainder = sum + 1;
+    }
+    if remainder == u128::MAX {
+        quotient += 1;
+        remainder = 0;
+    }
+    // `u128::MAX` is odd, so there are no ties.
+    quotient + (remainder > u128::MAX / 2) as u128
+}
+
+/// Returns the full 256-bit product of two `u128`s, as its high and low halves.
+const fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let low = (low_low & MASK) | (middle << 64);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+impl Default for Primant128 {
+    fn default() -> Self {
+        Primant128::ZERO
+    }
+}
+
+impl From<Primant> for Primant128 {
+    fn from(value: Primant) -> Self {
+        Primant128::from_primant(value)
+    }
+}
+
+impl From<Primant128> for Primant {
+    fn from(value: Primant128) -> Self {
+        value.to_primant()
+    }
+}
+
+impl Mul for Primant128 {
+    type Output = Primant128;
+
+    fn mul(self, other: Primant128) -> Self::Output {
+        Primant128::mul(self, other)
+    }
+}
+
+impl Lerp for Primant128 {
+    fn lerp(self, other: Self, t: Primant) -> Self {
+        self.blend(other, Primant128::from_primant(t))
+    }
+}
+
+impl Debug for Primant128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Primant128(")?;
+        #[cfg(feature = "float")]
+        write!(f, "{}", self.0 as f64 / u128::MAX as f64)?;
+        #[cfg(not(feature = "float"))]
+        crate::format::write_ratio(f, self.to_primant().to_raw() as u128, u32::MAX as u128, 1, 10, "", 0)?;
+        if f.alternate() {
+            write!(f, " ≈ raw {:#034x}", self.0)?;
+        }
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primant_round_trip() {
+        for raw in [0, 1, 2, 12345, u32::MAX / 2, u32::MAX - 1, u32::MAX] {
+            let value = Primant::from_raw(raw);
+            assert_eq!(Primant128::from(value).to_primant(), value);
+        }
+        assert_eq!(Primant128::from(Primant::MAX), Primant128::MAX);
+        assert_eq!(Primant128::from_raw(WIDEN / 2).to_primant(), Primant::ZERO);
+        assert_eq!(Primant128::from_raw(WIDEN / 2 + 1).to_primant(), Primant::from_raw(1));
+    }
+
+    #[test]
+    fn test_from_ratio() {
+        assert_eq!(Primant128::from_ratio(0, 5), Primant128::ZERO);
+        assert_eq!(Primant128::from_ratio(5, 5), Primant128::MAX);
+        assert_eq!(Primant128::from_ratio(1, 3), Primant128::from_raw(u128::MAX / 3));
+        assert_eq!(Primant128::from_ratio(u64::MAX - 1, u64::MAX), Primant128::from_raw(u128::MAX - (u64::MAX as u128 + 2)));
+        assert_eq!(Primant128::from_ratio(1, 4).to_primant(), Primant::from_raw(u32::MAX / 4 + 1));
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(Primant128::MAX * Primant128::MAX, Primant128::MAX);
+        assert_eq!(Primant128::MAX * Primant128::from_raw(7), Primant128::from_raw(7));
+        assert_eq!(Primant128::ZERO * Primant128::MAX, Primant128::ZERO);
+        assert_eq!(Primant128::from_raw(u128::MAX - 1).mul(Primant128::from_raw(u128::MAX - 1)), Primant128::from_raw(u128::MAX - 2));
+
+        // Widened products round to the same value as the exact product.
+        let max = u32::MAX as u64;
+        for (a, b) in [(3u32, 5u32), (u32::MAX / 3, u32::MAX / 7), (123_456_789, 987_654_321), (u32::MAX, 1)] {
+            let product = Primant128::from(Primant::from_raw(a)) * Primant128::from(Primant::from_raw(b));
+            let expected = (2 * a as u64 * b as u64 + max) / (2 * max);
+            assert_eq!(product.to_primant().to_raw() as u64, expected);
+        }
+    }
+
+    #[test]
+    fn test_pow() {
+        let half = Primant128::from_raw(u128::MAX / 2 + 1);
+        assert_eq!(half.pow(0), Primant128::MAX);
+        assert_eq!(half.pow(1), half);
+        assert_eq!(half.pow(3).to_primant(), Primant::from_raw(u32::MAX / 8 + 1));
+        assert_eq!(Primant128::MAX.pow(u32::MAX), Primant128::MAX);
+    }
+
+    #[test]
+    fn test_blend() {
+        let quarter = Primant128::from(Primant::from_ratio(1u32, 4u32));
+        let half = Primant128::from_raw(u128::MAX / 2 + 1);
+        assert_eq!(quarter.blend(Primant128::MAX, Primant128::ZERO), quarter);
+        assert_eq!(quarter.blend(Primant128::MAX, Primant128::MAX), Primant128::MAX);
+        assert_eq!(Primant128::MAX.blend(Primant128::ZERO, half).to_primant(), Primant::from_raw(u32::MAX / 2));
+        assert_eq!(Primant128::ZERO.lerp(Primant128::MAX, Primant::MAX), Primant128::MAX);
+    }
+}
@@ -0,0 +1,29 @@
+/// A minimal xorshift pseudo-random generator, used where the crate needs noise
+/// without depending on an external random number generator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Xorshift32(u32);
+
+impl Xorshift32 {
+    const DEFAULT_SEED: u32 = 0x9E37_79B9;
+
+    /// Creates a new generator. A seed of zero is replaced with a non-zero default.
+    pub(crate) const fn new(seed: u32) -> Self {
+        Xorshift32(if seed == 0 { Self::DEFAULT_SEED } else { seed })
+    }
+
+    /// Returns the next pseudo-random value.
+    pub(crate) fn next_u32(&mut self) -> u32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        x
+    }
+}
+
+impl Default for Xorshift32 {
+    fn default() -> Self {
+        Xorshift32(Self::DEFAULT_SEED)
+    }
+}
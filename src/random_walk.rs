@@ -0,0 +1,143 @@
+use crate::random::Xorshift32;
+use crate::{Phase, Primant};
+
+/// A bounded random walk.
+///
+/// Every step, the value moves by a pseudo-random amount of at most the configured
+/// step size, in either direction. How the bounds are handled depends on the type
+/// of the value:
+/// - a walk on [`Primant`] reflects at `0.0` and `1.0`, bouncing back into range;
+/// - a walk on [`Phase`] wraps around the end of the cycle.
+///
+/// This is useful for organic-looking parameter drift. The walk is an infinite
+/// [`Iterator`] over its values.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, RandomWalk};
+/// let start = Primant::from_ratio(1u32, 2u32);
+/// let step = Primant::from_ratio(1u32, 100u32);
+/// let mut previous = start;
+/// for value in RandomWalk::new(start, step).take(100) {
+///     assert!(value.to_raw().abs_diff(previous.to_raw()) <= step.to_raw());
+///     previous = value;
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RandomWalk<T> {
+    value: T,
+    step: Primant,
+    random: Xorshift32,
+}
+
+impl<T: Copy> RandomWalk<T> {
+    /// Creates a new [`RandomWalk`] starting at `start`, moving by at most `step`
+    /// every step.
+    ///
+    /// For a walk on [`Phase`], the step is measured as a fraction of a full turn.
+    pub fn new(start: T, step: Primant) -> Self {
+        RandomWalk {
+            value: start,
+            step,
+            random: Xorshift32::default(),
+        }
+    }
+
+    /// Sets the seed of the pseudo-random generator.
+    ///
+    /// A seed of zero is replaced with a non-zero default.
+    pub fn with_seed(self, seed: u32) -> Self {
+        RandomWalk { random: Xorshift32::new(seed), ..self }
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the maximum step size.
+    pub fn step(&self) -> Primant {
+        self.step
+    }
+
+    /// Sets the maximum step size.
+    pub fn set_step(&mut self, step: Primant) {
+        self.step = step;
+    }
+
+    /// Draws the next random displacement, returning its raw magnitude and whether
+    /// it is positive.
+    fn displacement(&mut self) -> (u32, bool) {
+        let random = self.random.next_u32();
+        let magnitude = (self.step.to_raw() as u64 * (random >> 1) as u64) >> 31;
+        (magnitude as u32, random & 1 == 1)
+    }
+}
+
+impl Iterator for RandomWalk<Primant> {
+    type Item = Primant;
+
+    fn next(&mut self) -> Option<Primant> {
+        let (magnitude, positive) = self.displacement();
+        let value = self.value.to_raw();
+        let value = if positive {
+            match value.checked_add(magnitude) {
+                Some(value) => value,
+                None => u32::MAX - (magnitude - (u32::MAX - value)),
+            }
+        } else {
+            match value.checked_sub(magnitude) {
+                Some(value) => value,
+                None => magnitude - value,
+            }
+        };
+        self.value = Primant::from_raw(value);
+        Some(self.value)
+    }
+}
+
+impl Iterator for RandomWalk<Phase> {
+    type Item = Phase;
+
+    fn next(&mut self) -> Option<Phase> {
+        let (magnitude, positive) = self.displacement();
+        let value = self.value.to_raw();
+        let value = if positive { value.wrapping_add(magnitude) } else { value.wrapping_sub(magnitude) };
+        self.value = Phase::from_raw(value);
+        Some(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reflecting() {
+        let step = Primant::from_ratio(1u32, 4u32);
+        let mut walk = RandomWalk::new(Primant::ZERO, step).with_seed(7);
+        let mut previous = walk.value();
+        for value in walk.by_ref().take(10_000) {
+            let distance = value.to_raw().abs_diff(previous.to_raw());
+            assert!(distance <= step.to_raw());
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let step = Primant::from_ratio(1u32, 4u32);
+        let walk = RandomWalk::new(Phase::MIN, step).with_seed(7);
+        let mut previous = walk.value();
+        let mut wrapped = false;
+        for value in walk.take(10_000) {
+            let distance = value.to_raw().wrapping_sub(previous.to_raw());
+            let distance = distance.min(distance.wrapping_neg());
+            assert!(distance <= step.to_raw());
+            wrapped |= value.to_raw().abs_diff(previous.to_raw()) > u32::MAX / 2;
+            previous = value;
+        }
+        assert!(wrapped);
+    }
+}
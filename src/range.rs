@@ -0,0 +1,61 @@
+use core::ops::RangeInclusive;
+use crate::Primant;
+
+/// An inclusive range of [`Primant`]s, such as the allowed range of a setting.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, PrimantRange};
+/// let range = PrimantRange::new(Primant::from_ratio(1u32, 4u32), Primant::from_ratio(3u32, 4u32));
+/// assert!(range.contains(Primant::from_ratio(1u32, 2u32)));
+/// assert_eq!(Primant::MAX.clamp_to(range), range.end());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrimantRange {
+    start: Primant,
+    end: Primant,
+}
+
+impl PrimantRange {
+    /// The range covering the whole unit interval.
+    pub const FULL: PrimantRange = PrimantRange::new(Primant::ZERO, Primant::MAX);
+
+    /// Creates a new [`PrimantRange`] from `start` to `end`, both inclusive.
+    ///
+    /// If `start` is greater than `end`, they are swapped.
+    pub const fn new(start: Primant, end: Primant) -> Self {
+        if start.to_raw() <= end.to_raw() {
+            PrimantRange { start, end }
+        } else {
+            PrimantRange { start: end, end: start }
+        }
+    }
+
+    /// Returns the lower bound of the range.
+    pub const fn start(&self) -> Primant {
+        self.start
+    }
+
+    /// Returns the upper bound of the range.
+    pub const fn end(&self) -> Primant {
+        self.end
+    }
+
+    /// Returns whether `value` lies within the range.
+    pub const fn contains(&self, value: Primant) -> bool {
+        self.start.to_raw() <= value.to_raw() && value.to_raw() <= self.end.to_raw()
+    }
+}
+
+impl From<RangeInclusive<Primant>> for PrimantRange {
+    fn from(range: RangeInclusive<Primant>) -> Self {
+        PrimantRange::new(*range.start(), *range.end())
+    }
+}
+
+impl From<PrimantRange> for RangeInclusive<Primant> {
+    fn from(range: PrimantRange) -> Self {
+        range.start..=range.end
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{Lerp, Primant};
+
+/// An estimator of an event rate, such as for utilization gauges or bandwidth
+/// displays.
+///
+/// A [`RateEstimator`] counts events in consecutive periods of a fixed number of
+/// ticks. At the end of every period, the count is expressed as a fraction of a
+/// maximum rate, and the estimate moves towards it by a smoothing factor, as an
+/// exponentially weighted moving average. Periods without any event pull the
+/// estimate towards zero.
+///
+/// Time is given as a monotonic tick count with every call, so the estimator works
+/// with any clock.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, RateEstimator};
+/// // Up to 100 events per 1000 ticks, without smoothing.
+/// let mut estimator = RateEstimator::new(1000, 100, Primant::MAX);
+/// for tick in (0..1000).step_by(40) {
+///     estimator.record(tick);
+/// }
+/// estimator.update(1000);
+/// assert_eq!(estimator.events_per_period(), 25);
+/// assert_eq!(estimator.rate(), Primant::from_ratio(1u32, 4u32));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateEstimator {
+    period_ticks: u64,
+    max_rate: u64,
+    smoothing: Primant,
+    period_start: Option<u64>,
+    count: u64,
+    rate: Primant,
+}
+
+impl RateEstimator {
+    /// Creates a new [`RateEstimator`] with periods of `period_ticks`, mapping
+    /// `max_rate` events per period to [`Primant::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period_ticks` or `max_rate` is zero.
+    pub fn new(period_ticks: u64, max_rate: u64, smoothing: Primant) -> Self {
+        assert_ne!(period_ticks, 0, "period must not be zero");
+        assert_ne!(max_rate, 0, "maximum rate must not be zero");
+        RateEstimator {
+            period_ticks,
+            max_rate,
+            smoothing,
+            period_start: None,
+            count: 0,
+            rate: Primant::ZERO,
+        }
+    }
+
+    /// Returns the estimated rate, as a fraction of the maximum rate.
+    pub fn rate(&self) -> Primant {
+        self.rate
+    }
+
+    /// Returns the estimated number of events per period, rounded to the nearest
+    /// integer.
+    pub fn events_per_period(&self) -> u64 {
+        self.rate.percent_of(self.max_rate)
+    }
+
+    /// Records an event at `now_ticks`.
+    pub fn record(&mut self, now_ticks: u64) {
+        self.record_many(now_ticks, 1);
+    }
+
+    /// Records `count` events at `now_ticks`, such as a number of bytes received.
+    pub fn record_many(&mut self, now_ticks: u64, count: u64) {
+        self.update(now_ticks);
+        self.count = self.count.saturating_add(count);
+    }
+
+    /// Closes every period that ended by `now_ticks`, updating the estimate.
+    ///
+    /// The first call starts the first period.
+    pub fn update(&mut self, now_ticks: u64) {
+        let Some(start) = self.period_start else {
+            self.period_start = Some(now_ticks);
+            return;
+        };
+
+        let periods = now_ticks.saturating_sub(start) / self.period_ticks;
+        if periods == 0 { return; }
+        self.period_start = Some(start + periods * self.period_ticks);
+
+        let sample = Primant::ratio_of_saturating(self.count, self.max_rate);
+        self.count = 0;
+        self.rate = self.rate.lerp(sample, self.smoothing);
+
+        // The remaining periods were empty, each keeping the complement of the
+        // smoothing factor. Rounding down makes sure the estimate decays all the way
+        // to zero.
+        let keep = decay(self.smoothing.complement(), periods - 1);
+        self.rate = Primant::from_raw(keep.scale_u128(self.rate.to_raw() as u128, false) as u32);
+    }
+
+    /// Forgets every event and resets the estimate to zero.
+    pub fn reset(&mut self) {
+        self.period_start = None;
+        self.count = 0;
+        self.rate = Primant::ZERO;
+    }
+}
+
+/// Raises `keep` to the power of `periods` by repeated squaring, in 62-bit fixed
+/// point, rounding down.
+fn decay(keep: Primant, mut periods: u64) -> Primant {
+    let mut base = ((keep.to_raw() as u128) << 62) / u32::MAX as u128;
+    let mut power = 1u128 << 62;
+    while periods > 0 && power > 0 {
+        if periods & 1 == 1 { power = (power * base) >> 62; }
+        base = (base * base) >> 62;
+        periods >>= 1;
+    }
+    Primant::from_raw(((power * u32::MAX as u128) >> 62) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smoothing() {
+        let mut estimator = RateEstimator::new(10, 4, Primant::from_ratio(1u32, 2u32));
+        estimator.update(5);
+        estimator.record_many(6, 4);
+        estimator.update(15);
+        assert_eq!(estimator.events_per_period(), 2);
+        estimator.record_many(16, 4);
+        estimator.update(25);
+        assert_eq!(estimator.events_per_period(), 3);
+        // A long idle period decays the estimate to zero without looping forever.
+        estimator.update(u64::MAX);
+        assert_eq!(estimator.rate(), Primant::ZERO);
+    }
+
+    #[test]
+    fn test_long_idle_gap() {
+        // The smallest smoothing factor decays by about a single raw step per period.
+        let mut estimator = RateEstimator::new(1, 1, Primant::from_raw(1));
+        estimator.rate = Primant::MAX;
+        estimator.update(0);
+        // About 1/e after as many empty periods as there are raw steps.
+        estimator.update(1 << 32);
+        let tolerance = Primant::from_ratio(1u32, 1_000_000u32);
+        assert!(estimator.rate().within(Primant::from_ratio(367_879u32, 1_000_000u32), tolerance));
+        estimator.update(u64::MAX);
+        assert_eq!(estimator.rate(), Primant::ZERO);
+    }
+
+    #[test]
+    fn test_saturation() {
+        let mut estimator = RateEstimator::new(1, 10, Primant::MAX);
+        estimator.record_many(0, 1000);
+        estimator.update(1);
+        assert_eq!(estimator.rate(), Primant::MAX);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::Primant;
+
+/// A budget limiting one kind of event to a fraction of another, such as retries
+/// to a fraction of requests.
+///
+/// Every recorded request deposits the ratio into the budget, as raw [`Primant`]
+/// units, and every allowed retry withdraws a whole unit. The accounting is done
+/// with integers only, so it does not drift: with a ratio of one tenth, a retry is
+/// allowed for every ten requests, and the error stays below one retry in four
+/// billion requests.
+///
+/// To keep a long period without retries from allowing a storm of them later,
+/// the budget holds at most `burst` retries.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, RatioBudget};
+/// let mut budget = RatioBudget::new(Primant::from_ratio(1u32, 10u32), 5);
+/// assert!(!budget.allow());
+/// for _ in 0..10 {
+///     budget.record();
+/// }
+/// assert!(budget.allow());
+/// assert!(!budget.allow());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RatioBudget {
+    ratio: Primant,
+    burst: u32,
+    balance: u64,
+}
+
+impl RatioBudget {
+    /// Creates a new, empty [`RatioBudget`] allowing `ratio` retries per request,
+    /// and holding at most `burst` retries.
+    pub fn new(ratio: Primant, burst: u32) -> Self {
+        RatioBudget {
+            ratio,
+            burst,
+            balance: 0,
+        }
+    }
+
+    /// Returns the ratio of retries to requests.
+    pub fn ratio(&self) -> Primant {
+        self.ratio
+    }
+
+    /// Returns the largest number of retries the budget can hold.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Returns the number of retries currently allowed.
+    pub fn available(&self) -> u32 {
+        (self.balance / u32::MAX as u64) as u32
+    }
+
+    /// Records a request, depositing the ratio into the budget.
+    pub fn record(&mut self) {
+        if self.ratio == Primant::ZERO { return; }
+        // A raw Primant is rounded down, so a unit is added to make sure the
+        // deposits never fall behind the exact ratio.
+        let deposit = self.ratio.to_raw() as u64 + 1;
+        let limit = self.burst as u64 * u32::MAX as u64;
+        self.balance = (self.balance + deposit).min(limit);
+    }
+
+    /// Withdraws a retry from the budget, returning whether it was allowed.
+    pub fn allow(&mut self) -> bool {
+        match self.balance.checked_sub(u32::MAX as u64) {
+            Some(balance) => {
+                self.balance = balance;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_ratio() {
+        let mut budget = RatioBudget::new(Primant::from_ratio(1u32, 3u32), u32::MAX);
+        let mut allowed = 0;
+        for _ in 0..3_000_000 {
+            budget.record();
+            if budget.allow() { allowed += 1; }
+        }
+        assert_eq!(allowed, 1_000_000);
+    }
+
+    #[test]
+    fn test_burst() {
+        let mut budget = RatioBudget::new(Primant::MAX, 2);
+        for _ in 0..10 {
+            budget.record();
+        }
+        assert_eq!(budget.available(), 2);
+        assert!(budget.allow() && budget.allow() && !budget.allow());
+        let mut never = RatioBudget::new(Primant::ZERO, 2);
+        never.record();
+        assert!(!never.allow());
+    }
+}
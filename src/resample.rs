@@ -0,0 +1,151 @@
+use core::iter::FusedIterator;
+use crate::{FracIndex, Primant};
+
+/// The read position of a sample-rate converter.
+///
+/// A [`ResamplePos`] tracks where each output sample falls between the input
+/// samples, advancing by the ratio of the input rate to the output rate every
+/// output sample. The fractional part is kept as an exact numerator over the
+/// output rate, and only rounded when a position is read, so the position does not
+/// drift, however long the stream runs.
+///
+/// As an iterator, it yields the position of every output sample, starting at
+/// zero, to be read with [`FracIndex::interpolate`].
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{FracIndex, Primant, ResamplePos};
+/// // From 48 kHz down to 32 kHz.
+/// let positions: Vec<_> = ResamplePos::new(48_000, 32_000).take(3).collect();
+/// assert_eq!(positions, [
+///     FracIndex::new(0, Primant::ZERO),
+///     FracIndex::new(1, Primant::from_ratio(1u32, 2u32)),
+///     FracIndex::new(3, Primant::ZERO),
+/// ]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResamplePos {
+    index: usize,
+    /// The fractional part of the position, over `output_rate`.
+    numerator: u32,
+    step_index: usize,
+    /// The fractional part of the step, over `output_rate`.
+    step_numerator: u32,
+    output_rate: u32,
+}
+
+impl ResamplePos {
+    /// Creates a new [`ResamplePos`] at the first input sample, converting from
+    /// `input_rate` to `output_rate`.
+    ///
+    /// Both rates must be given in the same unit. Reducing them first is not
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either rate is zero.
+    pub fn new(input_rate: u32, output_rate: u32) -> Self {
+        assert_ne!(input_rate, 0, "input rate must not be zero");
+        assert_ne!(output_rate, 0, "output rate must not be zero");
+        ResamplePos {
+            index: 0,
+            numerator: 0,
+            step_index: (input_rate / output_rate) as usize,
+            step_numerator: input_rate % output_rate,
+            output_rate,
+        }
+    }
+
+    /// Returns the current read position, with the fraction rounded down.
+    pub fn read_position(&self) -> FracIndex {
+        FracIndex::new(self.index, self.fraction())
+    }
+
+    /// Returns the index of the input sample before the current read position.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the fraction of the way from the current index to the next input
+    /// sample, rounded down.
+    pub fn fraction(&self) -> Primant {
+        Primant::ratio_of(self.numerator as u64, self.output_rate as u64)
+    }
+
+    /// Returns the distance between two output samples, in input samples, with
+    /// the fraction rounded down.
+    pub fn step(&self) -> FracIndex {
+        FracIndex::new(self.step_index, Primant::ratio_of(self.step_numerator as u64, self.output_rate as u64))
+    }
+
+    /// Advances the position by one output sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index overflows. Use [`ResamplePos::consume`] to keep it
+    /// small in long streams.
+    pub fn advance(&mut self) {
+        let numerator = self.numerator as u64 + self.step_numerator as u64;
+        let carry = numerator >= self.output_rate as u64;
+        self.numerator = if carry { numerator - self.output_rate as u64 } else { numerator } as u32;
+        self.index = self.index
+            .checked_add(self.step_index + carry as usize)
+            .expect("index must not overflow");
+    }
+
+    /// Moves the position back by `count` input samples, once they have been
+    /// dropped from the start of the input buffer. The fraction is kept exactly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than the current index.
+    pub fn consume(&mut self, count: usize) {
+        assert!(count <= self.index, "count must not be greater than the index");
+        self.index -= count;
+    }
+}
+
+impl Iterator for ResamplePos {
+    type Item = FracIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.read_position();
+        self.advance();
+        Some(position)
+    }
+}
+
+impl FusedIterator for ResamplePos {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_drift() {
+        // Ten minutes of 44.1 kHz to 48 kHz, in blocks.
+        let mut position = ResamplePos::new(44_100, 48_000);
+        let mut consumed = 0u64;
+        let outputs = 48_000 * 600;
+        for _ in 0..outputs / 480 {
+            for _ in 0..480 {
+                position.advance();
+            }
+            let index = position.index();
+            position.consume(index);
+            consumed += index as u64;
+        }
+        assert_eq!(consumed, outputs * 44_100 / 48_000);
+        assert_eq!(position.read_position(), FracIndex::new(0, Primant::ZERO));
+    }
+
+    #[test]
+    fn test_step() {
+        let mut position = ResamplePos::new(3, 2);
+        assert_eq!(position.step(), FracIndex::new(1, Primant::from_ratio(1u32, 2u32)));
+        position.nth(4);
+        assert_eq!(position.read_position(), FracIndex::new(7, Primant::from_ratio(1u32, 2u32)));
+        assert_eq!(ResamplePos::new(1, 3).nth(2), Some(FracIndex::new(0, Primant::from_ratio(2u32, 3u32))));
+    }
+}
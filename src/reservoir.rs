@@ -0,0 +1,133 @@
+use crate::gamma::log2;
+use crate::random::Xorshift32;
+use crate::Primant;
+
+/// A weighted reservoir sample of a stream, keeping at most `K` items without
+/// allocating.
+///
+/// Every item offered to a [`Reservoir`] carries a [`Primant`] weight, and the
+/// chance of an item ending up in the sample grows with its weight. Items with a
+/// weight of zero are never kept. This implements the A-Res algorithm of Efraimidis
+/// and Spirakis, with the keys `u^(1/w)` compared through their logarithms in
+/// fixed-point arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, Reservoir};
+/// let mut reservoir = Reservoir::<u32, 2>::new();
+/// for reading in 0..100 {
+///     let weight = if reading % 10 == 0 { Primant::MAX } else { Primant::ZERO };
+///     reservoir.offer(reading, weight);
+/// }
+/// assert_eq!(reservoir.len(), 2);
+/// assert!(reservoir.iter().all(|&reading| reading % 10 == 0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Reservoir<T, const K: usize> {
+    items: [Option<(u128, T)>; K],
+    random: Xorshift32,
+}
+
+impl<T, const K: usize> Reservoir<T, K> {
+    /// Creates a new, empty [`Reservoir`].
+    pub fn new() -> Self {
+        Reservoir {
+            items: core::array::from_fn(|_| None),
+            random: Xorshift32::default(),
+        }
+    }
+
+    /// Sets the seed of the pseudo-random generator.
+    ///
+    /// A seed of zero is replaced with a non-zero default.
+    pub fn with_seed(self, seed: u32) -> Self {
+        Reservoir { random: Xorshift32::new(seed), ..self }
+    }
+
+    /// Returns the number of items in the sample.
+    pub fn len(&self) -> usize {
+        self.items.iter().filter(|item| item.is_some()).count()
+    }
+
+    /// Returns whether the sample is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.iter().all(|item| item.is_none())
+    }
+
+    /// Returns an iterator over the items in the sample, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter().flatten().map(|(_, item)| item)
+    }
+
+    /// Removes every item from the sample.
+    pub fn clear(&mut self) {
+        self.items.iter_mut().for_each(|item| *item = None);
+    }
+
+    /// Offers an item with the given weight to the sample, returning whether it was
+    /// kept.
+    ///
+    /// If the sample is full, keeping the item evicts another one.
+    pub fn offer(&mut self, item: T, weight: Primant) -> bool {
+        if weight == Primant::ZERO { return false; }
+
+        // The smallest -log2(u) / w corresponds to the largest u^(1/w).
+        let random = self.random.next_u32().max(1);
+        let key = ((-log2(random)).max(0) as u128 * u32::MAX as u128) / weight.to_raw() as u128;
+
+        let slot = match self.items.iter().position(|item| item.is_none()) {
+            Some(index) => index,
+            None => {
+                let Some((index, largest)) = self.items.iter().enumerate()
+                    .filter_map(|(index, item)| item.as_ref().map(|(key, _)| (index, *key)))
+                    .max_by_key(|&(_, key)| key)
+                else { return false; };
+                if key >= largest { return false; }
+                index
+            }
+        };
+        self.items[slot] = Some((key, item));
+        true
+    }
+}
+
+impl<T, const K: usize> Default for Reservoir<T, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weights() {
+        let mut heavy = 0;
+        for seed in 1..=200 {
+            let mut reservoir = Reservoir::<bool, 1>::new().with_seed(seed);
+            for i in 0..20 {
+                let weight = if i == 7 { Primant::MAX } else { Primant::from_ratio(1u32, 19u32) };
+                reservoir.offer(i == 7, weight);
+            }
+            heavy += reservoir.iter().filter(|&&item| item).count();
+        }
+        // The heavy item carries half of the total weight.
+        assert!((70..=130).contains(&heavy), "{heavy}");
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut reservoir = Reservoir::<u8, 3>::new();
+        assert!(reservoir.is_empty());
+        for i in 0..10 {
+            reservoir.offer(i, Primant::MAX);
+        }
+        assert_eq!(reservoir.len(), 3);
+        assert!(!reservoir.offer(10, Primant::ZERO));
+        reservoir.clear();
+        assert!(reservoir.is_empty());
+        assert!(!Reservoir::<u8, 0>::new().offer(1, Primant::MAX));
+    }
+}
@@ -0,0 +1,224 @@
+use heapless::Deque;
+use crate::Primant;
+
+/// A mean over the last `N` values, such as for smoothing a telemetry reading.
+///
+/// A [`RollingMean`] keeps the values in a fixed-capacity ring buffer and a running
+/// sum, so it needs no allocator and every update takes constant time.
+///
+/// This type is only available with the `heapless` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, RollingMean};
+/// let mut load = RollingMean::<2>::new();
+/// load.push(Primant::MAX);
+/// load.push(Primant::ZERO);
+/// load.push(Primant::from_ratio(1u32, 2u32));
+/// // The first value has left the window.
+/// assert_eq!(load.mean(), Some(Primant::from_ratio(1u32, 4u32)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollingMean<const N: usize> {
+    values: Deque<Primant, N>,
+    sum: u64,
+}
+
+impl<const N: usize> RollingMean<N> {
+    /// Creates a new [`RollingMean`] with no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub const fn new() -> Self {
+        assert!(N > 0, "window must not be empty");
+        RollingMean {
+            values: Deque::new(),
+            sum: 0,
+        }
+    }
+
+    /// Returns the number of values in the window.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether no value has been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Pushes a value, returning the oldest one if the window was full.
+    pub fn push(&mut self, value: Primant) -> Option<Primant> {
+        let evicted = if self.values.is_full() { self.values.pop_front() } else { None };
+        if let Some(evicted) = evicted {
+            self.sum -= evicted.to_raw() as u64;
+        }
+        self.sum += value.to_raw() as u64;
+        let _ = self.values.push_back(value);
+        evicted
+    }
+
+    /// Returns the mean of the values in the window, rounded down, or `None` if it
+    /// is empty.
+    pub fn mean(&self) -> Option<Primant> {
+        if self.values.is_empty() { return None; }
+        Some(Primant::from_raw((self.sum / self.values.len() as u64) as u32))
+    }
+
+    /// Forgets every value.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.sum = 0;
+    }
+}
+
+impl<const N: usize> Default for RollingMean<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The minimum and maximum of the last `N` values, such as for a peak meter.
+///
+/// A [`RollingMinMax`] keeps the values in a fixed-capacity ring buffer, along with
+/// the candidates for the minimum and the maximum in monotonic queues, so it needs
+/// no allocator and every update takes amortized constant time.
+///
+/// This type is only available with the `heapless` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, RollingMinMax};
+/// let mut level = RollingMinMax::<2>::new();
+/// for raw in [5, 1, 3] {
+///     level.push(Primant::from_raw(raw));
+/// }
+/// assert_eq!(level.min(), Some(Primant::from_raw(1)));
+/// assert_eq!(level.max(), Some(Primant::from_raw(3)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollingMinMax<const N: usize> {
+    values: Deque<Primant, N>,
+    /// The values that may still become the minimum, in increasing order.
+    minima: Deque<Primant, N>,
+    /// The values that may still become the maximum, in decreasing order.
+    maxima: Deque<Primant, N>,
+}
+
+impl<const N: usize> RollingMinMax<N> {
+    /// Creates a new [`RollingMinMax`] with no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub const fn new() -> Self {
+        assert!(N > 0, "window must not be empty");
+        RollingMinMax {
+            values: Deque::new(),
+            minima: Deque::new(),
+            maxima: Deque::new(),
+        }
+    }
+
+    /// Returns the number of values in the window.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether no value has been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Pushes a value, returning the oldest one if the window was full.
+    pub fn push(&mut self, value: Primant) -> Option<Primant> {
+        let evicted = if self.values.is_full() { self.values.pop_front() } else { None };
+        if let Some(evicted) = evicted {
+            // Equal values are all kept in the queues, so evicting one of them
+            // leaves the others in place.
+            self.minima.pop_front_if(|&mut front| front == evicted);
+            self.maxima.pop_front_if(|&mut front| front == evicted);
+        }
+
+        while self.minima.back().is_some_and(|&back| back > value) {
+            self.minima.pop_back();
+        }
+        while self.maxima.back().is_some_and(|&back| back < value) {
+            self.maxima.pop_back();
+        }
+        let _ = self.minima.push_back(value);
+        let _ = self.maxima.push_back(value);
+        let _ = self.values.push_back(value);
+        evicted
+    }
+
+    /// Returns the smallest value in the window, or `None` if it is empty.
+    pub fn min(&self) -> Option<Primant> {
+        self.minima.front().copied()
+    }
+
+    /// Returns the largest value in the window, or `None` if it is empty.
+    pub fn max(&self) -> Option<Primant> {
+        self.maxima.front().copied()
+    }
+
+    /// Returns the difference between the largest and the smallest value in the
+    /// window, or `None` if it is empty.
+    pub fn spread(&self) -> Option<Primant> {
+        Some(Primant::from_raw(self.max()?.to_raw() - self.min()?.to_raw()))
+    }
+
+    /// Forgets every value.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.minima.clear();
+        self.maxima.clear();
+    }
+}
+
+impl<const N: usize> Default for RollingMinMax<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean() {
+        let mut mean = RollingMean::<3>::new();
+        assert_eq!(mean.mean(), None);
+        assert_eq!(mean.push(Primant::MAX), None);
+        assert_eq!(mean.mean(), Some(Primant::MAX));
+        for _ in 0..3 {
+            mean.push(Primant::from_raw(7));
+        }
+        assert_eq!(mean.len(), 3);
+        assert_eq!(mean.mean(), Some(Primant::from_raw(7)));
+        assert_eq!(mean.push(Primant::from_raw(8)), Some(Primant::from_raw(7)));
+        assert_eq!(mean.mean(), Some(Primant::from_raw(7)));
+        mean.clear();
+        assert!(mean.is_empty());
+    }
+
+    #[test]
+    fn test_min_max() {
+        let values = [4, 4, 9, 1, 4, 7, 7, 2, 3, 9, 0, 5];
+        let mut window = RollingMinMax::<3>::new();
+        assert_eq!(window.spread(), None);
+        for (index, &raw) in values.iter().enumerate() {
+            window.push(Primant::from_raw(raw));
+            let recent = &values[index.saturating_sub(2)..=index];
+            assert_eq!(window.min(), Some(Primant::from_raw(*recent.iter().min().unwrap())));
+            assert_eq!(window.max(), Some(Primant::from_raw(*recent.iter().max().unwrap())));
+        }
+        assert_eq!(window.spread(), Some(Primant::from_raw(9)));
+        window.clear();
+        assert_eq!(window.min(), None);
+    }
+}
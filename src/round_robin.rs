@@ -0,0 +1,105 @@
+use crate::Primant;
+
+/// A smooth weighted round-robin scheduler.
+///
+/// A [`WeightedRoundRobin`] distributes turns among `N` channels in proportion to
+/// their [`Primant`] weights, interleaving them as evenly as possible instead of
+/// serving each channel in a burst. It is an endless iterator of channel indices,
+/// and yields nothing if every weight is zero.
+///
+/// The weights are relative, so only their proportions matter. The schedule uses
+/// the smooth weighted round-robin algorithm with exact integer arithmetic, so over
+/// any number of turns, each channel is within one turn of its exact share.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, WeightedRoundRobin};
+/// let half = Primant::from_ratio(1u32, 2u32);
+/// let quarter = Primant::from_ratio(1u32, 4u32);
+/// let scheduler = WeightedRoundRobin::new([half, quarter, quarter]);
+/// let turns: Vec<_> = scheduler.take(8).collect();
+/// assert_eq!(turns, [0, 1, 2, 0, 0, 1, 2, 0]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeightedRoundRobin<const N: usize> {
+    weights: [Primant; N],
+    current: [i64; N],
+}
+
+impl<const N: usize> WeightedRoundRobin<N> {
+    /// Creates a new [`WeightedRoundRobin`] with the given weights.
+    pub fn new(weights: [Primant; N]) -> Self {
+        WeightedRoundRobin {
+            weights,
+            current: [0; N],
+        }
+    }
+
+    /// Returns the weights of the channels.
+    pub fn weights(&self) -> &[Primant; N] {
+        &self.weights
+    }
+
+    /// Changes the weight of the channel at `index`, keeping the progress of the
+    /// other channels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_weight(&mut self, index: usize, weight: Primant) {
+        self.weights[index] = weight;
+    }
+
+    /// Restarts the schedule from the beginning.
+    pub fn reset(&mut self) {
+        self.current = [0; N];
+    }
+}
+
+impl<const N: usize> Iterator for WeightedRoundRobin<N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut total = 0;
+        let mut selected = None;
+        for (index, weight) in self.weights.iter().enumerate() {
+            let weight = weight.to_raw() as i64;
+            if weight == 0 { continue; }
+            total += weight;
+            self.current[index] += weight;
+            if selected.is_none_or(|best: usize| self.current[index] > self.current[best]) {
+                selected = Some(index);
+            }
+        }
+        let index = selected?;
+        self.current[index] -= total;
+        Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shares() {
+        let weights = [Primant::from_raw(5), Primant::from_raw(1), Primant::ZERO, Primant::from_raw(1)];
+        let mut scheduler = WeightedRoundRobin::new(weights);
+        let mut counts = [0u32; 4];
+        for turn in 1..=700 {
+            counts[scheduler.next().unwrap()] += 1;
+            let expected = [turn * 5 / 7, turn / 7, 0, turn / 7];
+            for (count, expected) in counts.iter().zip(expected) {
+                assert!(count.abs_diff(expected) <= 1);
+            }
+        }
+        assert_eq!(counts, [500, 100, 0, 100]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(WeightedRoundRobin::new([Primant::ZERO; 3]).next(), None);
+        assert_eq!(WeightedRoundRobin::<0>::new([]).next(), None);
+    }
+}
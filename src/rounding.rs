@@ -0,0 +1,43 @@
+/// A rounding mode, used where a result falls between two representable values.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, Rounding};
+/// let value = Primant::from_ratio(3u32, 8u32);
+/// assert_eq!(value.quantize(4, Rounding::Down), Primant::from_ratio(1u32, 4u32));
+/// assert_eq!(value.quantize(4, Rounding::HalfEven), Primant::from_ratio(1u32, 2u32));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds towards zero.
+    Down,
+    /// Rounds away from zero.
+    Up,
+    /// Rounds to the nearest value, with ties rounded away from zero.
+    #[default]
+    HalfUp,
+    /// Rounds to the nearest value, with ties rounded towards zero.
+    HalfDown,
+    /// Rounds to the nearest value, with ties rounded to the even one, also known as
+    /// banker's rounding.
+    HalfEven,
+}
+
+impl Rounding {
+    /// Returns whether a value between two consecutive representable values should
+    /// be rounded up, given its distances from both and whether the lower one is odd.
+    ///
+    /// A value at a zero distance from the lower one is exact, so it is never rounded
+    /// up.
+    pub(crate) const fn rounds_up(self, to_lower: u128, to_upper: u128, lower_is_odd: bool) -> bool {
+        if to_lower == 0 { return false; }
+        match self {
+            Rounding::Down => false,
+            Rounding::Up => true,
+            Rounding::HalfUp => to_upper <= to_lower,
+            Rounding::HalfDown => to_upper < to_lower,
+            Rounding::HalfEven => to_upper < to_lower || (to_upper == to_lower && lower_is_odd),
+        }
+    }
+}
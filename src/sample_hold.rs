@@ -0,0 +1,122 @@
+use crate::random::Xorshift32;
+use crate::{Phase, Primant};
+
+/// A sample-and-hold stage.
+///
+/// A [`SampleHold`] follows a driving [`Phase`] and latches a new value every time
+/// that phase wraps around the end of its cycle, holding it until the next wrap.
+/// The new value can be either provided by the caller or pseudo-random, producing
+/// stepped modulation or blinking patterns.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Phase, Primant, SampleHold};
+/// let mut hold = SampleHold::new(Primant::ZERO);
+/// assert_eq!(hold.update(Phase::from_raw(1 << 31), Primant::MAX), Primant::ZERO);
+/// // The phase wrapped, so the provided value is latched.
+/// assert_eq!(hold.update(Phase::from_raw(1 << 30), Primant::MAX), Primant::MAX);
+/// assert_eq!(hold.update(Phase::from_raw(1 << 31), Primant::ZERO), Primant::MAX);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SampleHold {
+    previous: Phase,
+    held: Primant,
+    random: Xorshift32,
+}
+
+impl SampleHold {
+    /// Creates a new [`SampleHold`] holding `initial` until the first wrap.
+    pub fn new(initial: Primant) -> Self {
+        SampleHold {
+            previous: Phase::MIN,
+            held: initial,
+            random: Xorshift32::default(),
+        }
+    }
+
+    /// Sets the seed of the pseudo-random generator used by
+    /// [`SampleHold::update_random`].
+    ///
+    /// A seed of zero is replaced with a non-zero default.
+    pub fn with_seed(self, seed: u32) -> Self {
+        SampleHold { random: Xorshift32::new(seed), ..self }
+    }
+
+    /// Returns the currently held value.
+    pub fn value(&self) -> Primant {
+        self.held
+    }
+
+    /// Holds `initial` until the next wrap, as if newly created, and forgets the
+    /// last phase. The pseudo-random sequence carries on.
+    pub fn reset(&mut self, initial: Primant) {
+        *self = SampleHold { previous: Phase::MIN, held: initial, ..*self };
+    }
+
+    /// Follows the driving phase, latching `value` if it wrapped since the last
+    /// update, and returns the held value.
+    pub fn update(&mut self, phase: Phase, value: Primant) -> Primant {
+        if phase.wrapped_from(self.previous) {
+            self.held = value;
+        }
+        self.previous = phase;
+        self.held
+    }
+
+    /// Follows the driving phase, latching a new pseudo-random value if it wrapped
+    /// since the last update, and returns the held value.
+    pub fn update_random(&mut self, phase: Phase) -> Primant {
+        if phase.wrapped_from(self.previous) {
+            self.held = Primant::from_raw(self.random.next_u32());
+        }
+        self.previous = phase;
+        self.held
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hold() {
+        let mut hold = SampleHold::new(Primant::from_raw(5));
+        // The phase moves forwards without wrapping, so nothing is latched.
+        for raw in [0, 1, 1 << 30, 1 << 31, u32::MAX] {
+            assert_eq!(hold.update(Phase::from_raw(raw), Primant::from_raw(raw)), Primant::from_raw(5));
+        }
+        assert_eq!(hold.value(), Primant::from_raw(5));
+        assert_eq!(hold.update_random(Phase::MAX), Primant::from_raw(5));
+    }
+
+    #[test]
+    fn test_trigger() {
+        let mut hold = SampleHold::new(Primant::ZERO);
+        hold.update(Phase::MAX, Primant::ZERO);
+        // Wrapping to exactly the start of the cycle latches.
+        assert_eq!(hold.update(Phase::MIN, Primant::from_raw(7)), Primant::from_raw(7));
+        assert_eq!(hold.update(Phase::from_raw(1 << 31), Primant::ZERO), Primant::from_raw(7));
+        assert_eq!(hold.update(Phase::from_raw(1 << 30), Primant::from_raw(9)), Primant::from_raw(9));
+
+        // Every wrap draws the next value of the seeded sequence.
+        let mut hold = SampleHold::new(Primant::ZERO).with_seed(42);
+        let mut random = Xorshift32::new(42);
+        for _ in 0..5 {
+            assert_eq!(hold.update_random(Phase::from_raw(1 << 31)), hold.value());
+            let expected = Primant::from_raw(random.next_u32());
+            assert_eq!(hold.update_random(Phase::from_raw(1 << 30)), expected);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hold = SampleHold::new(Primant::ZERO).with_seed(7);
+        hold.update(Phase::MAX, Primant::ZERO);
+        hold.reset(Primant::from_raw(3));
+        assert_eq!(hold.value(), Primant::from_raw(3));
+        // The phase before the reset is forgotten, so this is not a wrap.
+        assert_eq!(hold.update(Phase::from_raw(1 << 30), Primant::MAX), Primant::from_raw(3));
+        assert_eq!(hold, SampleHold { previous: Phase::from_raw(1 << 30), ..SampleHold::new(Primant::from_raw(3)).with_seed(7) });
+    }
+}
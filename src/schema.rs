@@ -0,0 +1,109 @@
+//! JSON schemas of the fractional types, describing them as numbers in their
+//! ranges.
+//!
+//! These are only available with the `schemars` feature.
+
+use alloc::borrow::Cow;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use crate::{Bipolar, Phase, Primant};
+
+impl JsonSchema for Primant {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Primant".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0,
+            "maximum": 1
+        })
+    }
+}
+
+impl JsonSchema for Phase {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Phase".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0,
+            "exclusiveMaximum": 1
+        })
+    }
+}
+
+impl JsonSchema for Bipolar {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Bipolar".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": -1,
+            "maximum": 1
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schemars::schema_for;
+
+    #[test]
+    fn test_ranges() {
+        let schema = schema_for!(Phase);
+        assert_eq!(schema.get("type"), Some(&"number".into()));
+        assert_eq!(schema.get("minimum"), Some(&0.into()));
+        assert_eq!(schema.get("exclusiveMaximum"), Some(&1.into()));
+        assert_eq!(schema.get("maximum"), None);
+        assert_eq!(schema_for!(Primant).get("maximum"), Some(&1.into()));
+        assert_eq!(schema_for!(Bipolar).get("minimum"), Some(&(-1).into()));
+    }
+
+    /// Checks that `value` serializes to a number allowed by the schema of `T`, and
+    /// round-trips through JSON.
+    #[cfg(feature = "serde")]
+    fn assert_matches_schema<T>(value: T)
+    where
+        T: JsonSchema + serde::Serialize + serde::de::DeserializeOwned + PartialEq + core::fmt::Debug,
+    {
+        let json = serde_json::to_value(&value).unwrap();
+        let schema = schema_for!(T);
+        let number = json.as_f64().expect("value must serialize to a number");
+        assert_eq!(schema.get("type"), Some(&"number".into()));
+        let bound = |key: &str| schema.get(key).and_then(|bound| bound.as_f64());
+        assert!(bound("minimum").is_none_or(|minimum| number >= minimum), "{number}");
+        assert!(bound("maximum").is_none_or(|maximum| number <= maximum), "{number}");
+        assert!(bound("exclusiveMaximum").is_none_or(|maximum| number < maximum), "{number}");
+        assert_eq!(serde_json::from_value::<T>(json).unwrap(), value);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_matches_schema() {
+        for raw in [0, 1, 0x8000_0000, u32::MAX - 1, u32::MAX] {
+            assert_matches_schema(Primant::from_raw(raw));
+            assert_matches_schema(Phase::from_raw(raw));
+            assert_matches_schema(Bipolar::from_raw(raw as i32));
+        }
+        assert_matches_schema(Bipolar::MIN);
+        assert_matches_schema(Bipolar::MAX);
+    }
+}
@@ -0,0 +1,198 @@
+//! Helpers for serializing the types of this crate with `serde`.
+//!
+//! With the `serde` feature, [`Primant`], [`Phase`] and [`Bipolar`] implement
+//! [`Serialize`] and [`Deserialize`]. In human-readable formats, such as JSON, they
+//! are written as numbers in their ranges, as described by their JSON schemas with
+//! the `schemars` feature: from `0.0` to `1.0` for a [`Primant`], from `0.0` up to
+//! `1.0` (exclusive) for a [`Phase`], and from `-1.0` to `1.0` for a [`Bipolar`].
+//! Values outside of these ranges are rejected when reading. In other formats, the
+//! raw value is used, so every value round-trips exactly.
+//!
+//! These are only available with the `serde` feature.
+
+use ::serde::de::{Deserialize, Deserializer, Error};
+use ::serde::ser::{Serialize, Serializer};
+use num_traits::float::FloatCore;
+use crate::{Bipolar, Phase, Primant};
+
+impl Serialize for Primant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f64(f64::from(*self))
+        } else {
+            serializer.serialize_u32(self.to_raw())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Primant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return u32::deserialize(deserializer).map(Primant::from_raw);
+        }
+
+        let value = f64::deserialize(deserializer)?;
+        if !(0.0..=1.0).contains(&value) {
+            return Err(D::Error::custom("fraction must be in the range 0.0..=1.0"));
+        }
+        Ok(Primant::from_raw(FloatCore::round(value * u32::MAX as f64) as u32))
+    }
+}
+
+impl Serialize for Phase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f64(f64::from(*self))
+        } else {
+            serializer.serialize_u32(self.to_raw())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Phase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return u32::deserialize(deserializer).map(Phase::from_raw);
+        }
+
+        let value = f64::deserialize(deserializer)?;
+        if !(0.0..1.0).contains(&value) {
+            return Err(D::Error::custom("phase must be in the range 0.0..1.0"));
+        }
+        // Rounding to the nearest raw value may reach a full turn, which wraps to zero.
+        let raw = FloatCore::round(value * 4_294_967_296.0) as u64;
+        Ok(Phase::from_raw(raw as u32))
+    }
+}
+
+impl Serialize for Bipolar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f64(f64::from(*self))
+        } else {
+            serializer.serialize_i32(self.to_raw())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Bipolar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return i32::deserialize(deserializer).map(Bipolar::from_raw);
+        }
+
+        let value = f64::deserialize(deserializer)?;
+        if !(-1.0..=1.0).contains(&value) {
+            return Err(D::Error::custom("value must be in the range -1.0..=1.0"));
+        }
+        Ok(Bipolar::from_raw(FloatCore::round(value * i32::MAX as f64) as i32))
+    }
+}
+
+/// Serializes a [`Phase`] as an angle in degrees, for use with
+/// `#[serde(with = "unifrac::serde::degrees")]`.
+///
+/// In human-readable formats, such as JSON, the phase is written as a number of
+/// degrees from `0.0` up to `360.0` (exclusive), and any number of degrees is
+/// accepted when reading, wrapping around a full turn. In other formats, the raw
+/// value is used, so the phase round-trips exactly.
+///
+/// This differs from the JSON schema of a [`Phase`] with the `schemars` feature, so
+/// such fields need a schema of their own, such as with
+/// `#[schemars(with = "f64")]`.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::Phase;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Joint {
+///     #[serde(with = "unifrac::serde::degrees")]
+///     angle: Phase,
+/// }
+///
+/// let joint: Joint = serde_json::from_str(r#"{ "angle": -90 }"#).unwrap();
+/// assert_eq!(joint.angle, Phase::from_raw(0xC000_0000));
+/// assert_eq!(serde_json::to_string(&joint).unwrap(), r#"{"angle":270.0}"#);
+/// ```
+pub mod degrees {
+    use super::*;
+
+    /// The number of raw units in a full turn.
+    const TURN: f64 = 4_294_967_296.0;
+
+    /// Serializes `phase` as degrees in human-readable formats, or as its raw value
+    /// otherwise.
+    pub fn serialize<S: Serializer>(phase: &Phase, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f64(phase.to_raw() as f64 * 360.0 / TURN)
+        } else {
+            serializer.serialize_u32(phase.to_raw())
+        }
+    }
+
+    /// Deserializes a phase from degrees in human-readable formats, wrapping around
+    /// a full turn, or from its raw value otherwise.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Phase, D::Error> {
+        if !deserializer.is_human_readable() {
+            return u32::deserialize(deserializer).map(Phase::from_raw);
+        }
+
+        let degrees = f64::deserialize(deserializer)?;
+        if !degrees.is_finite() {
+            return Err(D::Error::custom("angle in degrees must be finite"));
+        }
+        let turns = degrees / 360.0;
+        // Rounding to the nearest raw value may reach a full turn, which wraps to zero.
+        let raw = FloatCore::round((turns - FloatCore::floor(turns)) * TURN) as u64;
+        Ok(Phase::from_raw(raw as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    fn from_json(text: &str) -> Phase {
+        degrees::deserialize(&mut serde_json::Deserializer::from_str(text)).unwrap()
+    }
+
+    fn to_json(phase: Phase) -> Vec<u8> {
+        let mut output = Vec::new();
+        degrees::serialize(&phase, &mut serde_json::Serializer::new(&mut output)).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_numbers() {
+        for raw in (0..=u32::MAX).step_by(9_999_991).chain([1, u32::MAX - 1, u32::MAX]) {
+            let primant = Primant::from_raw(raw);
+            assert_eq!(serde_json::from_str::<Primant>(&serde_json::to_string(&primant).unwrap()).unwrap(), primant);
+            let phase = Phase::from_raw(raw);
+            assert_eq!(serde_json::from_str::<Phase>(&serde_json::to_string(&phase).unwrap()).unwrap(), phase);
+            let bipolar = Bipolar::from_raw(raw as i32);
+            assert_eq!(serde_json::from_str::<Bipolar>(&serde_json::to_string(&bipolar).unwrap()).unwrap(), bipolar);
+        }
+        assert_eq!(serde_json::to_string(&Primant::MAX).unwrap(), "1.0");
+        assert_eq!(serde_json::to_string(&Phase::from_raw(0x4000_0000)).unwrap(), "0.25");
+        assert_eq!(serde_json::to_string(&Bipolar::MIN).unwrap(), "-1.0");
+        assert_eq!(serde_json::from_str::<Phase>("0.99999999999").unwrap(), Phase::MIN);
+        assert!(serde_json::from_str::<Primant>("1.5").is_err());
+        assert!(serde_json::from_str::<Phase>("1.0").is_err());
+        assert!(serde_json::from_str::<Bipolar>("-1.01").is_err());
+    }
+
+    #[test]
+    fn test_degrees() {
+        assert_eq!(from_json("90"), Phase::from_raw(0x4000_0000));
+        assert_eq!(from_json("450.0"), Phase::from_raw(0x4000_0000));
+        assert_eq!(from_json("-0.0000000000001"), Phase::MIN);
+        assert_eq!(to_json(Phase::from_raw(0x8000_0000)), b"180.0");
+        for raw in (0..=u32::MAX).step_by(9_999_991).chain([u32::MAX]) {
+            let phase = Phase::from_raw(raw);
+            assert_eq!(from_json(core::str::from_utf8(&to_json(phase)).unwrap()), phase);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Response shaping shared between [`Primant`](crate::Primant) and
+//! [`Bipolar`](crate::Bipolar), operating on magnitudes in the range `0..=full`.
+
+use crate::Primant;
+
+/// Maps magnitudes below `threshold` to zero, and rescales the rest to cover the
+/// whole range, rounding to the nearest value.
+pub(crate) fn deadzone(value: u64, full: u64, threshold: Primant) -> u64 {
+    let threshold = threshold.scale_u128(full as u128, true) as u64;
+    if value <= threshold { return 0; }
+    let span = (full - threshold) as u128;
+    (((value - threshold) as u128 * full as u128 + span / 2) / span) as u64
+}
+
+/// Blends a magnitude with its cube, `(1 - amount)·x + amount·x³`, rounding to the
+/// nearest value.
+pub(crate) fn expo(value: u64, full: u64, amount: Primant) -> u64 {
+    let (x, full) = (value as u128, full as u128);
+    let cubed = (x * x / full) * x / full;
+    let blended = (x * (u32::MAX - amount.to_raw()) as u128 + cubed * amount.to_raw() as u128
+        + u32::MAX as u128 / 2) / u32::MAX as u128;
+    blended as u64
+}
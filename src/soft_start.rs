@@ -0,0 +1,180 @@
+use crate::{Lerp, Primant};
+
+/// The shape of the transitions produced by a [`SoftStart`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RampProfile {
+    /// The value changes at a constant rate.
+    Linear,
+    /// The value accelerates and decelerates smoothly, following a smoothstep
+    /// curve. Transitions take one and a half times longer than linear ones, so the
+    /// peak rate of change stays within the limit.
+    Smooth,
+}
+
+/// A rate limiter for duty cycles.
+///
+/// A [`SoftStart`] follows a target [`Primant`], such as the duty cycle driving a
+/// motor or a power supply, but limits how fast its output can change. When the
+/// target jumps, the output ramps towards it over a number of ticks proportional to
+/// the size of the jump.
+///
+/// The rates of rise and fall are configured separately, as the time it would
+/// take to sweep the full range. Every transition is computed from its start, so
+/// no error accumulates over time.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, SoftStart};
+/// let mut ramp = SoftStart::new(4).with_fall_ticks(0);
+/// assert_eq!(ramp.tick(Primant::MAX), Primant::from_ratio(1u32, 4u32));
+/// ramp.tick(Primant::MAX);
+/// ramp.tick(Primant::MAX);
+/// assert_eq!(ramp.tick(Primant::MAX), Primant::MAX);
+/// assert_eq!(ramp.tick(Primant::ZERO), Primant::ZERO);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SoftStart {
+    rise_ticks: u32,
+    fall_ticks: u32,
+    profile: RampProfile,
+    current: Primant,
+    start: Primant,
+    target: Primant,
+    elapsed: u32,
+    duration: u32,
+}
+
+impl SoftStart {
+    /// Creates a new [`SoftStart`] starting at [`Primant::ZERO`], taking
+    /// `full_scale_ticks` to sweep the full range in either direction.
+    pub fn new(full_scale_ticks: u32) -> Self {
+        SoftStart {
+            rise_ticks: full_scale_ticks,
+            fall_ticks: full_scale_ticks,
+            profile: RampProfile::Linear,
+            current: Primant::ZERO,
+            start: Primant::ZERO,
+            target: Primant::ZERO,
+            elapsed: 0,
+            duration: 0,
+        }
+    }
+
+    /// Sets the number of ticks it takes to rise across the full range.
+    pub fn with_rise_ticks(self, ticks: u32) -> Self {
+        SoftStart { rise_ticks: ticks, ..self }
+    }
+
+    /// Sets the number of ticks it takes to fall across the full range.
+    ///
+    /// Zero makes the output fall immediately, which is common when stopping a
+    /// motor is safe but starting it is not.
+    pub fn with_fall_ticks(self, ticks: u32) -> Self {
+        SoftStart { fall_ticks: ticks, ..self }
+    }
+
+    /// Sets the shape of the transitions.
+    pub fn with_profile(self, profile: RampProfile) -> Self {
+        SoftStart { profile, ..self }
+    }
+
+    /// Returns the current output.
+    pub fn value(&self) -> Primant {
+        self.current
+    }
+
+    /// Returns whether the output has reached the latest target.
+    pub fn is_settled(&self) -> bool {
+        self.current == self.target
+    }
+
+    /// Sets the output immediately, without ramping.
+    pub fn reset(&mut self, value: Primant) {
+        self.current = value;
+        self.start = value;
+        self.target = value;
+        self.elapsed = 0;
+        self.duration = 0;
+    }
+
+    /// Advances by one tick towards `target` and returns the new output.
+    pub fn tick(&mut self, target: Primant) -> Primant {
+        if target != self.target {
+            self.start = self.current;
+            self.target = target;
+            self.elapsed = 0;
+            self.duration = self.transition_ticks();
+        }
+
+        if self.elapsed < self.duration {
+            self.elapsed += 1;
+        }
+        if self.elapsed >= self.duration {
+            self.current = self.target;
+            return self.current;
+        }
+
+        let progress = Primant::ratio_of(self.elapsed as u64, self.duration as u64);
+        let progress = match self.profile {
+            RampProfile::Linear => progress,
+            RampProfile::Smooth => smoothstep(progress),
+        };
+        self.current = self.start.lerp(self.target, progress);
+        self.current
+    }
+
+    /// Returns the number of ticks the transition from `start` to `target` takes.
+    fn transition_ticks(&self) -> u32 {
+        let (distance, full_scale_ticks) = if self.target >= self.start {
+            (self.target.to_raw() - self.start.to_raw(), self.rise_ticks)
+        } else {
+            (self.start.to_raw() - self.target.to_raw(), self.fall_ticks)
+        };
+        let ticks = (distance as u64 * full_scale_ticks as u64).div_ceil(u32::MAX as u64);
+        let ticks = match self.profile {
+            RampProfile::Linear => ticks,
+            RampProfile::Smooth => (ticks * 3).div_ceil(2),
+        };
+        ticks.min(u32::MAX as u64) as u32
+    }
+}
+
+/// Computes `3t² - 2t³`.
+fn smoothstep(t: Primant) -> Primant {
+    let (t, max) = (t.to_raw() as u128, u32::MAX as u128);
+    let squared = t * t;
+    let value = (3 * max * squared - 2 * squared * t) / (max * max);
+    Primant::from_raw(value as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit() {
+        let mut ramp = SoftStart::new(100).with_profile(RampProfile::Smooth);
+        let step = u32::MAX / 100 + 1;
+        let mut previous = ramp.value();
+        let mut ticks = 0;
+        while ramp.tick(Primant::MAX) != Primant::MAX {
+            assert!(ramp.value().to_raw() - previous.to_raw() <= step);
+            previous = ramp.value();
+            ticks += 1;
+        }
+        assert_eq!(ticks + 1, 150);
+    }
+
+    #[test]
+    fn test_retarget() {
+        let mut ramp = SoftStart::new(10);
+        for _ in 0..5 {
+            ramp.tick(Primant::MAX);
+        }
+        assert_eq!(ramp.value(), Primant::from_ratio(1u32, 2u32));
+        ramp.tick(Primant::ZERO);
+        assert_eq!(ramp.value(), Primant::from_ratio(2u32, 5u32));
+        assert!(!ramp.is_settled());
+    }
+}
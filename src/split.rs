@@ -0,0 +1,104 @@
+use crate::Primant;
+
+/// Divides `total` into `out`, in proportion to `weights`, so that the parts add
+/// up to exactly `total`, such as for sharing bandwidth or memory.
+///
+/// The weights are relative to their sum, so they do not need to add up to one.
+/// Every part is rounded down, and the remaining units go to the parts with the
+/// largest remainders, so no part is off by more than one unit. Equal remainders
+/// favor the first part. This needs no allocator.
+///
+/// # Panics
+///
+/// Panics if `weights` and `out` have different lengths, or if every weight is
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{split_budget, Primant};
+/// let third = Primant::from_ratio(1u32, 3u32);
+/// let mut parts = [0; 3];
+/// split_budget(100, &[third, third, third], &mut parts);
+/// assert_eq!(parts, [34, 33, 33]);
+/// ```
+pub fn split_budget(total: u64, weights: &[Primant], out: &mut [u64]) {
+    assert_eq!(weights.len(), out.len(), "weights and parts must have the same length");
+    let sum: u128 = weights.iter().map(|weight| weight.to_raw() as u128).sum();
+    assert_ne!(sum, 0, "weights must not all be zero");
+
+    let scaled = |weight: Primant| total as u128 * weight.to_raw() as u128;
+    let mut assigned = 0u64;
+    for (part, &weight) in out.iter_mut().zip(weights) {
+        *part = (scaled(weight) / sum) as u64;
+        assigned += *part;
+    }
+    let left = (total - assigned) as usize;
+    if left == 0 { return; }
+
+    // Finds the largest remainder that at least `left` parts reach, so the parts
+    // above it all get a unit, without sorting the remainders.
+    let remainder = |weight: Primant| scaled(weight) % sum;
+    let reaching = |threshold: u128| weights.iter().filter(|&&weight| remainder(weight) >= threshold).count();
+    let (mut low, mut high) = (0, sum);
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if reaching(middle) >= left { low = middle; } else { high = middle; }
+    }
+    let mut ties = left - reaching(low + 1);
+    for (part, &weight) in out.iter_mut().zip(weights) {
+        let remainder = remainder(weight);
+        if remainder > low {
+            *part += 1;
+        } else if remainder == low && ties > 0 {
+            *part += 1;
+            ties -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// Splits the budget by sorting the remainders.
+    fn reference(total: u64, weights: &[Primant]) -> Vec<u64> {
+        let sum: u128 = weights.iter().map(|weight| weight.to_raw() as u128).sum();
+        let scaled: Vec<u128> = weights.iter().map(|weight| total as u128 * weight.to_raw() as u128).collect();
+        let mut parts: Vec<u64> = scaled.iter().map(|&scaled| (scaled / sum) as u64).collect();
+        let mut order: Vec<usize> = (0..weights.len()).collect();
+        order.sort_by_key(|&index| core::cmp::Reverse(scaled[index] % sum));
+        let left = total - parts.iter().sum::<u64>();
+        for &index in order.iter().take(left as usize) {
+            parts[index] += 1;
+        }
+        parts
+    }
+
+    #[test]
+    fn test_split_budget() {
+        let mut seed = 1u32;
+        for length in 1..40 {
+            let weights: Vec<Primant> = (0..length).map(|index| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                // Repeated weights make equal remainders.
+                Primant::from_raw(if index % 3 == 0 { 12345 } else { seed >> (index % 32) })
+            }).collect();
+            for total in [0, 1, 7, 1000, 1 << 40, u64::MAX] {
+                let mut parts = vec![0; length];
+                split_budget(total, &weights, &mut parts);
+                assert_eq!(parts, reference(total, &weights));
+                assert_eq!(parts.iter().map(|&part| part as u128).sum::<u128>(), total as u128);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_weights() {
+        split_budget(10, &[Primant::ZERO; 2], &mut [0; 2]);
+    }
+}
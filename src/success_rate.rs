@@ -0,0 +1,172 @@
+use crate::{Lerp, Primant};
+
+/// A tracker of the success rate over the last `WINDOW` outcomes.
+///
+/// A [`SuccessRate`] keeps the outcomes in a ring buffer, so the rate reflects
+/// exactly the most recent outcomes, with every one weighted equally. For a
+/// smoother rate without a buffer, see [`ExponentialSuccessRate`].
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Primant, SuccessRate};
+/// let mut health = SuccessRate::<4>::new();
+/// for outcome in [true, false, true, true, true] {
+///     health.record(outcome);
+/// }
+/// // The first outcome has left the window.
+/// assert_eq!(health.rate(), Primant::from_ratio(3u32, 4u32));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuccessRate<const WINDOW: usize> {
+    outcomes: [bool; WINDOW],
+    next: usize,
+    len: usize,
+    successes: usize,
+}
+
+impl<const WINDOW: usize> SuccessRate<WINDOW> {
+    /// Creates a new [`SuccessRate`] with no outcomes recorded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `WINDOW` is zero.
+    pub const fn new() -> Self {
+        assert!(WINDOW > 0, "window must not be empty");
+        SuccessRate {
+            outcomes: [false; WINDOW],
+            next: 0,
+            len: 0,
+            successes: 0,
+        }
+    }
+
+    /// Returns the number of outcomes in the window.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no outcome has been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Records an outcome, evicting the oldest one if the window is full.
+    pub fn record(&mut self, success: bool) {
+        if self.len == WINDOW {
+            self.successes -= self.outcomes[self.next] as usize;
+        } else {
+            self.len += 1;
+        }
+        self.outcomes[self.next] = success;
+        self.successes += success as usize;
+        self.next = (self.next + 1) % WINDOW;
+    }
+
+    /// Returns the fraction of successes in the window.
+    ///
+    /// Before any outcome is recorded, there has been no failure, so the rate is
+    /// [`Primant::MAX`].
+    pub fn rate(&self) -> Primant {
+        if self.len == 0 { return Primant::MAX; }
+        Primant::ratio_of(self.successes as u64, self.len as u64)
+    }
+
+    /// Forgets every recorded outcome.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<const WINDOW: usize> Default for SuccessRate<WINDOW> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A tracker of an exponentially weighted success rate.
+///
+/// An [`ExponentialSuccessRate`] moves its rate towards every recorded outcome by
+/// a fixed fraction, its smoothing factor, so recent outcomes weigh the most and
+/// old ones fade away gradually. It takes constant space regardless of how many
+/// outcomes it remembers: a smoothing factor of `1/n` roughly corresponds to a
+/// window of `n` outcomes.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{ExponentialSuccessRate, Primant};
+/// let mut health = ExponentialSuccessRate::new(Primant::from_ratio(1u32, 2u32));
+/// health.record(false);
+/// assert_eq!(health.rate().percent_of(100u32), 50);
+/// health.record(false);
+/// assert_eq!(health.rate().percent_of(100u32), 25);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExponentialSuccessRate {
+    rate: Primant,
+    smoothing: Primant,
+}
+
+impl ExponentialSuccessRate {
+    /// Creates a new [`ExponentialSuccessRate`] with the given smoothing factor,
+    /// starting at a rate of [`Primant::MAX`].
+    pub fn new(smoothing: Primant) -> Self {
+        ExponentialSuccessRate {
+            rate: Primant::MAX,
+            smoothing,
+        }
+    }
+
+    /// Returns the smoothing factor.
+    pub fn smoothing(&self) -> Primant {
+        self.smoothing
+    }
+
+    /// Records an outcome, moving the rate towards it.
+    pub fn record(&mut self, success: bool) {
+        let target = if success { Primant::MAX } else { Primant::ZERO };
+        self.rate = self.rate.lerp(target, self.smoothing);
+    }
+
+    /// Returns the current success rate.
+    pub fn rate(&self) -> Primant {
+        self.rate
+    }
+
+    /// Sets the current success rate, such as for starting pessimistically.
+    pub fn set_rate(&mut self, rate: Primant) {
+        self.rate = rate;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window() {
+        let mut rate = SuccessRate::<3>::new();
+        assert!(rate.is_empty());
+        assert_eq!(rate.rate(), Primant::MAX);
+        rate.record(false);
+        assert_eq!(rate.rate(), Primant::ZERO);
+        for _ in 0..3 {
+            rate.record(true);
+        }
+        assert_eq!(rate.len(), 3);
+        assert_eq!(rate.rate(), Primant::MAX);
+        rate.clear();
+        assert!(rate.is_empty());
+    }
+
+    #[test]
+    fn test_exponential() {
+        let mut rate = ExponentialSuccessRate::new(Primant::MAX);
+        rate.record(false);
+        assert_eq!(rate.rate(), Primant::ZERO);
+        let mut rate = ExponentialSuccessRate::new(Primant::ZERO);
+        rate.record(false);
+        assert_eq!(rate.rate(), Primant::MAX);
+    }
+}
@@ -0,0 +1,145 @@
+use core::iter::FusedIterator;
+use crate::{Phase, Primant};
+
+/// An iterator over equally spaced [`Primant`]s, from exactly [`Primant::ZERO`] to
+/// exactly [`Primant::MAX`].
+///
+/// This iterator is created by [`Primant::sweep_inclusive`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SweepInclusive {
+    /// The number of intervals between the values.
+    intervals: u32,
+    /// The index of the next value from the front.
+    front: u32,
+    /// One past the index of the next value from the back.
+    back: u32,
+}
+
+impl SweepInclusive {
+    pub(crate) fn new(count: u32) -> Self {
+        SweepInclusive {
+            intervals: count.saturating_sub(1),
+            front: 0,
+            back: count,
+        }
+    }
+
+    /// Returns the value at `index`, rounded down like [`Primant::from_ratio`].
+    fn value(&self, index: u32) -> Primant {
+        if self.intervals == 0 { return Primant::ZERO; }
+        Primant::ratio_of(index as u64, self.intervals as u64)
+    }
+}
+
+impl Iterator for SweepInclusive {
+    type Item = Primant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+        self.front += 1;
+        Some(self.value(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for SweepInclusive {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+        self.back -= 1;
+        Some(self.value(self.back))
+    }
+}
+
+impl ExactSizeIterator for SweepInclusive {}
+
+impl FusedIterator for SweepInclusive {}
+
+/// An iterator over equally spaced [`Phase`]s covering exactly one turn, starting
+/// at [`Phase::MIN`] without repeating it at the end.
+///
+/// This iterator is created by [`Phase::cycle`]. It can be cloned, so
+/// [`Iterator::cycle`] turns it into an endless stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhaseCycle {
+    count: u32,
+    /// The index of the next value from the front.
+    front: u32,
+    /// One past the index of the next value from the back.
+    back: u32,
+}
+
+impl PhaseCycle {
+    pub(crate) fn new(count: u32) -> Self {
+        PhaseCycle { count, front: 0, back: count }
+    }
+
+    /// Returns the phase at `index`, rounded down.
+    fn value(&self, index: u32) -> Phase {
+        Phase::from_raw((((index as u64) << 32) / self.count as u64) as u32)
+    }
+}
+
+impl Iterator for PhaseCycle {
+    type Item = Phase;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+        self.front += 1;
+        Some(self.value(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for PhaseCycle {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+        self.back -= 1;
+        Some(self.value(self.back))
+    }
+}
+
+impl ExactSizeIterator for PhaseCycle {}
+
+impl FusedIterator for PhaseCycle {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_sweep_inclusive() {
+        assert_eq!(Primant::sweep_inclusive(0).next(), None);
+        assert_eq!(Primant::sweep_inclusive(1).collect::<Vec<_>>(), [Primant::ZERO]);
+        let values: Vec<_> = Primant::sweep_inclusive(4).collect();
+        assert_eq!(values, [Primant::ZERO, Primant::from_ratio(1u32, 3u32), Primant::from_ratio(2u32, 3u32), Primant::MAX]);
+        let reversed: Vec<_> = Primant::sweep_inclusive(4).rev().collect();
+        assert!(reversed.iter().rev().eq(values.iter()));
+
+        let mut sweep = Primant::sweep_inclusive(u32::MAX);
+        assert_eq!(sweep.len(), u32::MAX as usize);
+        assert_eq!(sweep.next_back(), Some(Primant::MAX));
+        assert_eq!(sweep.next_back(), Some(Primant::from_raw(u32::MAX - 2)));
+        assert_eq!(sweep.nth(1), Some(Primant::from_raw(1)));
+    }
+
+    #[test]
+    fn test_phase_cycle() {
+        assert_eq!(Phase::cycle(0).next(), None);
+        assert_eq!(Phase::cycle(1).collect::<Vec<_>>(), [Phase::MIN]);
+        let values: Vec<_> = Phase::cycle(3).map(Phase::to_raw).collect();
+        assert_eq!(values, [0, 0x5555_5555, 0xAAAA_AAAA]);
+        let stream: Vec<_> = Phase::cycle(2).cycle().take(5).map(Phase::to_raw).collect();
+        assert_eq!(stream, [0, 1 << 31, 0, 1 << 31, 0]);
+        assert_eq!(Phase::cycle(u32::MAX).next_back(), Some(Phase::from_raw(u32::MAX - 1)));
+    }
+}
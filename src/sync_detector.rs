@@ -0,0 +1,119 @@
+use crate::{Bipolar, Lerp, Phase, Primant};
+
+/// A lock-in amplifier, recovering the amplitude and phase of an input at the
+/// frequency of a reference, such as for demodulating an excited sensor.
+///
+/// A [`SyncDetector`] multiplies every sample of the input by the sine and the
+/// cosine of the reference [`Phase`] at that sample, and smooths both products with
+/// a one-pole low-pass filter, as an exponentially weighted moving average. For an
+/// input `A sin(θ + φ)` against a reference at `θ`, the averages settle at
+/// `A/2 cos φ` and `A/2 sin φ`, from which the amplitude `A` and the phase offset
+/// `φ` follow. Anything not at the reference frequency averages out.
+///
+/// A smaller smoothing factor rejects more noise, but settles more slowly. The
+/// products are kept at full precision, and computations use integer arithmetic
+/// only.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{Bipolar, Nco, Phase, Primant, SyncDetector};
+/// let mut reference = Nco::from_frequency(1, 16);
+/// let mut detector = SyncDetector::new(Primant::from_ratio(1u32, 256u32));
+/// let offset = Phase::from_raw(1 << 30);
+/// for _ in 0..16_384 {
+///     let phase = reference.tick();
+///     // The input lags a quarter turn behind, at half of full scale.
+///     let input = Bipolar::from_raw((phase - offset).sin().to_raw() / 2);
+///     detector.push(phase, input);
+/// }
+/// assert!(detector.amplitude().within(Primant::from_ratio(1u32, 2u32), Primant::from_ratio(1u32, 100u32)));
+/// assert!(detector.phase().within(-offset, Phase::from_raw(1 << 24)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SyncDetector {
+    smoothing: Primant,
+    in_phase: i64,
+    quadrature: i64,
+}
+
+impl SyncDetector {
+    /// Creates a new [`SyncDetector`], moving its averages towards every product by
+    /// `smoothing`.
+    pub fn new(smoothing: Primant) -> Self {
+        SyncDetector {
+            smoothing,
+            in_phase: 0,
+            quadrature: 0,
+        }
+    }
+
+    /// Feeds the next `sample` of the input, taken when the reference was at
+    /// `reference`.
+    pub fn push(&mut self, reference: Phase, sample: Bipolar) {
+        let (sin, cos) = reference.sin_cos();
+        let sample = sample.to_raw() as i64;
+        self.in_phase = self.in_phase.lerp(sample * sin.to_raw() as i64, self.smoothing);
+        self.quadrature = self.quadrature.lerp(sample * cos.to_raw() as i64, self.smoothing);
+    }
+
+    /// Returns the amplitude of the input at the reference frequency, as a fraction
+    /// of full scale, saturating at [`Primant::MAX`].
+    pub fn amplitude(&self) -> Primant {
+        let (in_phase, quadrature) = (self.in_phase as i128, self.quadrature as i128);
+        let magnitude = ((in_phase * in_phase + quadrature * quadrature) as u128).isqrt();
+        // The averages settle at half the amplitude, scaled by the square of full
+        // scale.
+        let full_scale = i32::MAX as u128 * i32::MAX as u128;
+        if 2 * magnitude >= full_scale { return Primant::MAX; }
+        Primant::from_raw((2 * magnitude * u32::MAX as u128 / full_scale) as u32)
+    }
+
+    /// Returns the phase offset of the input from the reference, to within a few
+    /// raw steps of the averages.
+    ///
+    /// Without any signal at the reference frequency, the offset is meaningless,
+    /// and [`Phase::MIN`] once both averages are zero.
+    pub fn phase(&self) -> Phase {
+        Phase::atan2_raw(self.quadrature as i128, self.in_phase as i128)
+    }
+
+    /// Forgets every sample, restarting both averages from zero.
+    pub fn reset(&mut self) {
+        (self.in_phase, self.quadrature) = (0, 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Nco;
+
+    #[test]
+    fn test_detection() {
+        for (raw_offset, amplitude) in [(0, i32::MAX), (0x2000_0000, i32::MAX / 4), (0xC000_0000u32, i32::MAX / 2)] {
+            let offset = Phase::from_raw(raw_offset);
+            let mut reference = Nco::from_frequency(3, 40);
+            let mut detector = SyncDetector::new(Primant::from_ratio(1u32, 1000u32));
+            let mut noise = 12345u32;
+            for _ in 0..40_000 {
+                let phase = reference.tick();
+                // A signal, a tone at another frequency, and some noise.
+                noise ^= noise << 13;
+                noise ^= noise >> 17;
+                noise ^= noise << 5;
+                let signal = (phase + offset).sin().to_raw() as i64 * amplitude as i64 / i32::MAX as i64;
+                let interference = (phase + phase).cos().to_raw() as i64 / 8;
+                let jitter = (noise >> 4) as i64 - (1 << 27);
+                let sample = (signal / 2 + interference + jitter).clamp(-(i32::MAX as i64), i32::MAX as i64);
+                detector.push(phase, Bipolar::from_raw(sample as i32));
+            }
+            let expected = Primant::ratio_of(amplitude as u64, 2 * i32::MAX as u64);
+            assert!(detector.amplitude().within(expected, Primant::from_ratio(1u32, 50u32)));
+            assert!(detector.phase().within(offset, Phase::from_raw(1 << 25)));
+            detector.reset();
+            assert_eq!(detector.amplitude(), Primant::ZERO);
+            assert_eq!(detector.phase(), Phase::MIN);
+        }
+    }
+}
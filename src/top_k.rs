@@ -0,0 +1,91 @@
+use crate::Primant;
+
+/// Moves the `k` items with the highest scores to the front of `items`, and returns
+/// them, such as for keeping the best candidates of a classifier.
+///
+/// `scores[i]` is the score of `items[i]`. The selected items keep their original
+/// order, rather than being sorted by score, and equal scores favor the first item.
+/// The order of the other items is unspecified. If `k` is greater than the number of
+/// items, every item is selected. This needs no allocator, and `scores` is left
+/// untouched.
+///
+/// # Panics
+///
+/// Panics if `items` and `scores` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// # use unifrac::{top_k_by_score, Primant};
+/// let mut labels = ["cat", "dog", "fox", "owl"];
+/// let scores = [2, 9, 4, 7].map(|score| Primant::ratio_of(score, 10));
+/// assert_eq!(top_k_by_score(&mut labels, &scores, 2), ["dog", "owl"]);
+/// ```
+pub fn top_k_by_score<'a, T>(items: &'a mut [T], scores: &[Primant], k: usize) -> &'a mut [T] {
+    assert_eq!(items.len(), scores.len(), "items and scores must have the same length");
+    let k = k.min(items.len());
+    if k == 0 { return &mut items[..0]; }
+
+    // Finds the highest score that at least `k` items reach, so the items above it
+    // are all selected, without sorting the scores.
+    let reaching = |threshold: u64| scores.iter().filter(|score| score.to_raw() as u64 >= threshold).count();
+    let (mut low, mut high) = (0, u32::MAX as u64 + 1);
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if reaching(middle) >= k { low = middle; } else { high = middle; }
+    }
+    let mut ties = k - reaching(low + 1);
+
+    // Items only move backwards, so `scores[index]` still belongs to `items[index]`
+    // when it is reached.
+    let mut selected = 0;
+    for (index, score) in scores.iter().enumerate() {
+        let score = score.to_raw() as u64;
+        let take = if score > low {
+            true
+        } else if score == low && ties > 0 {
+            ties -= 1;
+            true
+        } else {
+            false
+        };
+        if take {
+            items.swap(selected, index);
+            selected += 1;
+        }
+    }
+    &mut items[..k]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_top_k_by_score() {
+        let mut seed = 5u32;
+        for length in 0..30 {
+            let scores: Vec<Primant> = (0..length).map(|index| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                // Few distinct scores make ties.
+                Primant::from_raw(if index % 2 == 0 { seed % 4 } else { seed })
+            }).collect();
+            for k in 0..=length + 1 {
+                let mut items: Vec<usize> = (0..length).collect();
+                let selected = top_k_by_score(&mut items, &scores, k).to_vec();
+
+                // Sorting by descending score is stable, so ties favor the first item.
+                let mut expected: Vec<usize> = (0..length).collect();
+                expected.sort_by_key(|&index| core::cmp::Reverse(scores[index]));
+                expected.truncate(k);
+                expected.sort();
+                assert_eq!(selected, expected);
+
+                items.sort();
+                assert_eq!(items, (0..length).collect::<Vec<_>>());
+            }
+        }
+    }
+}